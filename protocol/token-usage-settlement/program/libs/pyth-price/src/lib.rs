@@ -0,0 +1,263 @@
+//! Validated reads of Pyth price accounts, shared by the programs that price tokens.
+//!
+//! Accounts are parsed field by field rather than cast, so short or misaligned data returns an
+//! error instead of panicking. The crate has no dependencies so that programs built against
+//! different Solana and Anchor versions can all use it and map [`OracleError`] into their own
+//! program errors.
+
+/// The magic number at the start of every Pyth account
+pub const MAGIC: u32 = 0xa1b2c3d4;
+/// The Pyth account layout version this crate reads
+pub const VERSION_2: u32 = 2;
+
+const ACCOUNT_TYPE_PRODUCT: u32 = 2;
+const ACCOUNT_TYPE_PRICE: u32 = 3;
+const PRICE_TYPE_PRICE: u32 = 1;
+const STATUS_TRADING: u32 = 1;
+
+// Offsets into version 2 Pyth product and price accounts
+const OFFSET_MAGIC: usize = 0;
+const OFFSET_VERSION: usize = 4;
+const OFFSET_ACCOUNT_TYPE: usize = 8;
+const OFFSET_PRODUCT_PRICE_ACCOUNT: usize = 16;
+const OFFSET_PRICE_TYPE: usize = 16;
+const OFFSET_EXPONENT: usize = 20;
+const OFFSET_AGG_PRICE: usize = 208;
+const OFFSET_AGG_CONFIDENCE: usize = 216;
+const OFFSET_AGG_STATUS: usize = 224;
+const OFFSET_AGG_PUBLISH_SLOT: usize = 232;
+
+const PRODUCT_ACCOUNT_MIN_SIZE: usize = 48;
+const PRICE_ACCOUNT_MIN_SIZE: usize = 240;
+
+/// The reasons a Pyth account can not be used
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OracleError {
+    /// The data is not a version 2 Pyth product account
+    InvalidProductAccount,
+    /// The data is not a version 2 Pyth price account
+    InvalidPriceAccount,
+    /// The aggregate price is not trading
+    PriceNotTrading,
+    /// The aggregate price is older than [`OracleConfig::max_slot_age`]
+    StalePrice,
+    /// The confidence interval is wider than [`OracleConfig::max_confidence_ratio`]
+    ConfidenceTooWide,
+    /// The aggregate price is not positive
+    InvalidPrice,
+}
+
+/// The limits a price has to be within to be used
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    /// The maximum number of slots since the aggregate price was published
+    pub max_slot_age: u64,
+    /// The maximum confidence interval, in basis points of the price
+    pub max_confidence_ratio: u64,
+}
+
+/// A validated aggregate price of `price * 10^exponent`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub confidence: u64,
+    pub exponent: i32,
+    pub publish_slot: u64,
+}
+
+impl OraclePrice {
+    /// Parses price account data, checking that the price is trading, no older than
+    /// `config.max_slot_age` and has a confidence interval within `config.max_confidence_ratio`
+    pub fn parse(
+        data: &[u8],
+        config: &OracleConfig,
+        current_slot: u64,
+    ) -> Result<Self, OracleError> {
+        if !has_header(data, PRICE_ACCOUNT_MIN_SIZE, ACCOUNT_TYPE_PRICE)
+            || read_u32(data, OFFSET_PRICE_TYPE) != PRICE_TYPE_PRICE
+        {
+            return Err(OracleError::InvalidPriceAccount);
+        }
+
+        if read_u32(data, OFFSET_AGG_STATUS) != STATUS_TRADING {
+            return Err(OracleError::PriceNotTrading);
+        }
+
+        let price = OraclePrice {
+            price: read_u64(data, OFFSET_AGG_PRICE) as i64,
+            confidence: read_u64(data, OFFSET_AGG_CONFIDENCE),
+            exponent: read_u32(data, OFFSET_EXPONENT) as i32,
+            publish_slot: read_u64(data, OFFSET_AGG_PUBLISH_SLOT),
+        };
+
+        if current_slot.saturating_sub(price.publish_slot) > config.max_slot_age {
+            return Err(OracleError::StalePrice);
+        }
+
+        if price.price <= 0 {
+            return Err(OracleError::InvalidPrice);
+        }
+
+        if price.confidence as u128 * 10_000
+            > price.price as u128 * config.max_confidence_ratio as u128
+        {
+            return Err(OracleError::ConfidenceTooWide);
+        }
+
+        Ok(price)
+    }
+}
+
+/// Returns the key of the first price account listed in Pyth product account data
+pub fn product_price_account(data: &[u8]) -> Result<[u8; 32], OracleError> {
+    if !has_header(data, PRODUCT_ACCOUNT_MIN_SIZE, ACCOUNT_TYPE_PRODUCT) {
+        return Err(OracleError::InvalidProductAccount);
+    }
+
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&data[OFFSET_PRODUCT_PRICE_ACCOUNT..OFFSET_PRODUCT_PRICE_ACCOUNT + 32]);
+    if key == [0u8; 32] {
+        return Err(OracleError::InvalidProductAccount);
+    }
+
+    Ok(key)
+}
+
+fn has_header(data: &[u8], min_size: usize, account_type: u32) -> bool {
+    data.len() >= min_size
+        && read_u32(data, OFFSET_MAGIC) == MAGIC
+        && read_u32(data, OFFSET_VERSION) == VERSION_2
+        && read_u32(data, OFFSET_ACCOUNT_TYPE) == account_type
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&data[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: OracleConfig = OracleConfig {
+        max_slot_age: 25,
+        max_confidence_ratio: 100,
+    };
+
+    #[test]
+    fn test_parse_trading_price() {
+        let data = make_price_account(2_000_000_000, 1_000_000, STATUS_TRADING, 100);
+
+        assert_eq!(
+            OraclePrice::parse(&data, &CONFIG, 110),
+            Ok(OraclePrice {
+                price: 2_000_000_000,
+                confidence: 1_000_000,
+                exponent: -8,
+                publish_slot: 100,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_halted_price() {
+        let data = make_price_account(2_000_000_000, 0, 2, 100);
+
+        assert_eq!(
+            OraclePrice::parse(&data, &CONFIG, 100),
+            Err(OracleError::PriceNotTrading)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_stale_price() {
+        let data = make_price_account(2_000_000_000, 0, STATUS_TRADING, 100);
+
+        assert_eq!(
+            OraclePrice::parse(&data, &CONFIG, 126),
+            Err(OracleError::StalePrice)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_wide_confidence() {
+        // 2% of the price against a 1% limit
+        let data = make_price_account(2_000_000_000, 40_000_000, STATUS_TRADING, 100);
+
+        assert_eq!(
+            OraclePrice::parse(&data, &CONFIG, 100),
+            Err(OracleError::ConfidenceTooWide)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_non_positive_price() {
+        let data = make_price_account(-1, 0, STATUS_TRADING, 100);
+
+        assert_eq!(
+            OraclePrice::parse(&data, &CONFIG, 100),
+            Err(OracleError::InvalidPrice)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_accounts() {
+        let mut data = make_price_account(2_000_000_000, 0, STATUS_TRADING, 100);
+
+        assert_eq!(
+            OraclePrice::parse(&data[..64], &CONFIG, 100),
+            Err(OracleError::InvalidPriceAccount)
+        );
+
+        data[OFFSET_ACCOUNT_TYPE] = ACCOUNT_TYPE_PRODUCT as u8;
+        assert_eq!(
+            OraclePrice::parse(&data, &CONFIG, 100),
+            Err(OracleError::InvalidPriceAccount)
+        );
+    }
+
+    #[test]
+    fn test_product_price_account() {
+        let price_account = [7u8; 32];
+        let mut data = vec![0u8; 512];
+        write_header(&mut data, ACCOUNT_TYPE_PRODUCT);
+
+        assert_eq!(
+            product_price_account(&data),
+            Err(OracleError::InvalidProductAccount)
+        );
+
+        data[OFFSET_PRODUCT_PRICE_ACCOUNT..OFFSET_PRODUCT_PRICE_ACCOUNT + 32]
+            .copy_from_slice(&price_account);
+        assert_eq!(product_price_account(&data), Ok(price_account));
+    }
+
+    fn write_header(data: &mut [u8], account_type: u32) {
+        data[OFFSET_MAGIC..OFFSET_MAGIC + 4].copy_from_slice(&MAGIC.to_le_bytes());
+        data[OFFSET_VERSION..OFFSET_VERSION + 4].copy_from_slice(&VERSION_2.to_le_bytes());
+        data[OFFSET_ACCOUNT_TYPE..OFFSET_ACCOUNT_TYPE + 4]
+            .copy_from_slice(&account_type.to_le_bytes());
+    }
+
+    fn make_price_account(price: i64, confidence: u64, status: u32, publish_slot: u64) -> Vec<u8> {
+        let mut data = vec![0u8; 3312];
+        write_header(&mut data, ACCOUNT_TYPE_PRICE);
+        data[OFFSET_PRICE_TYPE..OFFSET_PRICE_TYPE + 4]
+            .copy_from_slice(&PRICE_TYPE_PRICE.to_le_bytes());
+        data[OFFSET_EXPONENT..OFFSET_EXPONENT + 4].copy_from_slice(&(-8i32).to_le_bytes());
+        data[OFFSET_AGG_PRICE..OFFSET_AGG_PRICE + 8].copy_from_slice(&price.to_le_bytes());
+        data[OFFSET_AGG_CONFIDENCE..OFFSET_AGG_CONFIDENCE + 8]
+            .copy_from_slice(&confidence.to_le_bytes());
+        data[OFFSET_AGG_STATUS..OFFSET_AGG_STATUS + 4].copy_from_slice(&status.to_le_bytes());
+        data[OFFSET_AGG_PUBLISH_SLOT..OFFSET_AGG_PUBLISH_SLOT + 8]
+            .copy_from_slice(&publish_slot.to_le_bytes());
+        data
+    }
+}
@@ -0,0 +1,49 @@
+//! Program error wrappers around the shared [`pyth_price`] parser.
+use anchor_lang::prelude::*;
+pub use pyth_price::{OracleConfig, OraclePrice};
+use pyth_price::OracleError;
+
+use crate::ErrorCode;
+
+/// Reads and validates the aggregate price of a Pyth price account
+pub fn load_price(
+    price_account: &AccountInfo,
+    config: &OracleConfig,
+    current_slot: u64,
+) -> Result<OraclePrice, ProgramError> {
+    let data = price_account.try_borrow_data()?;
+    OraclePrice::parse(&data, config, current_slot).map_err(to_program_error)
+}
+
+/// Returns the first price account listed in a Pyth product account
+pub fn product_price_account(product_account: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    let data = product_account.try_borrow_data()?;
+    pyth_price::product_price_account(&data)
+        .map(Pubkey::new_from_array)
+        .map_err(to_program_error)
+}
+
+fn to_program_error(error: OracleError) -> ProgramError {
+    match error {
+        OracleError::InvalidProductAccount => ErrorCode::InvalidProductAccount,
+        OracleError::InvalidPriceAccount => ErrorCode::InvalidPriceAccount,
+        OracleError::PriceNotTrading => ErrorCode::PriceNotTrading,
+        OracleError::StalePrice => ErrorCode::StalePrice,
+        OracleError::ConfidenceTooWide => ErrorCode::ConfidenceTooWide,
+        OracleError::InvalidPrice => ErrorCode::InvalidPrice,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_program_error() {
+        assert_eq!(
+            to_program_error(OracleError::StalePrice),
+            ErrorCode::StalePrice.into()
+        );
+    }
+}
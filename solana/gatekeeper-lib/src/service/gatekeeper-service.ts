@@ -212,7 +212,8 @@ export class GatekeeperService {
       revoke(
         gatewayTokenKey,
         this.gatekeeperAuthority.publicKey,
-        gatekeeperAccount
+        gatekeeperAccount,
+        this.gatekeeperNetwork
       ),
       Action.REVOKE,
       options
@@ -233,7 +234,8 @@ export class GatekeeperService {
     const instruction: TransactionInstruction = freeze(
       gatewayTokenKey,
       this.gatekeeperAuthority.publicKey,
-      this.gatekeeperAccountAddress()
+      this.gatekeeperAccountAddress(),
+      this.gatekeeperNetwork
     );
     return this.updateToken(gatewayTokenKey, instruction, Action.FREEZE, options);
   }
@@ -252,7 +254,8 @@ export class GatekeeperService {
     const instruction: TransactionInstruction = unfreeze(
       gatewayTokenKey,
       this.gatekeeperAuthority.publicKey,
-      this.gatekeeperAccountAddress()
+      this.gatekeeperAccountAddress(),
+      this.gatekeeperNetwork
     );
     return this.updateToken(gatewayTokenKey, instruction, Action.UNFREEZE, options);
   }
@@ -287,7 +290,8 @@ export class GatekeeperService {
       gatewayTokenKey,
       this.gatekeeperAuthority.publicKey,
       this.gatekeeperAccountAddress(),
-      expireTime
+      expireTime,
+      this.gatekeeperNetwork
     );
     return this.updateToken(gatewayTokenKey, instruction, Action.REFRESH, options);
   }
@@ -308,6 +312,7 @@ export class GatekeeperService {
         this.gatekeeperAuthority.publicKey,
         this.gatekeeperAccountAddress(),
         this.gatekeeperAuthority.publicKey,
+        this.gatekeeperNetwork,
     );
     const normalizedOptions = await this.optionsWithDefaults(options);
     const transaction = new Transaction().add(instruction);
@@ -1,21 +1,43 @@
 import { Assignable, Enum, SCHEMA } from "./solanaBorsh";
 
 export class UserTokenExpiry extends Assignable {}
+export class OwnerTransfer extends Assignable {}
+export class MaxTokenLifetime extends Assignable {}
+export class FreezeOnly extends Assignable {}
 export class NetworkFeature extends Enum {
   userTokenExpiry?: UserTokenExpiry;
+  ownerTransfer?: OwnerTransfer;
+  maxTokenLifetime?: MaxTokenLifetime;
+  freezeOnly?: FreezeOnly;
 }
 
 SCHEMA.set(NetworkFeature, {
   kind: "enum",
   field: "enum",
-  values: [["userTokenExpiry", UserTokenExpiry]],
+  values: [
+    ["userTokenExpiry", UserTokenExpiry],
+    ["ownerTransfer", OwnerTransfer],
+    ["maxTokenLifetime", MaxTokenLifetime],
+    ["freezeOnly", FreezeOnly],
+  ],
 });
 SCHEMA.set(UserTokenExpiry, { kind: "struct", fields: [] });
+SCHEMA.set(OwnerTransfer, { kind: "struct", fields: [] });
+SCHEMA.set(MaxTokenLifetime, { kind: "struct", fields: [] });
+SCHEMA.set(FreezeOnly, { kind: "struct", fields: [] });
 
+// Must match get_network_feature_seed in state.rs
 export const mapEnumToFeatureName = (enumValue: string) => {
-  if (enumValue === "userTokenExpiry") {
-    return "expire";
+  switch (enumValue) {
+    case "userTokenExpiry":
+      return "expire";
+    case "ownerTransfer":
+      return "transfer";
+    case "maxTokenLifetime":
+      return "max_lifetime";
+    case "freezeOnly":
+      return "freeze_only";
+    default:
+      return enumValue;
   }
-
-  return enumValue;
 };
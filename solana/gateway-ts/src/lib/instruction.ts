@@ -8,7 +8,12 @@ import {
   TransactionInstruction,
 } from "@solana/web3.js";
 import { Active, Frozen, GatewayTokenState, Revoked } from "./GatewayTokenData";
-import { NetworkFeature, UserTokenExpiry } from "./GatewayNetworkData";
+import {
+  FreezeOnly,
+  MaxTokenLifetime,
+  NetworkFeature,
+  UserTokenExpiry,
+} from "./GatewayNetworkData";
 import { getFeatureAccountAddress } from "./util";
 import { AssignablePublicKey } from "./AssignablePublicKey";
 
@@ -196,6 +201,26 @@ export function revokeGatekeeper(
   });
 }
 
+const freezeOnlyFeature = new NetworkFeature({
+  freezeOnly: new FreezeOnly({}),
+});
+const maxTokenLifetimeFeature = new NetworkFeature({
+  maxTokenLifetime: new MaxTokenLifetime({}),
+});
+
+/**
+ * The feature account of the gatekeeper network that an instruction checks the feature in.
+ * The account does not need to exist, in which case the network does not have the feature.
+ */
+const getFeatureAccountMeta = (
+  feature: NetworkFeature,
+  gatekeeperNetwork: PublicKey
+): AccountMeta => ({
+  pubkey: getFeatureAccountAddress(feature, gatekeeperNetwork),
+  isSigner: false,
+  isWritable: false,
+});
+
 /**
  * Issue a gateway token to the owner publicKey.
  * Returns a Solana instruction that must be signed by the gatekeeper authority.
@@ -206,7 +231,7 @@ export function revokeGatekeeper(
  * @param gatekeeperAuthority The gatekeeper issuing the token
  * @param gatekeeperNetwork The network that the gatekeeper belongs to
  * @param seed An 8-byte seed array, used to add multiple tokens to the same owner. Must be unique to each token, if present
- * @param expireTime The unix timestamp at which the token is no longer valid
+ * @param expireTime The unix timestamp at which the token is no longer valid. Must be within the network's maximum token lifetime, if it has one
  */
 export function issue(
   gatewayTokenAccount: PublicKey,
@@ -227,6 +252,7 @@ export function issue(
     { pubkey: gatekeeperNetwork, isSigner: false, isWritable: false },
     { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    getFeatureAccountMeta(maxTokenLifetimeFeature, gatekeeperNetwork),
   ];
   const data = GatewayInstruction.issue(seed, expireTime).encode();
   return new TransactionInstruction({
@@ -239,11 +265,13 @@ export function issue(
 const getStateChangeAccountMeta = (
   gatewayTokenAccount: PublicKey,
   gatekeeperAuthority: PublicKey,
-  gatekeeperAccount: PublicKey
+  gatekeeperAccount: PublicKey,
+  gatekeeperNetwork: PublicKey
 ): AccountMeta[] => [
   { pubkey: gatewayTokenAccount, isSigner: false, isWritable: true },
   { pubkey: gatekeeperAuthority, isSigner: true, isWritable: false },
   { pubkey: gatekeeperAccount, isSigner: false, isWritable: false },
+  getFeatureAccountMeta(freezeOnlyFeature, gatekeeperNetwork),
 ];
 
 /**
@@ -252,16 +280,19 @@ const getStateChangeAccountMeta = (
  * @param gatewayTokenAccount The gateway token to revoke
  * @param gatekeeperAuthority The gatekeeper revoking the token (must be in the same network as the issuing gatekeeper)
  * @param gatekeeperAccount The account in the gatekeeper network of the gatekeeper revoking the token
+ * @param gatekeeperNetwork The network of the token. The revoke fails if the network only allows tokens to be frozen
  */
 export function revoke(
   gatewayTokenAccount: PublicKey,
   gatekeeperAuthority: PublicKey,
-  gatekeeperAccount: PublicKey
+  gatekeeperAccount: PublicKey,
+  gatekeeperNetwork: PublicKey
 ): TransactionInstruction {
   const keys: AccountMeta[] = getStateChangeAccountMeta(
     gatewayTokenAccount,
     gatekeeperAuthority,
-    gatekeeperAccount
+    gatekeeperAccount,
+    gatekeeperNetwork
   );
   const data = GatewayInstruction.revoke().encode();
   return new TransactionInstruction({
@@ -277,16 +308,19 @@ export function revoke(
  * @param gatewayTokenAccount The gateway token to freeze
  * @param gatekeeperAuthority The gatekeeper freezing the token (must be equal to the issuing gatekeeper)
  * @param gatekeeperAccount The account in the gatekeeper network of the gatekeeper freezing the token
+ * @param gatekeeperNetwork The network of the token
  */
 export function freeze(
   gatewayTokenAccount: PublicKey,
   gatekeeperAuthority: PublicKey,
-  gatekeeperAccount: PublicKey
+  gatekeeperAccount: PublicKey,
+  gatekeeperNetwork: PublicKey
 ): TransactionInstruction {
   const keys: AccountMeta[] = getStateChangeAccountMeta(
     gatewayTokenAccount,
     gatekeeperAuthority,
-    gatekeeperAccount
+    gatekeeperAccount,
+    gatekeeperNetwork
   );
   const data = GatewayInstruction.freeze().encode();
   return new TransactionInstruction({
@@ -302,16 +336,19 @@ export function freeze(
  * @param gatewayTokenAccount The gateway token to unfreeze
  * @param gatekeeperAuthority The gatekeeper unfreezing the token (must be equal to the issuing gatekeeper)
  * @param gatekeeperAccount The account in the gatekeeper network of the gatekeeper unfreezing the token
+ * @param gatekeeperNetwork The network of the token
  */
 export function unfreeze(
   gatewayTokenAccount: PublicKey,
   gatekeeperAuthority: PublicKey,
-  gatekeeperAccount: PublicKey
+  gatekeeperAccount: PublicKey,
+  gatekeeperNetwork: PublicKey
 ): TransactionInstruction {
   const keys: AccountMeta[] = getStateChangeAccountMeta(
     gatewayTokenAccount,
     gatekeeperAuthority,
-    gatekeeperAccount
+    gatekeeperAccount,
+    gatekeeperNetwork
   );
   const data = GatewayInstruction.unfreeze().encode();
   return new TransactionInstruction({
@@ -328,17 +365,20 @@ export function unfreeze(
  * @param gatekeeperAuthority The gatekeeper (must be equal to the issuing gatekeeper)
  * @param gatekeeperAccount The account in the gatekeeper network of the gatekeeper
  * @param expireTime The new expiry time
+ * @param gatekeeperNetwork The network of the token. The expiry time must be within the network's maximum token lifetime, if it has one
  */
 export function updateExpiry(
   gatewayTokenAccount: PublicKey,
   gatekeeperAuthority: PublicKey,
   gatekeeperAccount: PublicKey,
-  expireTime: number
+  expireTime: number,
+  gatekeeperNetwork: PublicKey
 ): TransactionInstruction {
   const keys: AccountMeta[] = [
     { pubkey: gatewayTokenAccount, isSigner: false, isWritable: true },
     { pubkey: gatekeeperAuthority, isSigner: true, isWritable: false },
     { pubkey: gatekeeperAccount, isSigner: false, isWritable: false },
+    getFeatureAccountMeta(maxTokenLifetimeFeature, gatekeeperNetwork),
   ];
   const data = GatewayInstruction.updateExpiry(expireTime).encode();
   return new TransactionInstruction({
@@ -441,20 +481,21 @@ export function expireToken(
  * @param gatekeeperAuthority The gatekeeper burning the token (must be in the same network as the issuing gatekeeper)
  * @param gatekeeperAccount The account in the gatekeeper network of the gatekeeper revoking the token
  * @param recipient The account to receive the SOL from the burn
+ * @param gatekeeperNetwork The network of the token. The burn fails if the network only allows tokens to be frozen
  */
 export function burn(
   gatewayTokenAccount: PublicKey,
   gatekeeperAuthority: PublicKey,
   gatekeeperAccount: PublicKey,
-  recipient: PublicKey
+  recipient: PublicKey,
+  gatekeeperNetwork: PublicKey
 ): TransactionInstruction {
   const keys: AccountMeta[] = [
-    ...getStateChangeAccountMeta(
-      gatewayTokenAccount,
-      gatekeeperAuthority,
-      gatekeeperAccount
-    ),
+    { pubkey: gatewayTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: gatekeeperAuthority, isSigner: true, isWritable: false },
+    { pubkey: gatekeeperAccount, isSigner: false, isWritable: false },
     { pubkey: recipient, isSigner: false, isWritable: true },
+    getFeatureAccountMeta(freezeOnlyFeature, gatekeeperNetwork),
   ];
   const data = GatewayInstruction.burnToken().encode();
   return new TransactionInstruction({
@@ -4,8 +4,10 @@ import sinon from "sinon";
 import { PublicKey, Keypair } from "@solana/web3.js";
 import { describe } from "mocha";
 import {
+  FreezeOnly,
   getFeatureAccountAddress,
   NetworkFeature,
+  PROGRAM_ID,
   UserTokenExpiry,
 } from "../../src";
 
@@ -39,5 +41,22 @@ describe("GatewayNetwork", () => {
         "BNkYz4VZFuNaLey1hF1GCjFfN1p11trYouGPKqwH7ioJ"
       );
     });
+
+    it("derives the featureAccount from the program's feature seed", () => {
+      const freezeOnlyFeature = new NetworkFeature({
+        freezeOnly: new FreezeOnly({}),
+      });
+
+      const featureAddress = getFeatureAccountAddress(
+        freezeOnlyFeature,
+        gatekeeperNetworkKey
+      );
+      expect(featureAddress.toBase58()).to.equal(
+        PublicKey.findProgramAddressSync(
+          [gatekeeperNetworkKey.toBytes(), Buffer.from("freeze_only", "utf8")],
+          PROGRAM_ID
+        )[0].toBase58()
+      );
+    });
   });
 });
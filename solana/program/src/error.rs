@@ -1,6 +1,7 @@
 //! Error types
 
 use {
+    crate::state::StateChangeReason,
     num_derive::FromPrimitive,
     solana_program::{decode_error::DecodeError, program_error::ProgramError},
     thiserror::Error,
@@ -44,6 +45,72 @@ pub enum GatewayError {
     /// An error occurred when burning the token
     #[error("An error occurred when burning the token")]
     BurnError,
+
+    /// The gateway token was revoked or frozen because of fraud
+    #[error("The gateway token was revoked or frozen because of fraud")]
+    TokenInvalidFraud,
+
+    /// The gateway token was revoked or frozen at the owner's request
+    #[error("The gateway token was revoked or frozen at the owner's request")]
+    TokenInvalidUserRequested,
+
+    /// The gateway token was revoked or frozen because the owner wallet is compromised
+    #[error("The gateway token was revoked or frozen because the owner wallet is compromised")]
+    TokenInvalidKeyCompromised,
+
+    /// The gateway token was revoked or frozen because it was replaced by another token
+    #[error("The gateway token was revoked or frozen because it was replaced by another token")]
+    TokenInvalidSuperseded,
+
+    /// The gateway token was revoked or frozen because the owner no longer meets the requirements
+    #[error(
+        "The gateway token was revoked or frozen because the owner no longer meets the requirements"
+    )]
+    TokenInvalidRequirementsNotMet,
+
+    /// The gateway token was revoked or frozen for a network-specific reason
+    #[error("The gateway token was revoked or frozen for a network-specific reason")]
+    TokenInvalidOtherReason,
+
+    /// The gateway token would expire later than the gatekeeper network allows
+    #[error("The gateway token would expire later than the gatekeeper network allows")]
+    TokenLifetimeTooLong,
+
+    /// The gateway token must be expired or revoked
+    #[error("The gateway token must be expired or revoked")]
+    TokenStillActive,
+
+    /// The gatekeeper that issued the gateway token has been removed
+    #[error("The gatekeeper that issued the gateway token has been removed")]
+    GatekeeperRemoved,
+
+    /// The gatekeeper's state does not allow the operation
+    #[error("The gatekeeper's state does not allow the operation")]
+    InvalidGatekeeperState,
+
+    /// The gatekeeper delegate does not have permission for the operation
+    #[error("The gatekeeper delegate does not have permission for the operation")]
+    DelegateNotPermitted,
+
+    /// The gatekeeper network does not allow owners to burn their gateway tokens
+    #[error("The gatekeeper network does not allow owners to burn their gateway tokens")]
+    OwnerBurnNotEnabled,
+}
+impl GatewayError {
+    /// The error for a revoked or frozen gateway token, given the recorded reason
+    pub fn token_invalid(reason: Option<StateChangeReason>) -> Self {
+        match reason {
+            None => GatewayError::TokenRevoked,
+            Some(StateChangeReason::Fraud) => GatewayError::TokenInvalidFraud,
+            Some(StateChangeReason::UserRequested) => GatewayError::TokenInvalidUserRequested,
+            Some(StateChangeReason::KeyCompromised) => GatewayError::TokenInvalidKeyCompromised,
+            Some(StateChangeReason::Superseded) => GatewayError::TokenInvalidSuperseded,
+            Some(StateChangeReason::RequirementsNotMet) => {
+                GatewayError::TokenInvalidRequirementsNotMet
+            }
+            Some(StateChangeReason::Other(_)) => GatewayError::TokenInvalidOtherReason,
+        }
+    }
 }
 impl From<GatewayError> for ProgramError {
     fn from(e: GatewayError) -> Self {
@@ -1,18 +1,18 @@
 //! Program instructions
 
-use crate::state::get_expire_address_with_seed;
+use crate::state::{get_expire_address_with_seed, get_network_feature_address};
 use crate::Gateway;
 use solana_program::clock::UnixTimestamp;
 use {
-    crate::state::GatewayTokenState,
     crate::state::{
         get_gatekeeper_account_address, get_gateway_token_address_with_seed, AddressSeed,
     },
+    crate::state::{GatekeeperPermissions, GatekeeperState, GatewayTokenState, StateChangeReason},
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::{
         instruction::{AccountMeta, Instruction},
         pubkey::Pubkey,
-        system_program,
+        system_program, sysvar,
     },
 };
 
@@ -40,10 +40,11 @@ pub enum GatewayInstruction {
     /// 1. `[writable]`            gateway_token: the destination account of the gateway token
     /// 2. `[]`                    owner: the wallet that the gateway token is issued for
     /// 3. `[]`                    gatekeeper_account: the account containing details of the gatekeeper issuing the gateway token
-    /// 4. `[signer]`              gatekeeper_authority: the authority that owns the gatekeeper account
+    /// 4. `[signer]`              gatekeeper_authority: the authority that owns the gatekeeper account, or a delegate of it
     /// 5. `[]`                    gatekeeper_network: the gatekeeper network to which the gatekeeper belongs
     /// 6. `[]`                    Rent sysvar
     /// 7. `[]`                    System program
+    /// 8. `[]`                    max_token_lifetime_feature: the MaxTokenLifetime feature account for the gatekeeper network
     Issue {
         /// An optional seed to use when generating a gateway token
         /// allowing multiple gateway tokens per wallet
@@ -61,8 +62,9 @@ pub enum GatewayInstruction {
     /// Accounts expected by this instruction:
     ///
     /// 0. `[writable]`            gateway_token: the destination account of the gateway token
-    /// 1. `[signer]`              gatekeeper_authority: the gatekeeper authority that is making the change
+    /// 1. `[signer]`              gatekeeper_authority: the gatekeeper authority that is making the change, or a delegate of it
     /// 2. `[]`                    gatekeeper_account: the account containing details of the gatekeeper
+    /// 3. `[]`                    freeze_only_feature: the FreezeOnly feature account for the gatekeeper network of the gateway token
     SetState {
         /// The new state of the gateway token
         state: GatewayTokenState,
@@ -73,8 +75,9 @@ pub enum GatewayInstruction {
     /// Accounts expected by this instruction:
     ///
     /// 0. `[writable]`            gateway_token: the destination account of the gateway token
-    /// 1. `[signer]`              gatekeeper_authority: the gatekeeper authority that is making the change
+    /// 1. `[signer]`              gatekeeper_authority: the gatekeeper authority that is making the change, or a delegate of it
     /// 2. `[]`                    gatekeeper_account: the account containing details of the gatekeeper
+    /// 3. `[]`                    max_token_lifetime_feature: the MaxTokenLifetime feature account for the gatekeeper network of the gateway token
     UpdateExpiry {
         ///  the new expiry time of the gateway token
         expire_time: UnixTimestamp,
@@ -138,10 +141,157 @@ pub enum GatewayInstruction {
     /// Accounts expected by this instruction:
     ///
     /// 0. `[writable]`            gateway_token: the account of the gateway token
-    /// 1. `[signer]`              gatekeeper_authority: the gatekeeper authority that is burning the token
+    /// 1. `[signer]`              gatekeeper_authority: the gatekeeper authority that is burning the token, or a delegate of it
     /// 2. `[]`                    gatekeeper_account: the gatekeeper account linking the gatekeeper authority to the gatekeeper network
     /// 3. `[writeable]`           recipient: the recipient of the lamports in the gateway token account
+    /// 4. `[]`                    freeze_only_feature: the FreezeOnly feature account for the gatekeeper network of the gateway token
     BurnToken,
+
+    /// Issue a gateway token to each of several owners in a single instruction
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable, signer]`    funder_account: the payer of the transaction
+    /// 1. `[]`                    gatekeeper_account: the account containing details of the gatekeeper issuing the gateway tokens
+    /// 2. `[signer]`              gatekeeper_authority: the authority that owns the gatekeeper account, or a delegate of it
+    /// 3. `[]`                    gatekeeper_network: the gatekeeper network to which the gatekeeper belongs
+    /// 4. `[]`                    System program
+    /// 5. `[]`                    max_token_lifetime_feature: the MaxTokenLifetime feature account for the gatekeeper network
+    ///
+    /// Followed by one pair of accounts per entry, in the same order as `entries`:
+    ///
+    /// 6 + 2n. `[writable]`       gateway_token: the destination account of the nth gateway token
+    /// 7 + 2n. `[]`               owner: the wallet that the nth gateway token is issued for
+    IssueBatch {
+        /// The seed and expiry of each gateway token to issue
+        entries: Vec<IssueBatchEntry>,
+    },
+
+    /// Move a gateway token to a new owner wallet, in a gatekeeper network with the OwnerTransfer feature
+    ///
+    /// The gateway token is recreated at the new owner's address with the same gatekeeper network,
+    /// issuing gatekeeper, state and expiry, and the old gateway token account is deleted.
+    /// The current owner and a gatekeeper in the gateway token's network must both sign.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable, signer]`    funder_account: the payer of the new gateway token, which receives the lamports of the old one
+    /// 1. `[writable]`            gateway_token: the gateway token to transfer
+    /// 2. `[signer]`              owner: the wallet that the gateway token is currently for
+    /// 3. `[writable]`            new_gateway_token: the destination account of the gateway token
+    /// 4. `[]`                    new_owner: the wallet that the gateway token is transferred to
+    /// 5. `[signer]`              gatekeeper_authority: the gatekeeper authority approving the transfer, or a delegate of it
+    /// 6. `[]`                    gatekeeper_account: the account containing details of the gatekeeper
+    /// 7. `[]`                    network_transfer_feature: The OwnerTransfer feature account for the gatekeeper network
+    /// 8. `[]`                    System program
+    TransferToNewOwner {
+        /// An optional seed to use when generating the new gateway token address
+        seed: Option<AddressSeed>,
+    },
+
+    /// Update the gateway token state, recording why it was changed
+    ///
+    /// As `SetState`, but also stores the reason alongside the time of the change,
+    /// for gateway tokens with a version that records state changes.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]`            gateway_token: the destination account of the gateway token
+    /// 1. `[signer]`              gatekeeper_authority: the gatekeeper authority that is making the change, or a delegate of it
+    /// 2. `[]`                    gatekeeper_account: the account containing details of the gatekeeper
+    /// 3. `[]`                    freeze_only_feature: the FreezeOnly feature account for the gatekeeper network of the gateway token
+    SetStateWithReason {
+        /// The new state of the gateway token
+        state: GatewayTokenState,
+        /// Why the state is being changed
+        reason: Option<StateChangeReason>,
+    },
+
+    /// Upgrade a version 0 gateway token to version 1, reallocating it to make room for the extension area
+    ///
+    /// Anyone may upgrade a gateway token, as long as they pay for the additional rent.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable, signer]`    funder_account: the payer of the additional rent
+    /// 1. `[writable]`            gateway_token: the gateway token to upgrade
+    /// 2. `[]`                    System program
+    UpgradeToken {
+        /// The seed used when the gateway token address was generated
+        seed: Option<AddressSeed>,
+    },
+
+    /// Add or update a feature of a gatekeeper network that holds a configuration
+    ///
+    /// The feature account is created if the gatekeeper network does not have the feature yet.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[signer, writable]` funder_account: The account funding this transaction
+    /// 1. `[signer]`           gatekeeper_network: The gatekeeper network whose feature is updated
+    /// 2. `[writable]`         feature_account: The feature account
+    /// 3. `[]`                 system_program: The system program
+    UpdateNetworkFeature { config: NetworkFeatureConfig },
+
+    /// Burn a gateway token as its owner, in a gatekeeper network with the OwnerBurn feature,
+    /// closing the account and returning its lamports to the owner
+    ///
+    /// If the network's OwnerBurn configuration requires it, the gateway token must already be
+    /// expired or revoked.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]`            gateway_token: the gateway token to burn
+    /// 1. `[writable, signer]`    owner: the wallet that the gateway token is for, which receives its lamports
+    /// 2. `[]`                    owner_burn_feature: The OwnerBurn feature account for the gatekeeper network
+    OwnerBurnToken,
+
+    /// Set the state of a gatekeeper, e.g. to suspend it while it is investigated
+    ///
+    /// A frozen gatekeeper may not issue gateway tokens or extend their expiry, and a halted gatekeeper
+    /// may not change gateway tokens at all. Gateway tokens of a halted gatekeeper are only rejected
+    /// by verification that is passed the gatekeeper account with the `check_gatekeeper_account` option set.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable, signer]`    funder_account: the payer of any additional rent
+    /// 1. `[writable]`            gatekeeper_account: the account containing details of the gatekeeper
+    /// 2. `[]`                    gatekeeper_authority: the authority that owns the gatekeeper account
+    /// 3. `[signer]`              gatekeeper_network: the gatekeeper network to which the gatekeeper belongs
+    /// 4. `[]`                    System program
+    SetGatekeeperState {
+        /// The new state of the gatekeeper
+        state: GatekeeperState,
+    },
+
+    /// Add, update or remove a delegate key that may sign for a gatekeeper, within its permissions
+    ///
+    /// A delegate signs in place of the gatekeeper authority, and gateway tokens it issues record
+    /// the gatekeeper authority as their issuing gatekeeper. Setting no permissions removes the delegate.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable, signer]`    funder_account: the payer of any additional rent
+    /// 1. `[writable]`            gatekeeper_account: the account containing details of the gatekeeper
+    /// 2. `[signer]`              gatekeeper_authority: the authority that owns the gatekeeper account
+    /// 3. `[]`                    gatekeeper_network: the gatekeeper network to which the gatekeeper belongs
+    /// 4. `[]`                    System program
+    SetGatekeeperDelegate {
+        /// The delegate signer
+        delegate: Pubkey,
+        /// The operations the delegate may sign for
+        permissions: GatekeeperPermissions,
+    },
+}
+
+/// The per-owner arguments of a `GatewayInstruction::IssueBatch` instruction
+#[derive(Clone, Copy, Debug, Default, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub struct IssueBatchEntry {
+    /// An optional seed to use when generating the gateway token
+    /// allowing multiple gateway tokens per wallet
+    pub seed: Option<AddressSeed>,
+    /// An optional unix timestamp at which point the issued token is no longer valid
+    pub expire_time: Option<UnixTimestamp>,
 }
 
 /// Features are properties of a gatekeeper network that can be enabled or disabled.
@@ -158,6 +308,54 @@ pub enum NetworkFeature {
     /// or not.
     ///
     UserTokenExpiry,
+    /// The OwnerTransfer feature allows a gateway token to be moved to a new owner wallet,
+    /// e.g. when a user rotates their keys, with the approval of a gatekeeper in the network.
+    OwnerTransfer,
+    /// The MaxTokenLifetime feature limits how far in the future gatekeepers can set the expire time
+    /// of a gateway token, when issuing it or updating its expiry.
+    ///
+    /// This feature holds a configuration, so is added with `UpdateNetworkFeature`.
+    MaxTokenLifetime,
+    /// The FreezeOnly feature stops gatekeepers from revoking or burning gateway tokens, so that they can
+    /// only be frozen, and later unfrozen.
+    FreezeOnly,
+    /// The OwnerBurn feature allows users to burn their own gateway tokens, reclaiming the rent.
+    ///
+    /// This feature holds a configuration, so is added with `UpdateNetworkFeature`.
+    OwnerBurn,
+}
+impl NetworkFeature {
+    /// Whether the feature account holds a `NetworkFeatureConfig`
+    pub fn has_config(&self) -> bool {
+        matches!(
+            self,
+            NetworkFeature::MaxTokenLifetime | NetworkFeature::OwnerBurn
+        )
+    }
+}
+
+/// The configuration held in the account of a network feature.
+#[derive(Copy, Clone, Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub enum NetworkFeatureConfig {
+    /// The configuration of the MaxTokenLifetime feature
+    MaxTokenLifetime {
+        /// The number of seconds after the current time that a gateway token may expire at, at most
+        max_lifetime: UnixTimestamp,
+    },
+    /// The configuration of the OwnerBurn feature
+    OwnerBurn {
+        /// Whether the gateway token must be expired or revoked before its owner can burn it
+        require_inactive: bool,
+    },
+}
+impl NetworkFeatureConfig {
+    /// The feature that is configured
+    pub fn feature(&self) -> NetworkFeature {
+        match self {
+            NetworkFeatureConfig::MaxTokenLifetime { .. } => NetworkFeature::MaxTokenLifetime,
+            NetworkFeatureConfig::OwnerBurn { .. } => NetworkFeature::OwnerBurn,
+        }
+    }
 }
 
 /// Create a `GatewayInstruction::AddGatekeeper` instruction
@@ -192,6 +390,8 @@ pub fn issue(
     expire_time: Option<UnixTimestamp>, // optional unix timestamp at which point the issued token is no longer valid
 ) -> Instruction {
     let (gateway_token, _) = get_gateway_token_address_with_seed(owner, &seed, gatekeeper_network);
+    let (max_token_lifetime_feature, _) =
+        get_network_feature_address(gatekeeper_network, NetworkFeature::MaxTokenLifetime);
     Instruction::new_with_borsh(
         Gateway::program_id(),
         &GatewayInstruction::Issue { seed, expire_time },
@@ -202,7 +402,9 @@ pub fn issue(
             AccountMeta::new_readonly(*gatekeeper_account, false),
             AccountMeta::new_readonly(*gatekeeper_authority, true),
             AccountMeta::new_readonly(*gatekeeper_network, false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(max_token_lifetime_feature, false),
         ],
     )
 }
@@ -212,6 +414,7 @@ pub fn set_state(
     gateway_token: &Pubkey,                 // the gateway token account
     gatekeeper_authority: &Pubkey,          // the authority that owns the gatekeeper account
     gatekeeper_account: &Pubkey, // the account containing details of the gatekeeper issuing the gateway token
+    gatekeeper_network: &Pubkey, // the gatekeeper network of the gateway token
     gateway_token_state: GatewayTokenState, // the state of the token to transition to
 ) -> Instruction {
     Instruction::new_with_borsh(
@@ -223,6 +426,53 @@ pub fn set_state(
             AccountMeta::new(*gateway_token, false),
             AccountMeta::new_readonly(*gatekeeper_authority, true),
             AccountMeta::new_readonly(*gatekeeper_account, false),
+            AccountMeta::new_readonly(
+                get_network_feature_address(gatekeeper_network, NetworkFeature::FreezeOnly).0,
+                false,
+            ),
+        ],
+    )
+}
+
+/// Create a `GatewayInstruction::SetStateWithReason` instruction
+pub fn set_state_with_reason(
+    gateway_token: &Pubkey,                 // the gateway token account
+    gatekeeper_authority: &Pubkey,          // the authority that owns the gatekeeper account
+    gatekeeper_account: &Pubkey, // the account containing details of the gatekeeper issuing the gateway token
+    gatekeeper_network: &Pubkey, // the gatekeeper network of the gateway token
+    gateway_token_state: GatewayTokenState, // the state of the token to transition to
+    reason: Option<StateChangeReason>, // why the state of the token is being changed
+) -> Instruction {
+    Instruction::new_with_borsh(
+        Gateway::program_id(),
+        &GatewayInstruction::SetStateWithReason {
+            state: gateway_token_state,
+            reason,
+        },
+        vec![
+            AccountMeta::new(*gateway_token, false),
+            AccountMeta::new_readonly(*gatekeeper_authority, true),
+            AccountMeta::new_readonly(*gatekeeper_account, false),
+            AccountMeta::new_readonly(
+                get_network_feature_address(gatekeeper_network, NetworkFeature::FreezeOnly).0,
+                false,
+            ),
+        ],
+    )
+}
+
+/// Create a `GatewayInstruction::UpgradeToken` instruction
+pub fn upgrade_token(
+    funder_account: &Pubkey,   // the payer of the additional rent
+    gateway_token: &Pubkey,    // the gateway token account
+    seed: Option<AddressSeed>, // the seed used when generating the gateway token address
+) -> Instruction {
+    Instruction::new_with_borsh(
+        Gateway::program_id(),
+        &GatewayInstruction::UpgradeToken { seed },
+        vec![
+            AccountMeta::new(*funder_account, true),
+            AccountMeta::new(*gateway_token, false),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
     )
@@ -233,7 +483,8 @@ pub fn update_expiry(
     gateway_token: &Pubkey,        // the gateway token account
     gatekeeper_authority: &Pubkey, // the authority that owns the gatekeeper account
     gatekeeper_account: &Pubkey, // the account containing details of the gatekeeper that issued the gateway token
-    expire_time: UnixTimestamp,  // new expiry time for the accountn
+    gatekeeper_network: &Pubkey, // the gatekeeper network of the gateway token
+    expire_time: UnixTimestamp,  // new expiry time for the account
 ) -> Instruction {
     Instruction::new_with_borsh(
         Gateway::program_id(),
@@ -242,6 +493,10 @@ pub fn update_expiry(
             AccountMeta::new(*gateway_token, false),
             AccountMeta::new_readonly(*gatekeeper_authority, true),
             AccountMeta::new_readonly(*gatekeeper_account, false),
+            AccountMeta::new_readonly(
+                get_network_feature_address(gatekeeper_network, NetworkFeature::MaxTokenLifetime).0,
+                false,
+            ),
         ],
     )
 }
@@ -266,6 +521,54 @@ pub fn remove_gatekeeper(
     )
 }
 
+/// Create a `GatewayInstruction::SetGatekeeperState` instruction
+pub fn set_gatekeeper_state(
+    funder_account: &Pubkey,
+    gatekeeper_authority: &Pubkey,
+    gatekeeper_network: &Pubkey,
+    state: GatekeeperState,
+) -> Instruction {
+    let (gatekeeper_account, _) =
+        get_gatekeeper_account_address(gatekeeper_authority, gatekeeper_network);
+    Instruction::new_with_borsh(
+        Gateway::program_id(),
+        &GatewayInstruction::SetGatekeeperState { state },
+        vec![
+            AccountMeta::new(*funder_account, true),
+            AccountMeta::new(gatekeeper_account, false),
+            AccountMeta::new_readonly(*gatekeeper_authority, false),
+            AccountMeta::new_readonly(*gatekeeper_network, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// Create a `GatewayInstruction::SetGatekeeperDelegate` instruction
+pub fn set_gatekeeper_delegate(
+    funder_account: &Pubkey,
+    gatekeeper_authority: &Pubkey,
+    gatekeeper_network: &Pubkey,
+    delegate: &Pubkey,
+    permissions: GatekeeperPermissions,
+) -> Instruction {
+    let (gatekeeper_account, _) =
+        get_gatekeeper_account_address(gatekeeper_authority, gatekeeper_network);
+    Instruction::new_with_borsh(
+        Gateway::program_id(),
+        &GatewayInstruction::SetGatekeeperDelegate {
+            delegate: *delegate,
+            permissions,
+        },
+        vec![
+            AccountMeta::new(*funder_account, true),
+            AccountMeta::new(gatekeeper_account, false),
+            AccountMeta::new_readonly(*gatekeeper_authority, true),
+            AccountMeta::new_readonly(*gatekeeper_network, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
 /// Create a `GatewayInstruction::ExpireToken` instruction
 pub fn expire_token(
     gateway_token: Pubkey,
@@ -296,7 +599,10 @@ pub fn add_feature_to_network(
         vec![
             AccountMeta::new(funder, true),
             AccountMeta::new_readonly(gatekeeper_network, true),
-            AccountMeta::new(get_expire_address_with_seed(&gatekeeper_network).0, false),
+            AccountMeta::new(
+                get_network_feature_address(&gatekeeper_network, feature).0,
+                false,
+            ),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
     )
@@ -312,7 +618,31 @@ pub fn remove_feature_from_network(
         vec![
             AccountMeta::new(funds_to, false),
             AccountMeta::new_readonly(gatekeeper_network, true),
-            AccountMeta::new(get_expire_address_with_seed(&gatekeeper_network).0, false),
+            AccountMeta::new(
+                get_network_feature_address(&gatekeeper_network, feature).0,
+                false,
+            ),
+        ],
+    )
+}
+
+/// Create a `GatewayInstruction::UpdateNetworkFeature` instruction
+pub fn update_network_feature(
+    funder: Pubkey,
+    gatekeeper_network: Pubkey,
+    config: NetworkFeatureConfig,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        Gateway::program_id(),
+        &GatewayInstruction::UpdateNetworkFeature { config },
+        vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(gatekeeper_network, true),
+            AccountMeta::new(
+                get_network_feature_address(&gatekeeper_network, config.feature()).0,
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
         ],
     )
 }
@@ -322,6 +652,7 @@ pub fn burn_token(
     gateway_token: &Pubkey,        // the gateway token account
     gatekeeper_authority: &Pubkey, // the authority that owns the gatekeeper account
     gatekeeper_account: &Pubkey, // the account containing details of the gatekeeper that issued the gateway token
+    gatekeeper_network: &Pubkey, // the gatekeeper network of the gateway token
     recipient: &Pubkey,          // recipient of the lamports stored in the gateway token account
 ) -> Instruction {
     Instruction::new_with_borsh(
@@ -332,6 +663,98 @@ pub fn burn_token(
             AccountMeta::new_readonly(*gatekeeper_authority, true),
             AccountMeta::new_readonly(*gatekeeper_account, false),
             AccountMeta::new(*recipient, false),
+            AccountMeta::new_readonly(
+                get_network_feature_address(gatekeeper_network, NetworkFeature::FreezeOnly).0,
+                false,
+            ),
+        ],
+    )
+}
+
+/// Create a `GatewayInstruction::OwnerBurnToken` instruction
+pub fn owner_burn_token(
+    gateway_token: &Pubkey,      // the gateway token account
+    owner: &Pubkey,              // the wallet that the gateway token is for
+    gatekeeper_network: &Pubkey, // the gatekeeper network of the gateway token
+) -> Instruction {
+    Instruction::new_with_borsh(
+        Gateway::program_id(),
+        &GatewayInstruction::OwnerBurnToken,
+        vec![
+            AccountMeta::new(*gateway_token, false),
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(
+                get_network_feature_address(gatekeeper_network, NetworkFeature::OwnerBurn).0,
+                false,
+            ),
+        ],
+    )
+}
+
+/// Create a `GatewayInstruction::IssueBatch` instruction
+pub fn issue_batch(
+    funder_account: &Pubkey,              // the payer of the transaction
+    gatekeeper_account: &Pubkey, // the account containing details of the gatekeeper issuing the gateway tokens
+    gatekeeper_authority: &Pubkey, // the authority that owns the gatekeeper account
+    gatekeeper_network: &Pubkey, // the gatekeeper network to which the gatekeeper belongs
+    owners: &[(Pubkey, IssueBatchEntry)], // the wallets that the gateway tokens are issued for
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*funder_account, true),
+        AccountMeta::new_readonly(*gatekeeper_account, false),
+        AccountMeta::new_readonly(*gatekeeper_authority, true),
+        AccountMeta::new_readonly(*gatekeeper_network, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(
+            get_network_feature_address(gatekeeper_network, NetworkFeature::MaxTokenLifetime).0,
+            false,
+        ),
+    ];
+    for (owner, entry) in owners {
+        let (gateway_token, _) =
+            get_gateway_token_address_with_seed(owner, &entry.seed, gatekeeper_network);
+        accounts.push(AccountMeta::new(gateway_token, false));
+        accounts.push(AccountMeta::new_readonly(*owner, false));
+    }
+
+    Instruction::new_with_borsh(
+        Gateway::program_id(),
+        &GatewayInstruction::IssueBatch {
+            entries: owners.iter().map(|(_, entry)| *entry).collect(),
+        },
+        accounts,
+    )
+}
+
+/// Create a `GatewayInstruction::TransferToNewOwner` instruction
+pub fn transfer_to_new_owner(
+    funder_account: &Pubkey,       // the payer of the new gateway token account
+    gateway_token: &Pubkey,        // the gateway token account to transfer
+    owner: &Pubkey,                // the wallet that the gateway token is currently for
+    new_owner: &Pubkey,            // the wallet that the gateway token is transferred to
+    gatekeeper_authority: &Pubkey, // the gatekeeper authority approving the transfer
+    gatekeeper_network: &Pubkey,   // the gatekeeper network of the gateway token
+    seed: Option<AddressSeed>,     // optional seed to use when generating the new gateway token
+) -> Instruction {
+    let (new_gateway_token, _) =
+        get_gateway_token_address_with_seed(new_owner, &seed, gatekeeper_network);
+    let (gatekeeper_account, _) =
+        get_gatekeeper_account_address(gatekeeper_authority, gatekeeper_network);
+    let (transfer_feature, _) =
+        get_network_feature_address(gatekeeper_network, NetworkFeature::OwnerTransfer);
+    Instruction::new_with_borsh(
+        Gateway::program_id(),
+        &GatewayInstruction::TransferToNewOwner { seed },
+        vec![
+            AccountMeta::new(*funder_account, true),
+            AccountMeta::new(*gateway_token, false),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(new_gateway_token, false),
+            AccountMeta::new_readonly(*new_owner, false),
+            AccountMeta::new_readonly(*gatekeeper_authority, true),
+            AccountMeta::new_readonly(gatekeeper_account, false),
+            AccountMeta::new_readonly(transfer_feature, false),
+            AccountMeta::new_readonly(system_program::id(), false),
         ],
     )
 }
@@ -355,9 +778,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn issue_batch_accounts_follow_entries() {
+        let gatekeeper_network = Pubkey::new_unique();
+        let owners = [
+            (Pubkey::new_unique(), IssueBatchEntry::default()),
+            (
+                Pubkey::new_unique(),
+                IssueBatchEntry {
+                    seed: Some([1; 8]),
+                    expire_time: Some(100),
+                },
+            ),
+        ];
+        let instruction = issue_batch(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &gatekeeper_network,
+            &owners,
+        );
+
+        assert_eq!(instruction.accounts.len(), 6 + 2 * owners.len());
+        for (i, (owner, entry)) in owners.iter().enumerate() {
+            let (gateway_token, _) =
+                get_gateway_token_address_with_seed(owner, &entry.seed, &gatekeeper_network);
+            assert_eq!(instruction.accounts[6 + 2 * i].pubkey, gateway_token);
+            assert!(instruction.accounts[6 + 2 * i].is_writable);
+            assert_eq!(instruction.accounts[7 + 2 * i].pubkey, *owner);
+        }
+        assert_eq!(
+            GatewayInstruction::try_from_slice(&instruction.data).unwrap(),
+            GatewayInstruction::IssueBatch {
+                entries: owners.iter().map(|(_, entry)| *entry).collect(),
+            }
+        );
+    }
+
     #[test]
     fn deserialize_invalid_instruction() {
-        let expected = vec![12];
+        let expected = vec![100];
         let err: ProgramError = GatewayInstruction::try_from_slice(&expected)
             .unwrap_err()
             .into();
@@ -14,7 +14,10 @@ pub mod state;
 mod test_utils;
 
 use crate::instruction::expire_token;
-use crate::state::{GatewayTokenAccess, GatewayTokenFunctions};
+use crate::state::{
+    get_gatekeeper_account_address, GatekeeperAccount, GatekeeperState, GatewayTokenAccess,
+    GatewayTokenFunctions, StateChangeReason,
+};
 use crate::{
     error::GatewayError,
     state::{GatewayToken, GatewayTokenState},
@@ -34,20 +37,43 @@ pub struct VerificationOptions {
     /// Number of seconds to allow a token to have expired by, for it still to be counted as active.
     /// Defaults to 0. Must be set if check_expiry is true.
     pub expiry_tolerance_seconds: Option<u32>,
+    /// If true, consider a token invalid once the gatekeeper that issued it has been removed from
+    /// the network or halted. Only checked by [`Gateway::verify_gateway_token_account_info_with_gatekeeper`],
+    /// which is passed the gatekeeper account. Defaults to false
+    pub check_gatekeeper_account: bool,
 }
 
 pub const DEFAULT_VERIFICATION_OPTIONS: VerificationOptions = VerificationOptions {
     check_expiry: true,
     expiry_tolerance_seconds: Some(0),
+    check_gatekeeper_account: false,
 };
 
+/// A gatekeeper network accepted by [`Gateway::verify_gateway_token_for_networks`], with the
+/// options to verify its tokens with. `None` uses [`DEFAULT_VERIFICATION_OPTIONS`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AcceptedNetwork {
+    pub gatekeeper_network: Pubkey,
+    pub options: Option<VerificationOptions>,
+}
+
+impl AcceptedNetwork {
+    pub fn new(gatekeeper_network: Pubkey) -> Self {
+        Self {
+            gatekeeper_network,
+            options: None,
+        }
+    }
+}
+
 pub struct Gateway {}
 impl Gateway {
     pub fn program_id() -> Pubkey {
         Pubkey::from_str("gatem74V238djXdzWnJf94Wo1DcnuGkfijbf3AuBhfs").unwrap()
     }
 
-    /// Unpacks an account into a gateway token object
+    /// Unpacks an account into a gateway token object.
+    /// Reads both the version 0 layout and the version 1 layout with its extension area.
     pub fn parse_gateway_token(account_info: &AccountInfo) -> Result<GatewayToken, GatewayError> {
         program_borsh::try_from_slice_incomplete::<GatewayToken>(&account_info.data.borrow())
             .map_err(|_| GatewayError::InvalidToken)
@@ -100,7 +126,13 @@ impl Gateway {
 
         if !gateway_token.is_valid_state() {
             msg!("Gateway token is invalid. It has either been revoked or frozen");
-            return Err(GatewayError::TokenRevoked);
+            let reason = gateway_token
+                .state_change()
+                .and_then(|change| change.reason);
+            if let Some(StateChangeReason::Other(code)) = reason {
+                msg!("Gateway token reason code: {}", code);
+            }
+            return Err(GatewayError::token_invalid(reason));
         }
 
         if verification_options.check_expiry
@@ -113,6 +145,33 @@ impl Gateway {
         Ok(())
     }
 
+    /// Verifies the gateway token belongs to the expected owner and was issued by one of the
+    /// accepted gatekeeper networks, using that network's options.
+    /// Returns the gatekeeper network that matched.
+    pub fn verify_gateway_token_for_networks(
+        gateway_token: &impl GatewayTokenAccess,
+        expected_owner: &Pubkey,
+        accepted_networks: &[AcceptedNetwork],
+    ) -> Result<Pubkey, GatewayError> {
+        // A token only belongs to one network, so at most one accepted network can match
+        let accepted_network = accepted_networks
+            .iter()
+            .find(|network| network.gatekeeper_network == *gateway_token.gatekeeper_network())
+            .ok_or_else(|| {
+                msg!("Gateway token not issued by any of the accepted gatekeeper networks");
+                GatewayError::IncorrectGatekeeper
+            })?;
+
+        Gateway::verify_gateway_token(
+            gateway_token,
+            expected_owner,
+            &accepted_network.gatekeeper_network,
+            accepted_network.options,
+        )?;
+
+        Ok(accepted_network.gatekeeper_network)
+    }
+
     pub fn gateway_token_reference(
         gateway_token_info: &AccountInfo,
     ) -> Result<Pubkey, GatewayError> {
@@ -152,6 +211,93 @@ impl Gateway {
         )
     }
 
+    /// Verifies the gateway token account as [`Gateway::verify_gateway_token_account_info`] does.
+    /// If [`VerificationOptions::check_gatekeeper_account`] is set, also verifies that the gatekeeper
+    /// account of the issuing gatekeeper still exists, i.e. that the gatekeeper has not been removed,
+    /// and that the gatekeeper is not halted.
+    pub fn verify_gateway_token_account_info_with_gatekeeper(
+        gateway_token_info: &AccountInfo,
+        gatekeeper_account_info: &AccountInfo,
+        expected_owner: &Pubkey,
+        expected_gatekeeper_key: &Pubkey,
+        options: Option<VerificationOptions>,
+    ) -> Result<(), GatewayError> {
+        if gateway_token_info.owner.ne(&Gateway::program_id()) {
+            msg!("Gateway token is not owned by gateway program");
+            return Err(GatewayError::IncorrectProgramId);
+        }
+
+        let gateway_token = Gateway::parse_gateway_token(gateway_token_info)?;
+
+        Gateway::verify_gateway_token(
+            &gateway_token,
+            expected_owner,
+            expected_gatekeeper_key,
+            options,
+        )?;
+        if !options
+            .unwrap_or(DEFAULT_VERIFICATION_OPTIONS)
+            .check_gatekeeper_account
+        {
+            return Ok(());
+        }
+        Gateway::verify_issuing_gatekeeper_account(&gateway_token, gatekeeper_account_info)
+    }
+
+    /// Verifies the gatekeeper account of the gatekeeper that issued the gateway token
+    /// exists, is owned by the gateway program and is not halted.
+    pub fn verify_issuing_gatekeeper_account(
+        gateway_token: &impl GatewayTokenAccess,
+        gatekeeper_account_info: &AccountInfo,
+    ) -> Result<(), GatewayError> {
+        let (gatekeeper_account, _) = get_gatekeeper_account_address(
+            gateway_token.issuing_gatekeeper(),
+            gateway_token.gatekeeper_network(),
+        );
+        if gatekeeper_account != *gatekeeper_account_info.key {
+            msg!("Gatekeeper account is not the account of the issuing gatekeeper");
+            return Err(GatewayError::IncorrectGatekeeper);
+        }
+
+        if gatekeeper_account_info.owner.ne(&Gateway::program_id())
+            || gatekeeper_account_info.lamports() == 0
+        {
+            msg!("The gatekeeper that issued the gateway token has been removed");
+            return Err(GatewayError::GatekeeperRemoved);
+        }
+
+        let gatekeeper_state = GatekeeperAccount::parse(gatekeeper_account_info)
+            .map_err(|_| GatewayError::IncorrectGatekeeper)?
+            .state;
+        if gatekeeper_state == GatekeeperState::Halted {
+            msg!("The gatekeeper that issued the gateway token is halted");
+            return Err(GatewayError::InvalidGatekeeperState);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the gateway token account parses to a valid gateway token issued by one of the
+    /// accepted gatekeeper networks. See [`Gateway::verify_gateway_token_for_networks`].
+    pub fn verify_gateway_token_account_info_for_networks(
+        gateway_token_info: &AccountInfo,
+        expected_owner: &Pubkey,
+        accepted_networks: &[AcceptedNetwork],
+    ) -> Result<Pubkey, GatewayError> {
+        if gateway_token_info.owner.ne(&Gateway::program_id()) {
+            msg!("Gateway token is not owned by gateway program");
+            return Err(GatewayError::IncorrectProgramId);
+        }
+
+        let gateway_token = Gateway::parse_gateway_token(gateway_token_info)?;
+
+        Gateway::verify_gateway_token_for_networks(
+            &gateway_token,
+            expected_owner,
+            accepted_networks,
+        )
+    }
+
     pub fn verify_gateway_token_with_eval(
         gateway_token_info: &AccountInfo,
         expected_owner: &Pubkey,
@@ -233,7 +379,9 @@ impl Gateway {
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use crate::state::{get_expire_address_with_seed, get_gateway_token_address_with_seed};
+    use crate::state::{
+        get_expire_address_with_seed, get_gateway_token_address_with_seed, StateChangeReason,
+    };
     use crate::test_utils::test_utils_stubs::{init, now};
     use std::{cell::RefCell, rc::Rc};
 
@@ -247,6 +395,7 @@ pub mod tests {
             issuing_gatekeeper: Default::default(),
             state: Default::default(),
             expire_time: None,
+            extensions: vec![],
         };
         token.set_expire_time(0); // 1.1.1970 is definitely in the past
         token
@@ -317,12 +466,246 @@ pub mod tests {
             Some(VerificationOptions {
                 check_expiry: true,
                 expiry_tolerance_seconds: Some(60),
+                ..Default::default()
             }),
         );
 
         assert!(matches!(verify_result, Ok(())))
     }
 
+    #[test]
+    fn verify_gateway_token_for_networks_reports_the_matching_network() {
+        init();
+        let mut token = expired_gateway_token();
+        let network = Pubkey::new_unique();
+        token.gatekeeper_network = network;
+
+        let accepted_networks = [
+            AcceptedNetwork::new(Pubkey::new_unique()),
+            AcceptedNetwork {
+                gatekeeper_network: network,
+                options: Some(VerificationOptions {
+                    check_expiry: false,
+                    ..Default::default()
+                }),
+            },
+        ];
+        let verify_result = Gateway::verify_gateway_token_for_networks(
+            &token,
+            &Default::default(),
+            &accepted_networks,
+        );
+
+        assert_eq!(verify_result, Ok(network));
+    }
+
+    #[test]
+    fn verify_gateway_token_for_networks_uses_the_matching_network_options() {
+        init();
+        let mut token = expired_gateway_token();
+        let network = Pubkey::new_unique();
+        token.gatekeeper_network = network;
+
+        let verify_result = Gateway::verify_gateway_token_for_networks(
+            &token,
+            &Default::default(),
+            &[AcceptedNetwork::new(network)],
+        );
+
+        assert!(matches!(verify_result, Err(GatewayError::TokenExpired)))
+    }
+
+    #[test]
+    fn verify_gateway_token_for_networks_fails_for_other_networks() {
+        init();
+        let token = expired_gateway_token();
+
+        let verify_result = Gateway::verify_gateway_token_for_networks(
+            &token,
+            &Default::default(),
+            &[
+                AcceptedNetwork::new(Pubkey::new_unique()),
+                AcceptedNetwork::new(Pubkey::new_unique()),
+            ],
+        );
+
+        assert!(matches!(
+            verify_result,
+            Err(GatewayError::IncorrectGatekeeper)
+        ))
+    }
+
+    #[test]
+    fn verify_gateway_token_returns_the_revocation_reason() {
+        init();
+        let mut token = GatewayToken::new(
+            &Default::default(),
+            &Default::default(),
+            &Default::default(),
+            &None,
+        );
+        token.set_state(
+            GatewayTokenState::Revoked,
+            Some(StateChangeReason::Fraud),
+            now(),
+        );
+
+        let verify_result =
+            Gateway::verify_gateway_token(&token, &Default::default(), &Default::default(), None);
+
+        assert_eq!(verify_result, Err(GatewayError::TokenInvalidFraud));
+        assert_eq!(
+            ProgramError::from(verify_result.unwrap_err()),
+            ProgramError::Custom(9)
+        );
+    }
+
+    fn gatekeeper_check_options() -> Option<VerificationOptions> {
+        Some(VerificationOptions {
+            check_gatekeeper_account: true,
+            ..DEFAULT_VERIFICATION_OPTIONS
+        })
+    }
+
+    #[test]
+    fn verify_gateway_token_with_gatekeeper_checks_the_gatekeeper_account_exists() {
+        init();
+        let program_id = Gateway::program_id();
+        let gatekeeper = Pubkey::new_unique();
+        let network = Pubkey::new_unique();
+        let token = GatewayToken::new(&Default::default(), &network, &gatekeeper, &None);
+        let mut token_data = borsh::to_vec(&token).unwrap();
+        let gateway_token =
+            get_gateway_token_address_with_seed(&Default::default(), &None, &network).0;
+        let mut token_lamports = 1_000_000;
+        let token_info = AccountInfo::new(
+            &gateway_token,
+            false,
+            false,
+            &mut token_lamports,
+            &mut token_data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let gatekeeper_account = get_gatekeeper_account_address(&gatekeeper, &network).0;
+        let mut gatekeeper_lamports = 1_000_000;
+        let mut gatekeeper_data = [];
+        let gatekeeper_info = AccountInfo::new(
+            &gatekeeper_account,
+            false,
+            false,
+            &mut gatekeeper_lamports,
+            &mut gatekeeper_data,
+            &program_id,
+            false,
+            0,
+        );
+        assert_eq!(
+            Gateway::verify_gateway_token_account_info_with_gatekeeper(
+                &token_info,
+                &gatekeeper_info,
+                &Default::default(),
+                &network,
+                gatekeeper_check_options(),
+            ),
+            Ok(())
+        );
+
+        // a removed gatekeeper has its lamports drained
+        **gatekeeper_info.lamports.borrow_mut() = 0;
+        assert_eq!(
+            Gateway::verify_gateway_token_account_info_with_gatekeeper(
+                &token_info,
+                &gatekeeper_info,
+                &Default::default(),
+                &network,
+                gatekeeper_check_options(),
+            ),
+            Err(GatewayError::GatekeeperRemoved)
+        );
+
+        // the gatekeeper account is only checked if the option is set
+        assert_eq!(
+            Gateway::verify_gateway_token_account_info_with_gatekeeper(
+                &token_info,
+                &gatekeeper_info,
+                &Default::default(),
+                &network,
+                None,
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            Gateway::verify_gateway_token_account_info(
+                &token_info,
+                &Default::default(),
+                &network,
+                None,
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn verify_issuing_gatekeeper_account_fails_for_another_gatekeeper() {
+        let program_id = Gateway::program_id();
+        let network = Pubkey::new_unique();
+        let token = GatewayToken::new(&Default::default(), &network, &Pubkey::new_unique(), &None);
+
+        let other_gatekeeper_account =
+            get_gatekeeper_account_address(&Pubkey::new_unique(), &network).0;
+        let mut lamports = 1_000_000;
+        let mut data = [];
+        let gatekeeper_info = AccountInfo::new(
+            &other_gatekeeper_account,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            Gateway::verify_issuing_gatekeeper_account(&token, &gatekeeper_info),
+            Err(GatewayError::IncorrectGatekeeper)
+        );
+    }
+
+    #[test]
+    fn verify_issuing_gatekeeper_account_fails_for_a_halted_gatekeeper() {
+        let program_id = Gateway::program_id();
+        let gatekeeper = Pubkey::new_unique();
+        let network = Pubkey::new_unique();
+        let token = GatewayToken::new(&Default::default(), &network, &gatekeeper, &None);
+
+        let gatekeeper_account = get_gatekeeper_account_address(&gatekeeper, &network).0;
+        let mut lamports = 1_000_000;
+        let mut data = borsh::to_vec(&GatekeeperAccount {
+            state: GatekeeperState::Halted,
+            ..Default::default()
+        })
+        .unwrap();
+        let gatekeeper_info = AccountInfo::new(
+            &gatekeeper_account,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            Gateway::verify_issuing_gatekeeper_account(&token, &gatekeeper_info),
+            Err(GatewayError::InvalidGatekeeperState)
+        );
+    }
+
     struct EvalOut {
         result: ProgramResult,
     }
@@ -1,12 +1,16 @@
 //! Program state processor
 
 use crate::error::GatewayError;
-use crate::instruction::{GatewayInstruction, NetworkFeature};
+use crate::instruction::{
+    GatewayInstruction, IssueBatchEntry, NetworkFeature, NetworkFeatureConfig,
+};
+use crate::program_borsh::try_from_slice_incomplete;
 use crate::state::{
     get_expire_address_with_seed, get_gatekeeper_account_address,
-    get_gateway_token_address_with_seed, verify_gatekeeper_address_and_account, AddressSeed,
-    GatewayToken, GatewayTokenAccess, GatewayTokenState, GATEKEEPER_ADDRESS_SEED,
-    GATEWAY_TOKEN_ADDRESS_SEED, NETWORK_EXPIRE_FEATURE_SEED,
+    get_gateway_token_address_with_seed, get_network_feature_address, get_network_feature_seed,
+    verify_gatekeeper_address_and_account, AddressSeed, GatekeeperAccount, GatekeeperPermissions,
+    GatekeeperState, GatewayToken, GatewayTokenAccess, GatewayTokenFunctions, GatewayTokenState,
+    StateChangeReason, GATEKEEPER_ADDRESS_SEED, GATEWAY_TOKEN_ADDRESS_SEED,
 };
 use crate::Gateway;
 use solana_program::clock::{Clock, UnixTimestamp};
@@ -16,7 +20,7 @@ use {
         account_info::{next_account_info, AccountInfo},
         entrypoint::ProgramResult,
         msg,
-        program::invoke_signed,
+        program::{invoke, invoke_signed},
         program_error::ProgramError,
         pubkey::Pubkey,
         rent::Rent,
@@ -58,7 +62,7 @@ pub fn process_instruction(
     let result = match instruction {
         GatewayInstruction::AddGatekeeper {} => add_gatekeeper(accounts),
         GatewayInstruction::Issue { seed, expire_time } => issue(accounts, &seed, &expire_time),
-        GatewayInstruction::SetState { state } => set_state(accounts, state),
+        GatewayInstruction::SetState { state } => set_state(accounts, state, None),
         GatewayInstruction::UpdateExpiry { expire_time } => update_expiry(accounts, expire_time),
         GatewayInstruction::RemoveGatekeeper => remove_gatekeeper(accounts),
         GatewayInstruction::ExpireToken {
@@ -71,6 +75,21 @@ pub fn process_instruction(
             remove_feature_from_network(accounts, feature)
         }
         GatewayInstruction::BurnToken => burn_token(accounts),
+        GatewayInstruction::IssueBatch { entries } => issue_batch(accounts, &entries),
+        GatewayInstruction::TransferToNewOwner { seed } => transfer_to_new_owner(accounts, &seed),
+        GatewayInstruction::SetStateWithReason { state, reason } => {
+            set_state(accounts, state, reason)
+        }
+        GatewayInstruction::UpgradeToken { seed } => upgrade_token(accounts, &seed),
+        GatewayInstruction::UpdateNetworkFeature { config } => {
+            update_network_feature(accounts, config)
+        }
+        GatewayInstruction::OwnerBurnToken => owner_burn_token(accounts),
+        GatewayInstruction::SetGatekeeperState { state } => set_gatekeeper_state(accounts, state),
+        GatewayInstruction::SetGatekeeperDelegate {
+            delegate,
+            permissions,
+        } => set_gatekeeper_delegate(accounts, &delegate, permissions),
     };
 
     if let Some(e) = result.clone().err() {
@@ -154,21 +173,110 @@ fn issue(
     let gatekeeper_authority_info = next_account_info(account_info_iter)?;
     let gatekeeper_network_info = next_account_info(account_info_iter)?;
 
+    let _rent_info = next_account_info(account_info_iter)?;
     let system_program_info = next_account_info(account_info_iter)?;
+    let max_token_lifetime_feature = next_account_info(account_info_iter)?;
 
     if !gatekeeper_authority_info.is_signer {
         msg!("Gatekeeper authority signature missing");
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    verify_gatekeeper_address_and_account(
+    let gatekeeper_authority = verify_gatekeeper_address_and_account(
         gatekeeper_account_info,
         gatekeeper_authority_info.key,
         gatekeeper_network_info.key,
+        GatekeeperPermissions::ISSUE,
     )?;
+    check_gatekeeper_state(gatekeeper_account_info, GatekeeperState::can_issue)?;
+
+    let max_lifetime =
+        get_max_token_lifetime(max_token_lifetime_feature, gatekeeper_network_info.key)?;
+    check_token_lifetime(expire_time, max_lifetime, Clock::get()?.unix_timestamp)?;
+
+    create_gateway_token(
+        funder_info,
+        gateway_token_info,
+        system_program_info,
+        seed,
+        &GatewayToken::new(
+            owner_info.key,
+            gatekeeper_network_info.key,
+            &gatekeeper_authority,
+            expire_time,
+        ),
+    )
+}
 
-    let (gateway_token_address, gateway_token_bump_seed) =
-        get_gateway_token_address_with_seed(owner_info.key, seed, gatekeeper_network_info.key);
+fn issue_batch(accounts: &[AccountInfo], entries: &[IssueBatchEntry]) -> ProgramResult {
+    msg!("GatewayInstruction::IssueBatch");
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let gatekeeper_account_info = next_account_info(account_info_iter)?;
+    let gatekeeper_authority_info = next_account_info(account_info_iter)?;
+    let gatekeeper_network_info = next_account_info(account_info_iter)?;
+
+    let system_program_info = next_account_info(account_info_iter)?;
+    let max_token_lifetime_feature = next_account_info(account_info_iter)?;
+
+    if !gatekeeper_authority_info.is_signer {
+        msg!("Gatekeeper authority signature missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if entries.is_empty() {
+        msg!("Error: no gateway tokens to issue");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let gatekeeper_authority = verify_gatekeeper_address_and_account(
+        gatekeeper_account_info,
+        gatekeeper_authority_info.key,
+        gatekeeper_network_info.key,
+        GatekeeperPermissions::ISSUE,
+    )?;
+    check_gatekeeper_state(gatekeeper_account_info, GatekeeperState::can_issue)?;
+
+    let max_lifetime =
+        get_max_token_lifetime(max_token_lifetime_feature, gatekeeper_network_info.key)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    for entry in entries {
+        let gateway_token_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        check_token_lifetime(&entry.expire_time, max_lifetime, now)?;
+
+        create_gateway_token(
+            funder_info,
+            gateway_token_info,
+            system_program_info,
+            &entry.seed,
+            &GatewayToken::new(
+                owner_info.key,
+                gatekeeper_network_info.key,
+                &gatekeeper_authority,
+                &entry.expire_time,
+            ),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Create and initialise the account of a gateway token at its owner's address
+fn create_gateway_token<'a>(
+    funder_info: &AccountInfo<'a>,
+    gateway_token_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    seed: &Option<AddressSeed>,
+    gateway_token: &GatewayToken,
+) -> ProgramResult {
+    let (gateway_token_address, gateway_token_bump_seed) = get_gateway_token_address_with_seed(
+        &gateway_token.owner_wallet,
+        seed,
+        &gateway_token.gatekeeper_network,
+    );
     if gateway_token_address != *gateway_token_info.key {
         msg!("Error: gateway_token address derivation mismatch");
         return Err(ProgramError::InvalidArgument);
@@ -181,26 +289,19 @@ fn issue(
     }
 
     let gateway_token_signer_seeds: &[&[_]] = &[
-        &owner_info.key.to_bytes(),
+        &gateway_token.owner_wallet.to_bytes(),
         GATEWAY_TOKEN_ADDRESS_SEED,
         &seed.unwrap_or_default(),
-        &gatekeeper_network_info.key.to_bytes(),
+        &gateway_token.gatekeeper_network.to_bytes(),
         &[gateway_token_bump_seed],
     ];
 
-    let gateway_token = GatewayToken::new(
-        owner_info.key,
-        gatekeeper_network_info.key,
-        gatekeeper_authority_info.key,
-        expire_time,
-    );
-
     invoke_signed(
         &system_instruction::create_account(
             funder_info.key,
             gateway_token_info.key,
-            1.max(Rent::get().unwrap().minimum_balance(GatewayToken::SIZE)),
-            GatewayToken::SIZE as u64,
+            1.max(Rent::get().unwrap().minimum_balance(gateway_token.size())),
+            gateway_token.size() as u64,
             &Gateway::program_id(),
         ),
         &[
@@ -212,16 +313,21 @@ fn issue(
     )?;
 
     gateway_token
-        .serialize(&mut *gateway_token_info.data.borrow_mut())
+        .serialize(&mut &mut gateway_token_info.data.borrow_mut()[..])
         .map_err(|e| e.into()) as ProgramResult
 }
 
-fn set_state(accounts: &[AccountInfo], state: GatewayTokenState) -> ProgramResult {
+fn set_state(
+    accounts: &[AccountInfo],
+    state: GatewayTokenState,
+    reason: Option<StateChangeReason>,
+) -> ProgramResult {
     msg!("GatewayInstruction::SetState");
     let account_info_iter = &mut accounts.iter();
     let gateway_token_info = next_account_info(account_info_iter)?;
     let gatekeeper_authority_info = next_account_info(account_info_iter)?;
     let gatekeeper_account_info = next_account_info(account_info_iter)?;
+    let freeze_only_feature = next_account_info(account_info_iter)?;
 
     if !gatekeeper_authority_info.is_signer {
         msg!("Gatekeeper authority signature missing");
@@ -235,11 +341,18 @@ fn set_state(accounts: &[AccountInfo], state: GatewayTokenState) -> ProgramResul
 
     let mut gateway_token = Gateway::parse_gateway_token(gateway_token_info)?;
 
-    verify_gatekeeper_address_and_account(
+    let permissions = if state == GatewayTokenState::Revoked {
+        GatekeeperPermissions::REVOKE
+    } else {
+        GatekeeperPermissions::FREEZE
+    };
+    let gatekeeper_authority = verify_gatekeeper_address_and_account(
         gatekeeper_account_info,
         gatekeeper_authority_info.key,
         &gateway_token.gatekeeper_network,
+        permissions,
     )?;
+    check_gatekeeper_state(gatekeeper_account_info, GatekeeperState::can_update)?;
 
     // check that the required state change is allowed
     if !gateway_token.is_valid_state_change(&state) {
@@ -254,16 +367,28 @@ fn set_state(accounts: &[AccountInfo], state: GatewayTokenState) -> ProgramResul
     // Only the issuing gatekeeper can freeze or unfreeze a GT
     // Any gatekeeper in the network (checked above) can revoke
     if (state == GatewayTokenState::Frozen || state == GatewayTokenState::Active)
-        && gateway_token.issuing_gatekeeper != *gatekeeper_authority_info.key
+        && gateway_token.issuing_gatekeeper != gatekeeper_authority
     {
         msg!("Error: Only the issuing gatekeeper can freeze or unfreeze");
         return Err(GatewayError::IncorrectGatekeeper.into());
     }
 
-    gateway_token.state = state;
+    let freeze_only = has_network_feature(
+        freeze_only_feature,
+        &gateway_token.gatekeeper_network,
+        NetworkFeature::FreezeOnly,
+    )?;
+    if state == GatewayTokenState::Revoked {
+        check_not_freeze_only(freeze_only)?;
+    }
+
+    if gateway_token.version == 0 {
+        msg!("Gateway token version does not record state changes");
+    }
+    gateway_token.set_state(state, reason, Clock::get()?.unix_timestamp);
 
     gateway_token
-        .serialize(&mut *gateway_token_info.data.borrow_mut())
+        .serialize(&mut &mut gateway_token_info.data.borrow_mut()[..])
         .map_err(|e| e.into()) as ProgramResult
 }
 
@@ -273,6 +398,7 @@ fn update_expiry(accounts: &[AccountInfo], expire_time: UnixTimestamp) -> Progra
     let gateway_token_info = next_account_info(account_info_iter)?;
     let gatekeeper_authority_info = next_account_info(account_info_iter)?;
     let gatekeeper_account_info = next_account_info(account_info_iter)?;
+    let max_token_lifetime_feature = next_account_info(account_info_iter)?;
 
     if !gatekeeper_authority_info.is_signer {
         msg!("Gatekeeper authority signature missing");
@@ -290,15 +416,120 @@ fn update_expiry(accounts: &[AccountInfo], expire_time: UnixTimestamp) -> Progra
         gatekeeper_account_info,
         gatekeeper_authority_info.key,
         &gateway_token.gatekeeper_network,
+        GatekeeperPermissions::UPDATE_EXPIRY,
+    )?;
+    check_gatekeeper_state(gatekeeper_account_info, GatekeeperState::can_issue)?;
+
+    let max_lifetime = get_max_token_lifetime(
+        max_token_lifetime_feature,
+        &gateway_token.gatekeeper_network,
+    )?;
+    check_token_lifetime(
+        &Some(expire_time),
+        max_lifetime,
+        Clock::get()?.unix_timestamp,
     )?;
 
     gateway_token.set_expire_time(expire_time);
 
     gateway_token
-        .serialize(&mut *gateway_token_info.data.borrow_mut())
+        .serialize(&mut &mut gateway_token_info.data.borrow_mut()[..])
         .map_err(|e| e.into()) as ProgramResult
 }
 
+fn set_gatekeeper_state(accounts: &[AccountInfo], state: GatekeeperState) -> ProgramResult {
+    msg!("GatewayInstruction::SetGatekeeperState");
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let gatekeeper_account_info = next_account_info(account_info_iter)?;
+    let gatekeeper_authority_info = next_account_info(account_info_iter)?;
+    let gatekeeper_network_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !gatekeeper_network_info.is_signer {
+        msg!("Gatekeeper network signature missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    set_gatekeeper_account_data(
+        funder_info,
+        gatekeeper_account_info,
+        gatekeeper_authority_info,
+        gatekeeper_network_info,
+        system_program_info,
+        |gatekeeper_account| gatekeeper_account.state = state,
+    )
+}
+
+fn set_gatekeeper_delegate(
+    accounts: &[AccountInfo],
+    delegate: &Pubkey,
+    permissions: GatekeeperPermissions,
+) -> ProgramResult {
+    msg!("GatewayInstruction::SetGatekeeperDelegate");
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let gatekeeper_account_info = next_account_info(account_info_iter)?;
+    let gatekeeper_authority_info = next_account_info(account_info_iter)?;
+    let gatekeeper_network_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !gatekeeper_authority_info.is_signer {
+        msg!("Gatekeeper authority signature missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    set_gatekeeper_account_data(
+        funder_info,
+        gatekeeper_account_info,
+        gatekeeper_authority_info,
+        gatekeeper_network_info,
+        system_program_info,
+        |gatekeeper_account| gatekeeper_account.set_delegate(delegate, permissions),
+    )
+}
+
+/// Updates the data in a gatekeeper account, recording the gatekeeper authority it is derived from
+fn set_gatekeeper_account_data<'a>(
+    funder_info: &AccountInfo<'a>,
+    gatekeeper_account_info: &AccountInfo<'a>,
+    gatekeeper_authority_info: &AccountInfo<'a>,
+    gatekeeper_network_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    update: impl FnOnce(&mut GatekeeperAccount),
+) -> ProgramResult {
+    if gatekeeper_account_info.owner.ne(&Gateway::program_id()) {
+        msg!("Incorrect program Id for gatekeeper account");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Only the gatekeeper authority itself, not a delegate, is recorded in the gatekeeper account
+    let (gatekeeper_address, _gatekeeper_bump_seed) =
+        get_gatekeeper_account_address(gatekeeper_authority_info.key, gatekeeper_network_info.key);
+    if gatekeeper_address != *gatekeeper_account_info.key {
+        msg!("Error: gatekeeper account address derivation mismatch");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut gatekeeper_account = GatekeeperAccount::parse(gatekeeper_account_info)?;
+    gatekeeper_account.authority = *gatekeeper_authority_info.key;
+    update(&mut gatekeeper_account);
+
+    let data = borsh::to_vec(&gatekeeper_account)?;
+    resize_account(
+        funder_info,
+        gatekeeper_account_info,
+        system_program_info,
+        data.len(),
+    )?;
+    gatekeeper_account_info
+        .data
+        .borrow_mut()
+        .copy_from_slice(&data);
+
+    Ok(())
+}
+
 fn remove_gatekeeper(accounts: &[AccountInfo]) -> ProgramResult {
     msg!("GatewayInstruction::RemoveGatekeeper");
     let account_info_iter = &mut accounts.iter();
@@ -363,7 +594,7 @@ fn expire_token(accounts: &[AccountInfo], gatekeeper_network: Pubkey) -> Program
     gateway_token.set_expire_time(Clock::get()?.unix_timestamp - 120);
 
     gateway_token
-        .serialize(&mut *gateway_token_info.data.borrow_mut())
+        .serialize(&mut &mut gateway_token_info.data.borrow_mut()[..])
         .map_err(|e| e.into()) as ProgramResult
 }
 
@@ -383,35 +614,103 @@ fn add_feature_to_network(accounts: &[AccountInfo], feature: NetworkFeature) ->
         return Err(ProgramError::InvalidArgument);
     }
 
-    match feature {
-        NetworkFeature::UserTokenExpiry => {
-            let (key, bump_seed) = get_expire_address_with_seed(gatekeeper_network.key);
-            if &key != feature_account.key {
-                return Err(ProgramError::InvalidArgument);
-            }
-            let seeds = &[
-                &gatekeeper_network.key.to_bytes(),
-                NETWORK_EXPIRE_FEATURE_SEED,
-                &[bump_seed],
-            ] as &[&[u8]];
-
-            invoke_signed(
-                &solana_program::system_instruction::create_account(
-                    funder_account.key,
-                    feature_account.key,
-                    1.max(Rent::get().unwrap().minimum_balance(0)),
-                    0,
-                    &Gateway::program_id(),
-                ),
-                &[
-                    system_program.clone(),
-                    funder_account.clone(),
-                    feature_account.clone(),
-                ],
-                &[seeds],
-            )
+    if feature.has_config() {
+        msg!("Error: features with a configuration are added with UpdateNetworkFeature");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (key, bump_seed) = get_network_feature_address(gatekeeper_network.key, feature);
+    if &key != feature_account.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let seeds = &[
+        &gatekeeper_network.key.to_bytes(),
+        get_network_feature_seed(feature),
+        &[bump_seed],
+    ] as &[&[u8]];
+
+    invoke_signed(
+        &solana_program::system_instruction::create_account(
+            funder_account.key,
+            feature_account.key,
+            1.max(Rent::get().unwrap().minimum_balance(0)),
+            0,
+            &Gateway::program_id(),
+        ),
+        &[
+            system_program.clone(),
+            funder_account.clone(),
+            feature_account.clone(),
+        ],
+        &[seeds],
+    )
+}
+
+fn update_network_feature(accounts: &[AccountInfo], config: NetworkFeatureConfig) -> ProgramResult {
+    msg!("GatewayInstruction::UpdateNetworkFeature");
+    let account_info_iter = &mut accounts.iter();
+    let funder_account = next_account_info(account_info_iter)?;
+    let gatekeeper_network = next_account_info(account_info_iter)?;
+    let feature_account = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    if !gatekeeper_network.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let feature = config.feature();
+    let (key, bump_seed) = get_network_feature_address(gatekeeper_network.key, feature);
+    if &key != feature_account.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if let NetworkFeatureConfig::MaxTokenLifetime { max_lifetime } = config {
+        if max_lifetime < 0 {
+            msg!("Error: the maximum token lifetime can not be negative");
+            return Err(ProgramError::InvalidArgument);
         }
     }
+
+    let data = borsh::to_vec(&config)?;
+    let minimum_balance = Rent::get()?.minimum_balance(data.len());
+
+    if feature_account.lamports() == 0 {
+        let seeds = &[
+            &gatekeeper_network.key.to_bytes(),
+            get_network_feature_seed(feature),
+            &[bump_seed],
+        ] as &[&[u8]];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account.key,
+                feature_account.key,
+                1.max(minimum_balance),
+                data.len() as u64,
+                &Gateway::program_id(),
+            ),
+            &[
+                system_program.clone(),
+                funder_account.clone(),
+                feature_account.clone(),
+            ],
+            &[seeds],
+        )?;
+    } else {
+        if feature_account.owner != &Gateway::program_id() {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        resize_account(funder_account, feature_account, system_program, data.len())?;
+    }
+
+    feature_account.data.borrow_mut().copy_from_slice(&data);
+
+    Ok(())
 }
 
 fn remove_feature_from_network(accounts: &[AccountInfo], feature: NetworkFeature) -> ProgramResult {
@@ -425,13 +724,9 @@ fn remove_feature_from_network(accounts: &[AccountInfo], feature: NetworkFeature
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    match feature {
-        NetworkFeature::UserTokenExpiry => {
-            if &get_expire_address_with_seed(gatekeeper_network.key).0 != feature_account.key {
-                return Err(ProgramError::InvalidArgument);
-            }
-        }
-    };
+    if &get_network_feature_address(gatekeeper_network.key, feature).0 != feature_account.key {
+        return Err(ProgramError::InvalidArgument);
+    }
 
     **funds_to_account.lamports.borrow_mut() += **feature_account.lamports.borrow();
     **feature_account.lamports.borrow_mut() = 0;
@@ -446,6 +741,7 @@ fn burn_token(accounts: &[AccountInfo]) -> ProgramResult {
     let gatekeeper_authority_info = next_account_info(account_info_iter)?;
     let gatekeeper_account_info = next_account_info(account_info_iter)?;
     let recipient_info = next_account_info(account_info_iter)?;
+    let freeze_only_feature = next_account_info(account_info_iter)?;
 
     if !gatekeeper_authority_info.is_signer {
         msg!("Gatekeeper authority signature missing");
@@ -464,7 +760,14 @@ fn burn_token(accounts: &[AccountInfo]) -> ProgramResult {
         gatekeeper_account_info,
         gatekeeper_authority_info.key,
         &gateway_token.gatekeeper_network,
+        GatekeeperPermissions::BURN,
     )?;
+    check_gatekeeper_state(gatekeeper_account_info, GatekeeperState::can_update)?;
+    check_not_freeze_only(has_network_feature(
+        freeze_only_feature,
+        &gateway_token.gatekeeper_network,
+        NetworkFeature::FreezeOnly,
+    )?)?;
 
     let recipient_starting_lamports = recipient_info.lamports();
     **recipient_info.lamports.borrow_mut() = recipient_starting_lamports
@@ -477,10 +780,344 @@ fn burn_token(accounts: &[AccountInfo]) -> ProgramResult {
     Ok(())
 }
 
+fn owner_burn_token(accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("GatewayInstruction::OwnerBurnToken");
+    let account_info_iter = &mut accounts.iter();
+    let gateway_token_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_burn_feature = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        msg!("Owner signature missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if gateway_token_info.owner.ne(&Gateway::program_id()) {
+        msg!("Incorrect program Id for gateway token account");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let gateway_token = Gateway::parse_gateway_token(gateway_token_info)?;
+
+    if gateway_token.owner_wallet() != owner_info.key {
+        return Err(GatewayError::InvalidOwner.into());
+    }
+
+    let require_inactive = match get_network_feature_config(
+        owner_burn_feature,
+        &gateway_token.gatekeeper_network,
+        NetworkFeature::OwnerBurn,
+    )? {
+        Some(NetworkFeatureConfig::OwnerBurn { require_inactive }) => require_inactive,
+        _ => {
+            msg!("Error: the gatekeeper network does not allow owners to burn gateway tokens");
+            return Err(GatewayError::OwnerBurnNotEnabled.into());
+        }
+    };
+
+    if require_inactive
+        && gateway_token.state != GatewayTokenState::Revoked
+        && !gateway_token.has_expired(0)
+    {
+        msg!("Error: the gateway token must be expired or revoked before it can be burned");
+        return Err(GatewayError::TokenStillActive.into());
+    }
+
+    let owner_starting_lamports = owner_info.lamports();
+    **owner_info.lamports.borrow_mut() = owner_starting_lamports
+        .checked_add(gateway_token_info.lamports())
+        .ok_or(GatewayError::BurnError)?;
+
+    **gateway_token_info.lamports.borrow_mut() = 0;
+    delete_account(gateway_token_info)?;
+
+    Ok(())
+}
+
+fn transfer_to_new_owner(accounts: &[AccountInfo], seed: &Option<AddressSeed>) -> ProgramResult {
+    msg!("GatewayInstruction::TransferToNewOwner");
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let gateway_token_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let new_gateway_token_info = next_account_info(account_info_iter)?;
+    let new_owner_info = next_account_info(account_info_iter)?;
+    let gatekeeper_authority_info = next_account_info(account_info_iter)?;
+    let gatekeeper_account_info = next_account_info(account_info_iter)?;
+    let network_transfer_feature = next_account_info(account_info_iter)?;
+
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !owner_info.is_signer {
+        msg!("Owner signature missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !gatekeeper_authority_info.is_signer {
+        msg!("Gatekeeper authority signature missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if gateway_token_info.owner.ne(&Gateway::program_id()) {
+        msg!("Incorrect program Id for gateway token account");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let gateway_token = Gateway::parse_gateway_token(gateway_token_info)?;
+
+    if gateway_token.owner_wallet() != owner_info.key {
+        return Err(GatewayError::InvalidOwner.into());
+    }
+
+    // Any gatekeeper in the gateway token's network can approve the transfer
+    verify_gatekeeper_address_and_account(
+        gatekeeper_account_info,
+        gatekeeper_authority_info.key,
+        &gateway_token.gatekeeper_network,
+        GatekeeperPermissions::ISSUE,
+    )?;
+    check_gatekeeper_state(gatekeeper_account_info, GatekeeperState::can_update)?;
+
+    if network_transfer_feature.owner != &Gateway::program_id() {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    if &get_network_feature_address(
+        &gateway_token.gatekeeper_network,
+        NetworkFeature::OwnerTransfer,
+    )
+    .0 != network_transfer_feature.key
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut new_gateway_token = GatewayToken::new(
+        new_owner_info.key,
+        &gateway_token.gatekeeper_network,
+        &gateway_token.issuing_gatekeeper,
+        &gateway_token.expire_time,
+    );
+    new_gateway_token.state = gateway_token.state;
+    new_gateway_token.extensions = gateway_token.extensions.clone();
+
+    create_gateway_token(
+        funder_info,
+        new_gateway_token_info,
+        system_program_info,
+        seed,
+        &new_gateway_token,
+    )?;
+
+    let funder_starting_lamports = funder_info.lamports();
+    **funder_info.lamports.borrow_mut() = funder_starting_lamports
+        .checked_add(gateway_token_info.lamports())
+        .ok_or(GatewayError::BurnError)?;
+
+    **gateway_token_info.lamports.borrow_mut() = 0;
+    delete_account(gateway_token_info)
+}
+
+fn upgrade_token(accounts: &[AccountInfo], seed: &Option<AddressSeed>) -> ProgramResult {
+    msg!("GatewayInstruction::UpgradeToken");
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let gateway_token_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !funder_info.is_signer {
+        msg!("Funder signature missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if gateway_token_info.owner.ne(&Gateway::program_id()) {
+        msg!("Incorrect program Id for gateway token account");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut gateway_token = Gateway::parse_gateway_token(gateway_token_info)?;
+
+    let (gateway_token_address, _) = get_gateway_token_address_with_seed(
+        &gateway_token.owner_wallet,
+        seed,
+        &gateway_token.gatekeeper_network,
+    );
+    if gateway_token_address != *gateway_token_info.key {
+        msg!("Error: gateway_token address derivation mismatch");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Only version 0 tokens of the original size can be upgraded
+    if gateway_token_info.data_len() != GatewayToken::SIZE {
+        msg!("Error: gateway token is not the size of a version 0 token");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !gateway_token.can_upgrade() {
+        msg!("Error: gateway token cannot be upgraded");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    gateway_token.version = 1;
+    resize_account(
+        funder_info,
+        gateway_token_info,
+        system_program_info,
+        gateway_token.size(),
+    )?;
+
+    gateway_token
+        .serialize(&mut &mut gateway_token_info.data.borrow_mut()[..])
+        .map_err(|e| e.into()) as ProgramResult
+}
+
+/// Checks that the state of the gatekeeper allows the operation
+fn check_gatekeeper_state(
+    gatekeeper_account_info: &AccountInfo,
+    allowed: impl FnOnce(&GatekeeperState) -> bool,
+) -> ProgramResult {
+    let gatekeeper_state = GatekeeperAccount::parse(gatekeeper_account_info)?.state;
+    if !allowed(&gatekeeper_state) {
+        msg!("Error: the gatekeeper is {:?}", gatekeeper_state);
+        return Err(GatewayError::InvalidGatekeeperState.into());
+    }
+
+    Ok(())
+}
+
+/// Tops up the rent of an account owned by the program and resizes it
+fn resize_account<'a>(
+    funder_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    new_size: usize,
+) -> ProgramResult {
+    let additional_rent = Rent::get()?
+        .minimum_balance(new_size)
+        .saturating_sub(account_info.lamports());
+    if additional_rent > 0 {
+        invoke(
+            &system_instruction::transfer(funder_info.key, account_info.key, additional_rent),
+            &[
+                funder_info.clone(),
+                account_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+
+    if account_info.data_len() != new_size {
+        account_info.realloc(new_size, true)?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the feature account is the gatekeeper network's account for the feature,
+/// and returns whether the gatekeeper network has the feature
+fn has_network_feature(
+    feature_account: &AccountInfo,
+    gatekeeper_network: &Pubkey,
+    feature: NetworkFeature,
+) -> Result<bool, ProgramError> {
+    if &get_network_feature_address(gatekeeper_network, feature).0 != feature_account.key {
+        msg!("Error: incorrect feature account for {:?}", feature);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(feature_account.owner == &Gateway::program_id() && feature_account.lamports() > 0)
+}
+
+/// Checks that gateway tokens may be revoked or burned, which a FreezeOnly network does not allow
+fn check_not_freeze_only(freeze_only: bool) -> Result<(), GatewayError> {
+    if freeze_only {
+        msg!("Error: the gatekeeper network only allows gateway tokens to be frozen");
+        return Err(GatewayError::InvalidStateChange);
+    }
+
+    Ok(())
+}
+
+/// Returns the configuration of a feature of the gatekeeper network, if the network has the feature.
+/// An empty feature account has no configuration, so the feature is disabled.
+fn get_network_feature_config(
+    feature_account: &AccountInfo,
+    gatekeeper_network: &Pubkey,
+    feature: NetworkFeature,
+) -> Result<Option<NetworkFeatureConfig>, ProgramError> {
+    if !has_network_feature(feature_account, gatekeeper_network, feature)?
+        || feature_account.data_is_empty()
+    {
+        return Ok(None);
+    }
+
+    let config: NetworkFeatureConfig = try_from_slice_incomplete(&feature_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if config.feature() != feature {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(Some(config))
+}
+
+/// Returns the maximum token lifetime of the gatekeeper network, if it has the MaxTokenLifetime feature
+fn get_max_token_lifetime(
+    feature_account: &AccountInfo,
+    gatekeeper_network: &Pubkey,
+) -> Result<Option<UnixTimestamp>, ProgramError> {
+    match get_network_feature_config(
+        feature_account,
+        gatekeeper_network,
+        NetworkFeature::MaxTokenLifetime,
+    )? {
+        Some(NetworkFeatureConfig::MaxTokenLifetime { max_lifetime }) => Ok(Some(max_lifetime)),
+        _ => Ok(None),
+    }
+}
+
+/// Checks that a gateway token expires within the maximum token lifetime, if there is one
+fn check_token_lifetime(
+    expire_time: &Option<UnixTimestamp>,
+    max_lifetime: Option<UnixTimestamp>,
+    now: UnixTimestamp,
+) -> Result<(), GatewayError> {
+    match (max_lifetime, expire_time) {
+        (Some(max_lifetime), Some(expire_time))
+            if *expire_time > now.saturating_add(max_lifetime) =>
+        {
+            msg!("Error: the expire time exceeds the maximum token lifetime");
+            Err(GatewayError::TokenLifetimeTooLong)
+        }
+        (Some(_), None) => {
+            msg!("Error: the gatekeeper network requires gateway tokens to expire");
+            Err(GatewayError::TokenLifetimeTooLong)
+        }
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
 
+    #[test]
+    fn check_token_lifetime_enforces_the_maximum() {
+        let now = 1_000;
+
+        assert_eq!(check_token_lifetime(&None, None, now), Ok(()));
+        assert_eq!(
+            check_token_lifetime(&Some(now + 100), Some(100), now),
+            Ok(())
+        );
+        assert_eq!(
+            check_token_lifetime(&Some(now + 101), Some(100), now),
+            Err(GatewayError::TokenLifetimeTooLong)
+        );
+        assert_eq!(
+            check_token_lifetime(&None, Some(100), now),
+            Err(GatewayError::TokenLifetimeTooLong)
+        );
+    }
+
     #[test]
     fn set_state_should_fail_with_invalid_program_owner_on_gateway_token() {
         let invalid_owner = Default::default();
@@ -496,10 +1133,12 @@ pub mod tests {
         let mut gateway_token_lamports = 0;
         let mut gatekeeper_authority_lamports = 0;
         let mut gatekeeper_account_lamports = 0;
+        let mut freeze_only_feature_lamports = 0;
         let rent_epoch = 0;
         let owner = Gateway::program_id();
         let gatekeeper_authority = Default::default();
         let gatekeeper_account = Default::default();
+        let freeze_only_feature = Default::default();
         let gateway_token = AccountInfo::new(
             &gatekeeper_token_address,
             false,
@@ -530,7 +1169,22 @@ pub mod tests {
             false,
             rent_epoch,
         );
-        let accounts = vec![gateway_token, gatekeeper_authority, gatekeeper_account];
+        let freeze_only_feature = AccountInfo::new(
+            &freeze_only_feature,
+            false,
+            false,
+            &mut freeze_only_feature_lamports,
+            &mut [],
+            &owner,
+            false,
+            rent_epoch,
+        );
+        let accounts = vec![
+            gateway_token,
+            gatekeeper_authority,
+            gatekeeper_account,
+            freeze_only_feature,
+        ];
 
         // create the transaction
         let process_result = process_instruction(
@@ -559,10 +1213,12 @@ pub mod tests {
         let mut gateway_token_lamports = 0;
         let mut gatekeeper_authority_lamports = 0;
         let mut gatekeeper_account_lamports = 0;
+        let mut freeze_only_feature_lamports = 0;
         let rent_epoch = 0;
         let owner = Gateway::program_id();
         let gatekeeper_authority = Default::default();
         let gatekeeper_account = Default::default();
+        let freeze_only_feature = Default::default();
         let gateway_token = AccountInfo::new(
             &gatekeeper_token_address,
             false,
@@ -593,7 +1249,22 @@ pub mod tests {
             false,
             rent_epoch,
         );
-        let accounts = vec![gateway_token, gatekeeper_authority, gatekeeper_account];
+        let freeze_only_feature = AccountInfo::new(
+            &freeze_only_feature,
+            false,
+            false,
+            &mut freeze_only_feature_lamports,
+            &mut [],
+            &owner,
+            false,
+            rent_epoch,
+        );
+        let accounts = vec![
+            gateway_token,
+            gatekeeper_authority,
+            gatekeeper_account,
+            freeze_only_feature,
+        ];
 
         // create the transaction
         let process_result = process_instruction(
@@ -1,11 +1,19 @@
 //! Program state
+use crate::instruction::NetworkFeature;
 use crate::networks::GATEKEEPER_NETWORKS_WITH_EXPIRE_ADDRESSES;
-use crate::{Gateway, GatewayError};
+use crate::{program_borsh, Gateway, GatewayError};
+use std::convert::TryFrom;
+use std::ops::BitOr;
 use {
-    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+    borsh::{
+        io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write},
+        BorshDeserialize, BorshSchema, BorshSerialize,
+    },
     solana_program::{
         account_info::AccountInfo,
         clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
         pubkey::Pubkey,
         sysvar::{clock::Clock, Sysvar},
     },
@@ -25,6 +33,18 @@ pub const GATEKEEPER_ADDRESS_SEED: &[u8] = br"gatekeeper";
 /// The seed string used to derive a program address for a network expire feature
 pub const NETWORK_EXPIRE_FEATURE_SEED: &[u8] = br"expire";
 
+/// The seed string used to derive a program address for a network owner transfer feature
+pub const NETWORK_TRANSFER_FEATURE_SEED: &[u8] = br"transfer";
+
+/// The seed string used to derive a program address for a network max token lifetime feature
+pub const NETWORK_MAX_LIFETIME_FEATURE_SEED: &[u8] = br"max_lifetime";
+
+/// The seed string used to derive a program address for a network freeze only feature
+pub const NETWORK_FREEZE_ONLY_FEATURE_SEED: &[u8] = br"freeze_only";
+
+/// The seed string used to derive a program address for a network owner burn feature
+pub const NETWORK_OWNER_BURN_FEATURE_SEED: &[u8] = br"owner_burn";
+
 /// An optional seed to use when generating a gateway token,
 /// allowing multiple gateway tokens per wallet
 pub type AddressSeed = [u8; 8];
@@ -58,13 +78,16 @@ pub fn get_gatekeeper_account_address(authority: &Pubkey, network: &Pubkey) -> (
     )
 }
 
-/// Verifies that the gatekeeper account matches the passed in gatekeeper and gatekeeper network
+/// Verifies that the gatekeeper account matches the passed in gatekeeper and gatekeeper network.
+/// The gatekeeper may be the gatekeeper authority, or a delegate of it with the given permissions.
+/// Returns the gatekeeper authority.
 /// NOTE: This does not check that the gatekeeper is a signer of the transaction.
 pub fn verify_gatekeeper_address_and_account(
     gatekeeper_account_info: &AccountInfo,
     gatekeeper: &Pubkey,
     gatekeeper_network: &Pubkey,
-) -> Result<(), GatewayError> {
+    permissions: GatekeeperPermissions,
+) -> Result<Pubkey, GatewayError> {
     // Gatekeeper account must be owned by the gateway program
     if gatekeeper_account_info.owner.ne(&Gateway::program_id()) {
         return Err(GatewayError::IncorrectProgramId);
@@ -73,11 +96,28 @@ pub fn verify_gatekeeper_address_and_account(
     // Gatekeeper account must be derived correctly from the gatekeeper and gatekeeper network
     let (gatekeeper_address, _gatekeeper_bump_seed) =
         get_gatekeeper_account_address(gatekeeper, gatekeeper_network);
+    if gatekeeper_address == *gatekeeper_account_info.key {
+        return Ok(*gatekeeper);
+    }
+
+    // Otherwise, the gatekeeper must be a delegate listed in the gatekeeper account
+    let gatekeeper_account = GatekeeperAccount::parse(gatekeeper_account_info)
+        .map_err(|_| GatewayError::IncorrectGatekeeper)?;
+    let (gatekeeper_address, _gatekeeper_bump_seed) =
+        get_gatekeeper_account_address(&gatekeeper_account.authority, gatekeeper_network);
     if gatekeeper_address != *gatekeeper_account_info.key {
         return Err(GatewayError::IncorrectGatekeeper);
     }
 
-    Ok(())
+    let delegate = gatekeeper_account
+        .delegate(gatekeeper)
+        .ok_or(GatewayError::IncorrectGatekeeper)?;
+    if !delegate.permissions.contains(permissions) {
+        msg!("Error: the delegate does not have the required permissions");
+        return Err(GatewayError::DelegateNotPermitted);
+    }
+
+    Ok(gatekeeper_account.authority)
 }
 
 // Expire feature addresses for gatekeeper networks are PDAs derived from the network address.
@@ -96,11 +136,39 @@ pub fn get_expire_address_with_seed(network: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
+/// Get the program-derived address of a feature account for the gatekeeper network
+pub fn get_network_feature_address(network: &Pubkey, feature: NetworkFeature) -> (Pubkey, u8) {
+    match feature {
+        NetworkFeature::UserTokenExpiry => get_expire_address_with_seed(network),
+        _ => Pubkey::find_program_address(
+            &[network.as_ref(), get_network_feature_seed(feature)],
+            &Gateway::program_id(),
+        ),
+    }
+}
+
+/// The seed string used to derive a network feature address
+pub fn get_network_feature_seed(feature: NetworkFeature) -> &'static [u8] {
+    match feature {
+        NetworkFeature::UserTokenExpiry => NETWORK_EXPIRE_FEATURE_SEED,
+        NetworkFeature::OwnerTransfer => NETWORK_TRANSFER_FEATURE_SEED,
+        NetworkFeature::MaxTokenLifetime => NETWORK_MAX_LIFETIME_FEATURE_SEED,
+        NetworkFeature::FreezeOnly => NETWORK_FREEZE_ONLY_FEATURE_SEED,
+        NetworkFeature::OwnerBurn => NETWORK_OWNER_BURN_FEATURE_SEED,
+    }
+}
+
 /// Defines the gateway token structure
-#[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize, BorshSchema, PartialEq)]
+///
+/// Version 0 tokens hold the fields up to `expire_time` in `GatewayToken::SIZE` bytes.
+/// Version 1 tokens are followed by an extension area, starting after those `GatewayToken::SIZE` bytes
+/// so that it does not move when the expire time is set. The extension area is a list of
+/// type-length-value entries (a little-endian u16 type, a little-endian u16 length and the borsh-encoded value),
+/// ending with a zero type or the end of the account.
+#[derive(Clone, Debug, Default, BorshSchema, PartialEq)]
 pub struct GatewayToken {
     /// Version flag that allows for future upgrades
-    /// Currently ignored and initialised to 0
+    /// 0 for legacy tokens, 1 for tokens with an extension area
     pub version: u8,
     /// If the token is a session token,
     /// this is set to the parent token that was used to generate it.
@@ -119,8 +187,13 @@ pub struct GatewayToken {
     pub state: GatewayTokenState,
     /// The expiry time of the token (unix timestamp) (expirable tokens only)
     pub expire_time: Option<UnixTimestamp>,
+    /// The entries of the extension area (version 1 tokens only)
+    pub extensions: Vec<GatewayTokenExtension>,
 }
 impl GatewayToken {
+    /// The version of newly issued gateway tokens
+    pub const CURRENT_VERSION: u8 = 1;
+
     // Fix the size of gateway token accounts to ensure that gateway token structs can be resized,
     // for example, if an expire time is added later.
     // Note - to avoid breaking backward compatibility, the unused, and deprecated fields,
@@ -138,6 +211,77 @@ impl GatewayToken {
             32 +
             1 + // owner_identity NOTE - SIZE FIXED TO 1 - DEPRECATED - see note above
             32 + 32 + 1 + (1 + 8);
+
+    /// The minimum account size of a version 1 gateway token: the version 0 fields, a state change
+    /// entry and the extension area terminator.
+    /// This reserves room for a state change, so that changing the state never needs a realloc.
+    pub const SIZE_V1: usize = Self::SIZE
+        + GatewayTokenExtension::HEADER_SIZE
+        + StateChange::SIZE
+        + GatewayTokenExtension::TERMINATOR_SIZE;
+
+    /// The account size needed to hold the gateway token, given its version and extensions
+    pub fn size(&self) -> usize {
+        if self.version == 0 {
+            return Self::SIZE;
+        }
+
+        let extensions_len: usize = self
+            .extensions
+            .iter()
+            .filter(|extension| !matches!(extension, GatewayTokenExtension::StateChange(_)))
+            .map(GatewayTokenExtension::packed_len)
+            .sum();
+        Self::SIZE_V1 + extensions_len
+    }
+
+    /// Legacy tokens can be upgraded to version 1, unless they use the deprecated fields,
+    /// which would move the extension area
+    pub fn can_upgrade(&self) -> bool {
+        self.version == 0 && self.parent_gateway_token.is_none() && self.owner_identity.is_none()
+    }
+
+    /// The number of bytes taken up by the fields that all versions share
+    fn base_len(&self) -> usize {
+        let option_pubkey_len = |key: &Option<Pubkey>| if key.is_some() { 33 } else { 1 };
+        1 + option_pubkey_len(&self.parent_gateway_token)
+            + 32
+            + option_pubkey_len(&self.owner_identity)
+            + 32
+            + 32
+            + 1
+            + if self.expire_time.is_some() { 9 } else { 1 }
+    }
+
+    /// Records a state change, if the token version has room for it
+    pub fn set_state(
+        &mut self,
+        state: GatewayTokenState,
+        reason: Option<StateChangeReason>,
+        timestamp: UnixTimestamp,
+    ) {
+        self.state = state;
+        if self.version > 0 {
+            self.set_extension(GatewayTokenExtension::StateChange(StateChange {
+                reason,
+                timestamp,
+            }));
+        }
+    }
+
+    /// Adds an extension, replacing any existing extension of the same type
+    pub fn set_extension(&mut self, extension: GatewayTokenExtension) {
+        let extension_type = extension.extension_type();
+        match self
+            .extensions
+            .iter_mut()
+            .find(|existing| existing.extension_type() == extension_type)
+        {
+            Some(existing) => *existing = extension,
+            None => self.extensions.push(extension),
+        }
+    }
+
     pub fn new(
         owner_wallet: &Pubkey,
         gatekeeper_network: &Pubkey,
@@ -145,7 +289,7 @@ impl GatewayToken {
         expire_time: &Option<UnixTimestamp>,
     ) -> Self {
         Self {
-            version: 0,
+            version: Self::CURRENT_VERSION,
             parent_gateway_token: None,
             owner_wallet: *owner_wallet,
 
@@ -154,6 +298,7 @@ impl GatewayToken {
             issuing_gatekeeper: *issuing_gatekeeper,
             state: Default::default(),
             expire_time: *expire_time,
+            extensions: vec![],
         }
     }
 
@@ -182,12 +327,67 @@ impl GatewayToken {
     }
 }
 
+impl BorshSerialize for GatewayToken {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        self.version.serialize(writer)?;
+        self.parent_gateway_token.serialize(writer)?;
+        self.owner_wallet.serialize(writer)?;
+        self.owner_identity.serialize(writer)?;
+        self.gatekeeper_network.serialize(writer)?;
+        self.issuing_gatekeeper.serialize(writer)?;
+        self.state.serialize(writer)?;
+        self.expire_time.serialize(writer)?;
+
+        if self.version > 0 {
+            let padding = Self::SIZE.saturating_sub(self.base_len());
+            writer.write_all(&vec![0; padding])?;
+            for extension in &self.extensions {
+                extension.serialize(writer)?;
+            }
+            // Terminate the extension area, in case an extension used to be longer
+            0u16.serialize(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for GatewayToken {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> IoResult<Self> {
+        let mut gateway_token = Self {
+            version: u8::deserialize_reader(reader)?,
+            parent_gateway_token: BorshDeserialize::deserialize_reader(reader)?,
+            owner_wallet: BorshDeserialize::deserialize_reader(reader)?,
+            owner_identity: BorshDeserialize::deserialize_reader(reader)?,
+            gatekeeper_network: BorshDeserialize::deserialize_reader(reader)?,
+            issuing_gatekeeper: BorshDeserialize::deserialize_reader(reader)?,
+            state: BorshDeserialize::deserialize_reader(reader)?,
+            expire_time: BorshDeserialize::deserialize_reader(reader)?,
+            extensions: vec![],
+        };
+
+        if gateway_token.version > 0 {
+            let padding = Self::SIZE.saturating_sub(gateway_token.base_len());
+            reader.read_exact(&mut vec![0; padding])?;
+            while let Some(extension) = GatewayTokenExtension::deserialize_entry(reader)? {
+                gateway_token.extensions.push(extension);
+            }
+        }
+
+        Ok(gateway_token)
+    }
+}
+
 pub trait GatewayTokenAccess {
     fn owner_wallet(&self) -> &Pubkey;
     fn gatekeeper_network(&self) -> &Pubkey;
     fn issuing_gatekeeper(&self) -> &Pubkey;
     fn state(&self) -> GatewayTokenState;
     fn expire_time(&self) -> Option<UnixTimestamp>;
+    /// The reason and time of the last state change, if recorded
+    fn state_change(&self) -> Option<StateChange> {
+        None
+    }
 }
 impl GatewayTokenAccess for GatewayToken {
     fn owner_wallet(&self) -> &Pubkey {
@@ -209,6 +409,15 @@ impl GatewayTokenAccess for GatewayToken {
     fn expire_time(&self) -> Option<UnixTimestamp> {
         self.expire_time
     }
+
+    fn state_change(&self) -> Option<StateChange> {
+        self.extensions
+            .iter()
+            .find_map(|extension| match extension {
+                GatewayTokenExtension::StateChange(state_change) => Some(*state_change),
+                _ => None,
+            })
+    }
 }
 pub trait GatewayTokenFunctions: GatewayTokenAccess {
     /// Checks if the gateway token is in a valid state
@@ -244,6 +453,245 @@ impl GatewayTokenState {
         &[Self::Active, Self::Frozen, Self::Revoked];
 }
 
+/// Why a gatekeeper changed the state of a gateway token.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub enum StateChangeReason {
+    /// The gatekeeper detected fraud by the token owner.
+    Fraud,
+    /// The token owner asked for the change.
+    UserRequested,
+    /// The owner wallet is believed to be compromised.
+    KeyCompromised,
+    /// The token was replaced by another token.
+    Superseded,
+    /// The owner no longer meets the requirements of the gatekeeper network.
+    RequirementsNotMet,
+    /// A network-specific reason code.
+    Other(u16),
+}
+
+/// The reason and time of the last change to a gateway token's state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct StateChange {
+    /// Why the state was changed, if the gatekeeper gave a reason
+    pub reason: Option<StateChangeReason>,
+    /// When the state was changed
+    pub timestamp: UnixTimestamp,
+}
+impl StateChange {
+    pub const SIZE: usize = (1 + 1 + 2) + 8;
+}
+
+/// An entry in the extension area of a version 1 gateway token.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSchema)]
+pub enum GatewayTokenExtension {
+    /// The reason and time of the last state change
+    StateChange(StateChange),
+    /// An extension this version of the program does not know, kept as it was read
+    Unknown { extension_type: u16, value: Vec<u8> },
+}
+impl GatewayTokenExtension {
+    /// The size of the type and length of an entry
+    pub const HEADER_SIZE: usize = 2 + 2;
+    /// The size of the zero type ending the extension area
+    pub const TERMINATOR_SIZE: usize = 2;
+
+    const STATE_CHANGE_TYPE: u16 = 1;
+
+    pub fn extension_type(&self) -> u16 {
+        match self {
+            GatewayTokenExtension::StateChange(_) => Self::STATE_CHANGE_TYPE,
+            GatewayTokenExtension::Unknown { extension_type, .. } => *extension_type,
+        }
+    }
+
+    /// The size of the entry, including its header
+    pub fn packed_len(&self) -> usize {
+        Self::HEADER_SIZE
+            + match self {
+                GatewayTokenExtension::StateChange(_) => StateChange::SIZE,
+                GatewayTokenExtension::Unknown { value, .. } => value.len(),
+            }
+    }
+
+    /// Reads the next entry of an extension area, or `None` at the end of it
+    fn deserialize_entry<R: Read>(reader: &mut R) -> IoResult<Option<Self>> {
+        let mut extension_type = [0; 2];
+        match reader.read_exact(&mut extension_type) {
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            result => result?,
+        }
+        let extension_type = u16::from_le_bytes(extension_type);
+        if extension_type == 0 {
+            return Ok(None);
+        }
+
+        let length = u16::deserialize_reader(reader)?;
+        let mut value = vec![0; length as usize];
+        reader.read_exact(&mut value)?;
+
+        Ok(Some(match extension_type {
+            Self::STATE_CHANGE_TYPE => GatewayTokenExtension::StateChange(
+                program_borsh::try_from_slice_incomplete(&value)?,
+            ),
+            _ => GatewayTokenExtension::Unknown {
+                extension_type,
+                value,
+            },
+        }))
+    }
+}
+impl BorshSerialize for GatewayTokenExtension {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        let value = match self {
+            GatewayTokenExtension::StateChange(state_change) => {
+                // Pad to the fixed size, so that the entry does not change length with the reason
+                let mut value = borsh::to_vec(state_change)?;
+                value.resize(StateChange::SIZE, 0);
+                value
+            }
+            GatewayTokenExtension::Unknown { value, .. } => value.clone(),
+        };
+        let length = u16::try_from(value.len())
+            .map_err(|_| IoError::new(ErrorKind::InvalidInput, "Extension too long"))?;
+
+        self.extension_type().serialize(writer)?;
+        length.serialize(writer)?;
+        writer.write_all(&value)
+    }
+}
+
+/// The state of a gatekeeper, set by its gatekeeper network.
+#[derive(
+    Default, Copy, Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema,
+)]
+pub enum GatekeeperState {
+    /// Functional gatekeeper
+    #[default]
+    Active,
+    /// Gatekeeper may not issue gateway tokens or extend their expiry,
+    /// but may still freeze, revoke and burn them
+    Frozen,
+    /// Gatekeeper may not change gateway tokens. Its gateway tokens are only rejected by verification
+    /// with `VerificationOptions::check_gatekeeper_account` set, which is passed the gatekeeper account,
+    /// see `Gateway::verify_gateway_token_account_info_with_gatekeeper`
+    Halted,
+}
+impl GatekeeperState {
+    /// Whether the gatekeeper may issue gateway tokens or extend their expiry
+    pub fn can_issue(&self) -> bool {
+        *self == GatekeeperState::Active
+    }
+
+    /// Whether the gatekeeper may change the state of gateway tokens or burn them
+    pub fn can_update(&self) -> bool {
+        *self != GatekeeperState::Halted
+    }
+}
+
+/// The operations a gatekeeper delegate may sign for, as a set of flags.
+#[derive(
+    Default, Copy, Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema,
+)]
+pub struct GatekeeperPermissions(u8);
+impl GatekeeperPermissions {
+    /// No permissions
+    pub const NONE: Self = Self(0);
+    /// Issue gateway tokens, or transfer them to a new owner
+    pub const ISSUE: Self = Self(1 << 0);
+    /// Freeze or unfreeze gateway tokens
+    pub const FREEZE: Self = Self(1 << 1);
+    /// Revoke gateway tokens
+    pub const REVOKE: Self = Self(1 << 2);
+    /// Update the expiry of gateway tokens
+    pub const UPDATE_EXPIRY: Self = Self(1 << 3);
+    /// Burn gateway tokens
+    pub const BURN: Self = Self(1 << 4);
+    /// All permissions
+    pub const ALL: Self = Self(
+        Self::ISSUE.0 | Self::FREEZE.0 | Self::REVOKE.0 | Self::UPDATE_EXPIRY.0 | Self::BURN.0,
+    );
+
+    /// Whether all of the given permissions are in this set
+    pub fn contains(&self, permissions: Self) -> bool {
+        self.0 & permissions.0 == permissions.0
+    }
+
+    /// Whether this set has no permissions
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+impl BitOr for GatekeeperPermissions {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// A key that may sign for a gatekeeper, within its permissions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct GatekeeperDelegate {
+    /// The delegate signer
+    pub key: Pubkey,
+    /// The operations the delegate may sign for
+    pub permissions: GatekeeperPermissions,
+}
+
+/// The data held in a gatekeeper account.
+///
+/// Gatekeeper accounts are created empty, which is read as an active gatekeeper without delegates.
+/// The layout starts with a version, so that fields can be added in a later version.
+#[derive(Default, Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct GatekeeperAccount {
+    /// The version of the gatekeeper account layout
+    pub version: u8,
+    /// The state of the gatekeeper
+    pub state: GatekeeperState,
+    /// The gatekeeper authority that the gatekeeper account is derived from.
+    /// Recorded when the account is first written to.
+    pub authority: Pubkey,
+    /// Keys that may sign for the gatekeeper authority
+    pub delegates: Vec<GatekeeperDelegate>,
+}
+impl GatekeeperAccount {
+    /// The version of the gatekeeper account layout written by this program
+    pub const CURRENT_VERSION: u8 = 0;
+
+    /// The delegate with the given key, if there is one
+    pub fn delegate(&self, key: &Pubkey) -> Option<&GatekeeperDelegate> {
+        self.delegates.iter().find(|delegate| delegate.key == *key)
+    }
+
+    /// Adds, updates or, given no permissions, removes a delegate
+    pub fn set_delegate(&mut self, key: &Pubkey, permissions: GatekeeperPermissions) {
+        self.delegates.retain(|delegate| delegate.key != *key);
+        if !permissions.is_empty() {
+            self.delegates.push(GatekeeperDelegate {
+                key: *key,
+                permissions,
+            });
+        }
+    }
+
+    /// Unpacks a gatekeeper account, which may be empty
+    pub fn parse(gatekeeper_account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        let data = gatekeeper_account_info.data.borrow();
+        if data.is_empty() {
+            return Ok(Self::default());
+        }
+
+        if data[0] != Self::CURRENT_VERSION {
+            msg!("Error: unsupported gatekeeper account version {}", data[0]);
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        program_borsh::try_from_slice_incomplete(&data)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -260,6 +708,7 @@ pub mod tests {
             issuing_gatekeeper: Default::default(),
             state: Default::default(),
             expire_time: None,
+            extensions: vec![],
         }
     }
 
@@ -271,6 +720,83 @@ pub mod tests {
         assert_eq!(token, deserialized);
     }
 
+    #[test]
+    fn serialize_version_1_data() {
+        let mut token = GatewayToken::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &None,
+        );
+        token.set_state(
+            GatewayTokenState::Revoked,
+            Some(StateChangeReason::Other(7)),
+            1_000,
+        );
+        let serialized = borsh::to_vec(&token).unwrap();
+        assert_eq!(serialized.len(), GatewayToken::SIZE_V1);
+
+        // the state change does not move when an expire time is set
+        token.set_expire_time(2_000);
+        let serialized_with_expiry = borsh::to_vec(&token).unwrap();
+        assert_eq!(
+            serialized[GatewayToken::SIZE..],
+            serialized_with_expiry[GatewayToken::SIZE..]
+        );
+        assert_eq!(
+            GatewayToken::try_from_slice(&serialized_with_expiry).unwrap(),
+            token
+        );
+    }
+
+    #[test]
+    fn deserialize_version_1_extension_area() {
+        let mut token = stub_gateway_token();
+        assert!(token.can_upgrade());
+        token.version = 1;
+
+        // a freshly reallocated extension area is zero-filled, so has no extensions
+        let mut account_data = vec![0; token.size()];
+        token.serialize(&mut account_data.as_mut_slice()).unwrap();
+        assert_eq!(
+            program_borsh::try_from_slice_incomplete::<GatewayToken>(&account_data).unwrap(),
+            token
+        );
+
+        // extensions unknown to the program are kept as they were read
+        token.set_extension(GatewayTokenExtension::Unknown {
+            extension_type: 100,
+            value: vec![1, 2, 3],
+        });
+        token.set_state(GatewayTokenState::Frozen, None, 1_000);
+        let mut account_data = vec![0; token.size()];
+        token.serialize(&mut account_data.as_mut_slice()).unwrap();
+        let deserialized =
+            program_borsh::try_from_slice_incomplete::<GatewayToken>(&account_data).unwrap();
+        assert_eq!(deserialized, token);
+        assert_eq!(
+            deserialized.state_change(),
+            Some(StateChange {
+                reason: None,
+                timestamp: 1_000
+            })
+        );
+    }
+
+    #[test]
+    fn version_0_tokens_do_not_record_state_changes() {
+        let mut token = stub_gateway_token();
+        token.set_state(
+            GatewayTokenState::Revoked,
+            Some(StateChangeReason::Fraud),
+            0,
+        );
+
+        assert_eq!(token.state, GatewayTokenState::Revoked);
+        assert_eq!(token.state_change(), None);
+        assert!(borsh::to_vec(&token).unwrap().len() <= GatewayToken::SIZE);
+    }
+
     #[test]
     fn is_inactive() {
         let mut token = stub_gateway_token();
@@ -281,6 +807,32 @@ pub mod tests {
         assert!(!token.is_valid());
     }
 
+    #[test]
+    fn network_feature_addresses_are_distinct() {
+        let network = Pubkey::new_unique();
+
+        assert_eq!(
+            get_network_feature_address(&network, NetworkFeature::UserTokenExpiry),
+            get_expire_address_with_seed(&network)
+        );
+
+        let features = [
+            NetworkFeature::UserTokenExpiry,
+            NetworkFeature::OwnerTransfer,
+            NetworkFeature::MaxTokenLifetime,
+            NetworkFeature::FreezeOnly,
+            NetworkFeature::OwnerBurn,
+        ];
+        for (i, feature) in features.iter().enumerate() {
+            for other in &features[i + 1..] {
+                assert_ne!(
+                    get_network_feature_address(&network, *feature),
+                    get_network_feature_address(&network, *other)
+                );
+            }
+        }
+    }
+
     #[test]
     fn has_expired() {
         init();
@@ -292,6 +844,156 @@ pub mod tests {
         assert!(!token.is_valid());
     }
 
+    #[test]
+    fn verify_gatekeeper_accepts_delegates_with_permissions() {
+        let program_id = Gateway::program_id();
+        let authority = Pubkey::new_unique();
+        let network = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let gatekeeper_address = get_gatekeeper_account_address(&authority, &network).0;
+
+        let mut gatekeeper_account = GatekeeperAccount {
+            authority,
+            ..Default::default()
+        };
+        gatekeeper_account.set_delegate(
+            &delegate,
+            GatekeeperPermissions::ISSUE | GatekeeperPermissions::FREEZE,
+        );
+        let mut lamports = 1_000_000;
+        let mut data = borsh::to_vec(&gatekeeper_account).unwrap();
+        let account_info = AccountInfo::new(
+            &gatekeeper_address,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            verify_gatekeeper_address_and_account(
+                &account_info,
+                &authority,
+                &network,
+                GatekeeperPermissions::ALL
+            ),
+            Ok(authority)
+        );
+        assert_eq!(
+            verify_gatekeeper_address_and_account(
+                &account_info,
+                &delegate,
+                &network,
+                GatekeeperPermissions::ISSUE
+            ),
+            Ok(authority)
+        );
+        assert_eq!(
+            verify_gatekeeper_address_and_account(
+                &account_info,
+                &delegate,
+                &network,
+                GatekeeperPermissions::REVOKE
+            ),
+            Err(GatewayError::DelegateNotPermitted)
+        );
+        assert_eq!(
+            verify_gatekeeper_address_and_account(
+                &account_info,
+                &Pubkey::new_unique(),
+                &network,
+                GatekeeperPermissions::ISSUE
+            ),
+            Err(GatewayError::IncorrectGatekeeper)
+        );
+        // the same delegate is not accepted for another network
+        assert_eq!(
+            verify_gatekeeper_address_and_account(
+                &account_info,
+                &delegate,
+                &Pubkey::new_unique(),
+                GatekeeperPermissions::ISSUE
+            ),
+            Err(GatewayError::IncorrectGatekeeper)
+        );
+
+        gatekeeper_account.set_delegate(&delegate, GatekeeperPermissions::NONE);
+        assert!(gatekeeper_account.delegates.is_empty());
+    }
+
+    #[test]
+    fn empty_gatekeeper_account_is_active() {
+        let key = Pubkey::new_unique();
+        let owner = Gateway::program_id();
+        let mut lamports = 1_000_000;
+        let mut empty_data = [];
+        let empty_account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut empty_data,
+            &owner,
+            false,
+            0,
+        );
+        assert_eq!(
+            GatekeeperAccount::parse(&empty_account).unwrap().state,
+            GatekeeperState::Active
+        );
+
+        let mut lamports = 1_000_000;
+        let mut data = borsh::to_vec(&GatekeeperAccount {
+            state: GatekeeperState::Halted,
+            ..Default::default()
+        })
+        .unwrap();
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        let state = GatekeeperAccount::parse(&account).unwrap().state;
+        assert_eq!(state, GatekeeperState::Halted);
+        assert!(!state.can_issue());
+        assert!(!state.can_update());
+    }
+
+    #[test]
+    fn gatekeeper_account_with_an_unknown_version_is_rejected() {
+        let key = Pubkey::new_unique();
+        let owner = Gateway::program_id();
+        let mut lamports = 1_000_000;
+        let mut data = borsh::to_vec(&GatekeeperAccount {
+            version: GatekeeperAccount::CURRENT_VERSION + 1,
+            ..Default::default()
+        })
+        .unwrap();
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            GatekeeperAccount::parse(&account),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
     pub trait CompoundIterator: Iterator + Sized {
         fn compound<I: IntoIterator>(self, other: I) -> CompoundIter<Self, I::IntoIter>
         where
@@ -4,7 +4,12 @@ mod common;
 
 use common::util::{assert_instruction_error, clone_keypair};
 use solana_gateway::instruction;
-use solana_gateway::state::get_gatekeeper_account_address;
+use solana_gateway::instruction::{
+    owner_burn_token, update_network_feature, NetworkFeature, NetworkFeatureConfig,
+};
+use solana_gateway::state::{
+    get_gatekeeper_account_address, get_gateway_token_address_with_seed, GatewayTokenState,
+};
 use solana_program::instruction::InstructionError;
 use solana_program::instruction::InstructionError::Custom;
 use solana_program::pubkey::Pubkey;
@@ -24,6 +29,42 @@ async fn setup() -> GatewayContext {
     context
 }
 
+async fn add_owner_burn_feature(context: &mut GatewayContext, require_inactive: bool) {
+    let update_feature_tx = Transaction::new_signed_with_payer(
+        &[update_network_feature(
+            context.context.borrow().payer.pubkey(),
+            context.gatekeeper_network.as_ref().unwrap().pubkey(),
+            NetworkFeatureConfig::OwnerBurn { require_inactive },
+        )],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[
+            &context.context.borrow().payer,
+            context.gatekeeper_network.as_ref().unwrap(),
+        ],
+        context.context.borrow().last_blockhash,
+    );
+    context
+        .execute_transaction(update_feature_tx)
+        .await
+        .unwrap();
+}
+
+fn owner_burn_transaction(context: &GatewayContext) -> Transaction {
+    let gatekeeper_network = context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let (gateway_token, _) =
+        get_gateway_token_address_with_seed(&context.owner.pubkey(), &None, &gatekeeper_network);
+    Transaction::new_signed_with_payer(
+        &[owner_burn_token(
+            &gateway_token,
+            &context.owner.pubkey(),
+            &gatekeeper_network,
+        )],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[&context.context.borrow().payer, &context.owner],
+        context.context.borrow().last_blockhash,
+    )
+}
+
 #[tokio::test]
 async fn burn_should_succeed() {
     let mut context = setup().await;
@@ -46,6 +87,7 @@ async fn burn_wrong_account_type_should_fail() {
             &context.gatekeeper_account.unwrap(), // incorrect gateway token
             &authority.pubkey(),
             &context.gatekeeper_account.unwrap(),
+            &context.gatekeeper_network.as_ref().unwrap().pubkey(),
             &Pubkey::new_unique(),
         )],
         Some(&context.context.borrow().payer.pubkey()),
@@ -71,6 +113,7 @@ async fn burn_non_gateway_account_type_should_fail() {
             &account, // incorrect gateway token
             &authority.pubkey(),
             &context.gatekeeper_account.unwrap(),
+            &context.gatekeeper_network.as_ref().unwrap().pubkey(),
             &Pubkey::new_unique(),
         )],
         Some(&context.context.borrow().payer.pubkey()),
@@ -113,6 +156,7 @@ async fn burn_missing_gatekeeper_signature_should_fail() {
         &context.context.borrow().payer.pubkey(),
         &context.owner.pubkey(),
         &gatekeeper_account,
+        network,
         &Pubkey::new_unique(),
     );
     // gatekeeper authority has index 1
@@ -129,3 +173,103 @@ async fn burn_missing_gatekeeper_signature_should_fail() {
 
     assert_instruction_error(result, InstructionError::MissingRequiredSignature);
 }
+
+#[tokio::test]
+async fn owner_burn_should_succeed() {
+    let mut context = setup().await;
+    add_owner_burn_feature(&mut context, false).await;
+
+    let tx = owner_burn_transaction(&context);
+    context.execute_transaction(tx).await.unwrap();
+
+    let gt_option = context.get_gateway_token(&context.owner.pubkey()).await;
+    assert!(gt_option.is_none());
+}
+
+#[tokio::test]
+async fn owner_burn_without_feature_should_fail() {
+    let mut context = setup().await;
+
+    let tx = owner_burn_transaction(&context);
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, Custom(20)); // OwnerBurnNotEnabled
+}
+
+#[tokio::test]
+async fn owner_burn_active_token_when_inactive_required_should_fail() {
+    let mut context = setup().await;
+    add_owner_burn_feature(&mut context, true).await;
+
+    let tx = owner_burn_transaction(&context);
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, Custom(16)); // TokenStillActive
+}
+
+#[tokio::test]
+async fn owner_burn_revoked_token_when_inactive_required_should_succeed() {
+    let mut context = setup().await;
+    add_owner_burn_feature(&mut context, true).await;
+
+    context
+        .set_gateway_token_state(&context.owner.pubkey(), GatewayTokenState::Revoked)
+        .await;
+
+    let tx = owner_burn_transaction(&context);
+    context.execute_transaction(tx).await.unwrap();
+
+    let gt_option = context.get_gateway_token(&context.owner.pubkey()).await;
+    assert!(gt_option.is_none());
+}
+
+#[tokio::test]
+async fn burn_in_a_freeze_only_network_should_fail() {
+    let mut context = setup().await;
+    context
+        .add_feature_to_network(NetworkFeature::FreezeOnly)
+        .await;
+
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    let tx = context.burn_gateway_token_transaction(
+        &context.owner.pubkey(),
+        &authority,
+        &context.gatekeeper_account.unwrap(),
+        &authority.pubkey(),
+    );
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, Custom(5)); // InvalidStateChange
+}
+
+#[tokio::test]
+async fn burn_without_the_freeze_only_feature_account_should_fail() {
+    let mut context = setup().await;
+
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    let network = &context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let (gatekeeper_account, _) = get_gatekeeper_account_address(&authority.pubkey(), network);
+    let (gateway_token, _) =
+        get_gateway_token_address_with_seed(&context.owner.pubkey(), &None, network);
+
+    let mut ix = instruction::burn_token(
+        &gateway_token,
+        &authority.pubkey(),
+        &gatekeeper_account,
+        network,
+        &authority.pubkey(),
+    );
+    // the freeze only feature account is the last account
+    ix.accounts.pop();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[&context.context.borrow().payer, &authority],
+        context.context.borrow().last_blockhash,
+    );
+
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, InstructionError::NotEnoughAccountKeys);
+}
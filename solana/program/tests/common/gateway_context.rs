@@ -3,12 +3,12 @@
 #![allow(clippy::await_holding_refcell_ref)]
 
 use super::util::clone_keypair;
-use crate::instruction::GatewayInstruction;
+use crate::instruction::{GatewayInstruction, IssueBatchEntry, NetworkFeature};
 use solana_gateway::processor::process_instruction;
 use solana_gateway::program_borsh::try_from_slice_incomplete;
 use solana_gateway::state::{
-    get_gatekeeper_account_address, get_gateway_token_address_with_seed, GatewayToken,
-    GatewayTokenState,
+    get_gatekeeper_account_address, get_gateway_token_address_with_seed, GatekeeperState,
+    GatewayToken, GatewayTokenState,
 };
 use solana_gateway::{instruction, Gateway};
 use solana_program::{pubkey::Pubkey, system_program};
@@ -143,6 +143,41 @@ impl GatewayContext {
         funds_to
     }
 
+    pub async fn add_feature_to_network(&mut self, feature: NetworkFeature) {
+        let gatekeeper_network = self.gatekeeper_network.as_ref().unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction::add_feature_to_network(
+                self.context.borrow().payer.pubkey(),
+                gatekeeper_network.pubkey(),
+                feature,
+            )],
+            Some(&self.context.borrow().payer.pubkey()),
+            &[&self.context.borrow().payer, gatekeeper_network],
+            self.context.borrow().last_blockhash,
+        );
+
+        self.execute_transaction(tx).await.unwrap();
+    }
+
+    pub async fn set_gatekeeper_state(&mut self, state: GatekeeperState) {
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction::set_gatekeeper_state(
+                &self.context.borrow().payer.pubkey(),
+                &self.gatekeeper_authority.as_ref().unwrap().pubkey(),
+                &self.gatekeeper_network.as_ref().unwrap().pubkey(),
+                state,
+            )],
+            Some(&self.context.borrow().payer.pubkey()),
+            &[
+                &self.context.borrow().payer,
+                self.gatekeeper_network.as_ref().unwrap(),
+            ],
+            self.context.borrow().last_blockhash,
+        );
+
+        self.execute_transaction(transaction).await.unwrap();
+    }
+
     pub fn add_gatekeeper_transaction(
         &mut self,
         authority: &Pubkey,
@@ -201,6 +236,7 @@ impl GatewayContext {
                 &gateway_token,
                 &gatekeeper_authority.pubkey(),
                 gatekeeper_account,
+                &self.gatekeeper_network.as_ref().unwrap().pubkey(),
                 expire_time,
             )],
             Some(&self.context.borrow().payer.pubkey()),
@@ -221,6 +257,7 @@ impl GatewayContext {
                 gateway_token,
                 &gatekeeper_authority.pubkey(),
                 gatekeeper_account,
+                &self.gatekeeper_network.as_ref().unwrap().pubkey(),
                 gateway_token_state,
             )],
             Some(&self.context.borrow().payer.pubkey()),
@@ -246,6 +283,7 @@ impl GatewayContext {
                 &gateway_token,
                 &gatekeeper_authority.pubkey(),
                 gatekeeper_account,
+                &self.gatekeeper_network.as_ref().unwrap().pubkey(),
                 recipient,
             )],
             Some(&self.context.borrow().payer.pubkey()),
@@ -351,6 +389,25 @@ impl GatewayContext {
         )
     }
 
+    pub fn issue_gateway_token_batch_transaction(
+        &mut self,
+        owners: &[(Pubkey, IssueBatchEntry)],
+    ) -> Transaction {
+        let authority = clone_keypair(self.gatekeeper_authority.as_ref().unwrap());
+        Transaction::new_signed_with_payer(
+            &[instruction::issue_batch(
+                &self.context.borrow().payer.pubkey(),
+                &self.gatekeeper_account.unwrap(),
+                &authority.pubkey(),
+                &self.gatekeeper_network.as_ref().unwrap().pubkey(),
+                owners,
+            )],
+            Some(&self.context.borrow().payer.pubkey()),
+            &[&self.context.borrow().payer, &authority],
+            self.context.borrow().last_blockhash,
+        )
+    }
+
     pub async fn update_gateway_token_expiry(
         &mut self,
         owner: &Pubkey,
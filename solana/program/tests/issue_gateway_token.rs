@@ -4,8 +4,13 @@ mod common;
 
 use common::util::{assert_instruction_error, clone_keypair};
 use solana_gateway::instruction;
-use solana_gateway::state::{get_gatekeeper_account_address, get_gateway_token_address_with_seed};
+use solana_gateway::instruction::IssueBatchEntry;
+use solana_gateway::state::{
+    get_gatekeeper_account_address, get_gateway_token_address_with_seed, GatekeeperPermissions,
+    GatekeeperState,
+};
 use solana_program::instruction::InstructionError;
+use solana_program::instruction::InstructionError::Custom;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signer};
 use solana_sdk::transaction::Transaction;
@@ -203,3 +208,161 @@ async fn issue_should_fail_if_already_issued() {
 
     assert_instruction_error(result, InstructionError::AccountAlreadyInitialized);
 }
+
+#[tokio::test]
+async fn issue_batch_should_succeed() {
+    let mut context = setup().await;
+
+    let future = GatewayContext::now() + 100_000;
+    let owners = [
+        (Pubkey::new_unique(), IssueBatchEntry::default()),
+        (
+            Pubkey::new_unique(),
+            IssueBatchEntry {
+                seed: None,
+                expire_time: Some(future),
+            },
+        ),
+    ];
+
+    let tx = context.issue_gateway_token_batch_transaction(&owners);
+    context.execute_transaction(tx).await.unwrap();
+
+    for (owner, entry) in owners {
+        let gateway_token = context.get_gateway_token(&owner).await.unwrap();
+        assert_eq!(gateway_token.owner_wallet, owner);
+        assert_eq!(gateway_token.expire_time, entry.expire_time);
+    }
+}
+
+#[tokio::test]
+async fn issue_batch_should_fail_if_any_token_already_issued() {
+    let mut context = setup().await;
+
+    context
+        .issue_gateway_token(&context.owner.pubkey(), None)
+        .await;
+
+    let owners = [
+        (Pubkey::new_unique(), IssueBatchEntry::default()),
+        (context.owner.pubkey(), IssueBatchEntry::default()),
+    ];
+    let tx = context.issue_gateway_token_batch_transaction(&owners);
+
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, InstructionError::AccountAlreadyInitialized);
+    assert!(context.get_gateway_token(&owners[0].0).await.is_none());
+}
+
+#[tokio::test]
+async fn issue_should_fail_if_gatekeeper_frozen() {
+    let mut context = setup().await;
+    context.set_gatekeeper_state(GatekeeperState::Frozen).await;
+
+    let tx = context.issue_gateway_token_transaction(&context.owner.pubkey(), None);
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, Custom(18)); // InvalidGatekeeperState
+}
+
+#[tokio::test]
+async fn issue_by_delegate_should_record_the_gatekeeper_authority() {
+    let mut context = setup().await;
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    let network = context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let delegate = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::set_gatekeeper_delegate(
+            &context.context.borrow().payer.pubkey(),
+            &authority.pubkey(),
+            &network,
+            &delegate.pubkey(),
+            GatekeeperPermissions::ISSUE,
+        )],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[&context.context.borrow().payer, &authority],
+        context.context.borrow().last_blockhash,
+    );
+    context.execute_transaction(tx).await.unwrap();
+
+    let tx = context.issue_gateway_token_detailed(
+        &context.owner.pubkey(),
+        &delegate,
+        &context.gatekeeper_account.unwrap(),
+        &network,
+        None,
+    );
+    context.execute_transaction(tx).await.unwrap();
+
+    let gateway_token = context
+        .get_gateway_token(&context.owner.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(gateway_token.issuing_gatekeeper, authority.pubkey());
+}
+
+#[tokio::test]
+async fn issue_should_fail_without_the_max_token_lifetime_feature_account() {
+    let mut context = setup().await;
+
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    let network = &context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let (gatekeeper_account, _) = get_gatekeeper_account_address(&authority.pubkey(), network);
+
+    let mut ix = instruction::issue(
+        &context.context.borrow().payer.pubkey(),
+        &context.owner.pubkey(),
+        &gatekeeper_account,
+        &authority.pubkey(),
+        network,
+        None,
+        None,
+    );
+    // the max token lifetime feature account is the last account
+    ix.accounts.pop();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[&context.context.borrow().payer, &authority],
+        context.context.borrow().last_blockhash,
+    );
+
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, InstructionError::NotEnoughAccountKeys);
+}
+
+#[tokio::test]
+async fn issue_should_fail_if_wrong_max_token_lifetime_feature_account() {
+    let mut context = setup().await;
+
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    let network = &context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let (gatekeeper_account, _) = get_gatekeeper_account_address(&authority.pubkey(), network);
+
+    let mut ix = instruction::issue(
+        &context.context.borrow().payer.pubkey(),
+        &context.owner.pubkey(),
+        &gatekeeper_account,
+        &authority.pubkey(),
+        network,
+        None,
+        None,
+    );
+    // replace the max token lifetime feature account with a random one
+    ix.accounts[8].pubkey = Pubkey::new_unique();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[&context.context.borrow().payer, &authority],
+        context.context.borrow().last_blockhash,
+    );
+
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, InstructionError::InvalidArgument);
+}
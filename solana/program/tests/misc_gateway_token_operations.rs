@@ -2,9 +2,13 @@
 
 mod common;
 
+use common::util::assert_instruction_error;
+use solana_gateway::state::{get_gateway_token_address_with_seed, GatewayToken};
 use solana_gateway::{instruction, Gateway};
-use solana_program::instruction::Instruction;
-use solana_sdk::signature::Signer;
+use solana_program::instruction::{Instruction, InstructionError};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
 use solana_sdk::transaction::Transaction;
 use {common::gateway_context::GatewayContext, solana_program_test::tokio};
 
@@ -55,3 +59,92 @@ async fn burn_and_reissue_a_gateway_token() {
         context.gatekeeper_authority.unwrap().pubkey()
     );
 }
+
+/// Stores a version 0 gateway token for a new owner at the given address, padded to `size` bytes,
+/// returning the owner
+fn add_legacy_gateway_token(
+    context: &GatewayContext,
+    address: Option<Pubkey>,
+    size: usize,
+) -> Pubkey {
+    let owner = Keypair::new().pubkey();
+    let gatekeeper_network = context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let mut gateway_token = GatewayToken::new(
+        &owner,
+        &gatekeeper_network,
+        &context.gatekeeper_authority.as_ref().unwrap().pubkey(),
+        &Some(4794223772),
+    );
+    gateway_token.version = 0;
+
+    let mut data = borsh::to_vec(&gateway_token).unwrap();
+    data.resize(size, 0);
+    let address = address.unwrap_or_else(|| {
+        get_gateway_token_address_with_seed(&owner, &None, &gatekeeper_network).0
+    });
+    context.context.borrow_mut().set_account(
+        &address,
+        &Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: Gateway::program_id(),
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    owner
+}
+
+async fn upgrade_gateway_token(
+    context: &mut GatewayContext,
+    gateway_token: &Pubkey,
+) -> Result<(), solana_program_test::BanksClientError> {
+    let payer = context.context.borrow().payer.pubkey();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction::upgrade_token(&payer, gateway_token, None)],
+        Some(&payer),
+        &[&context.context.borrow().payer],
+        context.context.borrow().last_blockhash,
+    );
+
+    context.execute_transaction(transaction).await
+}
+
+#[tokio::test]
+async fn upgrade_a_legacy_gateway_token() {
+    let mut context = setup().await;
+    let owner = add_legacy_gateway_token(&context, None, GatewayToken::SIZE);
+    let gatekeeper_network = context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let (address, _) = get_gateway_token_address_with_seed(&owner, &None, &gatekeeper_network);
+
+    upgrade_gateway_token(&mut context, &address).await.unwrap();
+
+    let gateway_token = context.get_gateway_token(&owner).await.unwrap();
+    assert_eq!(gateway_token.version, 1);
+    assert_eq!(gateway_token.owner_wallet, owner);
+}
+
+#[tokio::test]
+async fn upgrading_a_gateway_token_at_another_address_should_fail() {
+    let mut context = setup().await;
+    let address = Keypair::new().pubkey();
+    add_legacy_gateway_token(&context, Some(address), GatewayToken::SIZE);
+
+    let result = upgrade_gateway_token(&mut context, &address).await;
+
+    assert_instruction_error(result, InstructionError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn upgrading_an_oversized_legacy_gateway_token_should_fail() {
+    let mut context = setup().await;
+    let owner = add_legacy_gateway_token(&context, None, GatewayToken::SIZE + 32);
+    let gatekeeper_network = context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let (address, _) = get_gateway_token_address_with_seed(&owner, &None, &gatekeeper_network);
+
+    let result = upgrade_gateway_token(&mut context, &address).await;
+
+    assert_instruction_error(result, InstructionError::InvalidAccountData);
+}
@@ -4,8 +4,10 @@ mod common;
 
 use common::util::{assert_instruction_error, clone_keypair};
 use solana_gateway::instruction;
+use solana_gateway::instruction::NetworkFeature;
 use solana_gateway::state::{
-    get_gatekeeper_account_address, get_gateway_token_address_with_seed, GatewayTokenState,
+    get_gatekeeper_account_address, get_gateway_token_address_with_seed, GatekeeperState,
+    GatewayTokenState,
 };
 use solana_program::instruction::InstructionError;
 use solana_program::instruction::InstructionError::Custom;
@@ -208,6 +210,7 @@ async fn set_state_missing_gatekeeper_signature_should_fail() {
         &context.context.borrow().payer.pubkey(),
         &context.owner.pubkey(),
         &gatekeeper_account,
+        network,
         GatewayTokenState::Revoked,
     );
     // gatekeeper authority has index 1
@@ -224,3 +227,87 @@ async fn set_state_missing_gatekeeper_signature_should_fail() {
 
     assert_instruction_error(result, InstructionError::MissingRequiredSignature);
 }
+
+#[tokio::test]
+async fn revoke_by_frozen_gatekeeper_should_succeed() {
+    let mut context = setup().await;
+    context.set_gatekeeper_state(GatekeeperState::Frozen).await;
+
+    let gateway_token = context
+        .set_gateway_token_state(&context.owner.pubkey(), GatewayTokenState::Revoked)
+        .await;
+
+    assert_eq!(gateway_token.state, GatewayTokenState::Revoked);
+}
+
+#[tokio::test]
+async fn freeze_by_halted_gatekeeper_should_fail() {
+    let mut context = setup().await;
+    context.set_gatekeeper_state(GatekeeperState::Halted).await;
+
+    let tx = context
+        .set_gateway_token_state_transaction(&context.owner.pubkey(), GatewayTokenState::Frozen);
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, Custom(18)); // InvalidGatekeeperState
+}
+
+#[tokio::test]
+async fn revoke_in_a_freeze_only_network_should_fail() {
+    let mut context = setup().await;
+    context
+        .add_feature_to_network(NetworkFeature::FreezeOnly)
+        .await;
+
+    let tx = context
+        .set_gateway_token_state_transaction(&context.owner.pubkey(), GatewayTokenState::Revoked);
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, Custom(5)); // InvalidStateChange
+}
+
+#[tokio::test]
+async fn freeze_in_a_freeze_only_network_should_succeed() {
+    let mut context = setup().await;
+    context
+        .add_feature_to_network(NetworkFeature::FreezeOnly)
+        .await;
+
+    let gateway_token = context
+        .set_gateway_token_state(&context.owner.pubkey(), GatewayTokenState::Frozen)
+        .await;
+
+    assert_eq!(gateway_token.state, GatewayTokenState::Frozen);
+}
+
+#[tokio::test]
+async fn set_state_without_the_freeze_only_feature_account_should_fail() {
+    let mut context = setup().await;
+
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    let network = &context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let (gatekeeper_account, _) = get_gatekeeper_account_address(&authority.pubkey(), network);
+    let (gateway_token, _) =
+        get_gateway_token_address_with_seed(&context.owner.pubkey(), &None, network);
+
+    let mut ix = instruction::set_state(
+        &gateway_token,
+        &authority.pubkey(),
+        &gatekeeper_account,
+        network,
+        GatewayTokenState::Revoked,
+    );
+    // the freeze only feature account is the last account
+    ix.accounts.pop();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[&context.context.borrow().payer, &authority],
+        context.context.borrow().last_blockhash,
+    );
+
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, InstructionError::NotEnoughAccountKeys);
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "test-sbf")]
+#![allow(clippy::await_holding_refcell_ref)]
+
+mod common;
+
+use crate::instruction::{add_feature_to_network, transfer_to_new_owner, NetworkFeature};
+use common::util::{assert_instruction_error, clone_keypair};
+use solana_gateway::instruction;
+use solana_gateway::state::{get_gateway_token_address_with_seed, GatewayTokenState};
+use solana_program::instruction::InstructionError;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use {common::gateway_context::GatewayContext, solana_program_test::tokio};
+
+async fn setup() -> (GatewayContext, Pubkey) {
+    let mut context = GatewayContext::new().await;
+    context.create_gatekeeper().await;
+
+    // issue a token
+    context
+        .issue_gateway_token(&context.owner.pubkey(), None)
+        .await;
+
+    let gateway_token_address = get_gateway_token_address_with_seed(
+        &context.owner.pubkey(),
+        &None,
+        &context.gatekeeper_network.as_ref().unwrap().pubkey(),
+    )
+    .0;
+
+    (context, gateway_token_address)
+}
+
+async fn add_transfer_feature(context: &mut GatewayContext) {
+    let add_feature_tx = Transaction::new_signed_with_payer(
+        &[add_feature_to_network(
+            context.context.borrow().payer.pubkey(),
+            context.gatekeeper_network.as_ref().unwrap().pubkey(),
+            NetworkFeature::OwnerTransfer,
+        )],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[
+            &context.context.borrow().payer,
+            context.gatekeeper_network.as_ref().unwrap(),
+        ],
+        context.context.borrow().last_blockhash,
+    );
+    context.execute_transaction(add_feature_tx).await.unwrap();
+}
+
+fn transfer_transaction(
+    context: &GatewayContext,
+    gateway_token: &Pubkey,
+    new_owner: &Pubkey,
+) -> Transaction {
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    Transaction::new_signed_with_payer(
+        &[transfer_to_new_owner(
+            &context.context.borrow().payer.pubkey(),
+            gateway_token,
+            &context.owner.pubkey(),
+            new_owner,
+            &authority.pubkey(),
+            &context.gatekeeper_network.as_ref().unwrap().pubkey(),
+            None,
+        )],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[&context.context.borrow().payer, &context.owner, &authority],
+        context.context.borrow().last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn transfer_to_new_owner_should_succeed() {
+    let (mut context, gateway_token_address) = setup().await;
+    add_transfer_feature(&mut context).await;
+
+    let frozen_gateway_token = context
+        .set_gateway_token_state(&context.owner.pubkey(), GatewayTokenState::Frozen)
+        .await;
+
+    let new_owner = Keypair::new().pubkey();
+    let tx = transfer_transaction(&context, &gateway_token_address, &new_owner);
+    context.execute_transaction(tx).await.unwrap();
+
+    // the old gateway token is deleted
+    assert!(context
+        .get_gateway_token(&context.owner.pubkey())
+        .await
+        .is_none());
+
+    let gateway_token = context.get_gateway_token(&new_owner).await.unwrap();
+    assert_eq!(gateway_token.owner_wallet, new_owner);
+    assert_eq!(
+        gateway_token.issuing_gatekeeper,
+        frozen_gateway_token.issuing_gatekeeper
+    );
+    assert_eq!(gateway_token.state, GatewayTokenState::Frozen);
+    assert_eq!(gateway_token.expire_time, frozen_gateway_token.expire_time);
+}
+
+#[tokio::test]
+async fn transfer_to_new_owner_without_feature_should_fail() {
+    let (mut context, gateway_token_address) = setup().await;
+
+    let tx = transfer_transaction(&context, &gateway_token_address, &Pubkey::new_unique());
+
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, InstructionError::IllegalOwner);
+}
+
+#[tokio::test]
+async fn transfer_to_new_owner_without_owner_signature_should_fail() {
+    let (mut context, gateway_token_address) = setup().await;
+    add_transfer_feature(&mut context).await;
+
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    let mut ix = instruction::transfer_to_new_owner(
+        &context.context.borrow().payer.pubkey(),
+        &gateway_token_address,
+        &context.owner.pubkey(),
+        &Pubkey::new_unique(),
+        &authority.pubkey(),
+        &context.gatekeeper_network.as_ref().unwrap().pubkey(),
+        None,
+    );
+
+    // owner has index 2
+    ix.accounts[2].is_signer = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[&context.context.borrow().payer, &authority],
+        context.context.borrow().last_blockhash,
+    );
+
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, InstructionError::MissingRequiredSignature);
+}
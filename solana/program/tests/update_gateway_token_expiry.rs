@@ -3,7 +3,10 @@
 
 mod common;
 
-use crate::instruction::{add_feature_to_network, expire_token, NetworkFeature};
+use crate::instruction::{
+    add_feature_to_network, expire_token, update_network_feature, NetworkFeature,
+    NetworkFeatureConfig,
+};
 use common::util::{assert_instruction_error, clone_keypair};
 use solana_gateway::instruction;
 use solana_gateway::state::{get_gatekeeper_account_address, get_gateway_token_address_with_seed};
@@ -104,6 +107,7 @@ async fn update_expiry_wrong_account_should_fail() {
                 &context.gatekeeper_account.unwrap(),
                 &authority.pubkey(),
                 &context.gatekeeper_account.unwrap(),
+                &context.gatekeeper_network.as_ref().unwrap().pubkey(),
                 10,
             )],
             Some(&context.context.borrow().payer.pubkey()),
@@ -137,6 +141,7 @@ async fn update_expiry_non_gateway_account_should_fail() {
                 &account,
                 &authority.pubkey(),
                 &context.gatekeeper_account.unwrap(),
+                &context.gatekeeper_network.as_ref().unwrap().pubkey(),
                 10,
             )],
             Some(&context.context.borrow().payer.pubkey()),
@@ -167,6 +172,7 @@ async fn update_expiry_missing_gatekeeper_signature_should_fail() {
         &gateway_token_address,
         &context.owner.pubkey(),
         &gatekeeper_account,
+        network,
         100,
     );
     // gatekeeper authority has index 1
@@ -381,3 +387,90 @@ async fn expire_token_should_fail_if_the_token_is_for_a_different_network() {
 
     assert_instruction_error(result, InstructionError::InvalidAccountData);
 }
+
+#[tokio::test]
+async fn update_expiry_beyond_max_token_lifetime_should_fail() {
+    let (mut context, _) = setup().await;
+
+    let update_feature_tx = Transaction::new_signed_with_payer(
+        &[update_network_feature(
+            context.context.borrow().payer.pubkey(),
+            context.gatekeeper_network.as_ref().unwrap().pubkey(),
+            NetworkFeatureConfig::MaxTokenLifetime { max_lifetime: 100 },
+        )],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[
+            &context.context.borrow().payer,
+            context.gatekeeper_network.as_ref().unwrap(),
+        ],
+        context.context.borrow().last_blockhash,
+    );
+    context
+        .execute_transaction(update_feature_tx)
+        .await
+        .unwrap();
+
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    let tx = context.update_gateway_token_expiry_transaction(
+        &context.owner.pubkey(),
+        &authority,
+        &context.gatekeeper_account.unwrap(),
+        GatewayContext::now() + 1000,
+    );
+
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, Custom(15)); // TokenLifetimeTooLong
+}
+
+#[tokio::test]
+async fn update_network_feature_with_negative_max_token_lifetime_should_fail() {
+    let (mut context, _) = setup().await;
+
+    let update_feature_tx = Transaction::new_signed_with_payer(
+        &[update_network_feature(
+            context.context.borrow().payer.pubkey(),
+            context.gatekeeper_network.as_ref().unwrap().pubkey(),
+            NetworkFeatureConfig::MaxTokenLifetime { max_lifetime: -1 },
+        )],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[
+            &context.context.borrow().payer,
+            context.gatekeeper_network.as_ref().unwrap(),
+        ],
+        context.context.borrow().last_blockhash,
+    );
+    let result = context.execute_transaction(update_feature_tx).await;
+
+    assert_instruction_error(result, InstructionError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn update_expiry_without_the_max_token_lifetime_feature_account_should_fail() {
+    let (mut context, gateway_token_address) = setup().await;
+
+    let authority = clone_keypair(context.gatekeeper_authority.as_ref().unwrap());
+    let network = &context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let (gatekeeper_account, _) = get_gatekeeper_account_address(&authority.pubkey(), network);
+
+    let mut ix = instruction::update_expiry(
+        &gateway_token_address,
+        &authority.pubkey(),
+        &gatekeeper_account,
+        network,
+        GatewayContext::now() + 1000,
+    );
+    // the max token lifetime feature account is the last account
+    ix.accounts.pop();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.context.borrow().payer.pubkey()),
+        &[&context.context.borrow().payer, &authority],
+        context.context.borrow().last_blockhash,
+    );
+
+    let result = context.execute_transaction(tx).await;
+
+    assert_instruction_error(result, InstructionError::NotEnoughAccountKeys);
+}
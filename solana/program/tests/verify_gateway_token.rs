@@ -0,0 +1,88 @@
+#![cfg(feature = "test-sbf")]
+#![allow(clippy::await_holding_refcell_ref)]
+
+mod common;
+
+use solana_gateway::error::GatewayError;
+use solana_gateway::state::{get_gateway_token_address_with_seed, GatekeeperState};
+use solana_gateway::{instruction, Gateway, VerificationOptions, DEFAULT_VERIFICATION_OPTIONS};
+use solana_program::account_info::IntoAccountInfo;
+use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use solana_sdk::signature::Signer;
+use {common::gateway_context::GatewayContext, solana_program_test::tokio};
+
+async fn setup() -> GatewayContext {
+    let mut context = GatewayContext::new().await;
+    context.create_gatekeeper().await;
+
+    // issue a token
+    context
+        .issue_gateway_token(&context.owner.pubkey(), None)
+        .await;
+
+    context
+}
+
+async fn get_account(context: &GatewayContext, address: &Pubkey) -> Account {
+    context
+        .context
+        .borrow_mut()
+        .banks_client
+        .get_account(*address)
+        .await
+        .unwrap()
+        // a closed account has no lamports or data
+        .unwrap_or_default()
+}
+
+/// Verification runs outside of a transaction, where the program test stubs have no context to log to.
+/// The stubs are global, so this file keeps to a single test that swaps them.
+struct VerificationStubs;
+impl SyscallStubs for VerificationStubs {}
+
+/// Verifies the owner's gateway token together with the account of its issuing gatekeeper,
+/// as read from the chain
+async fn verify_with_gatekeeper(context: &GatewayContext) -> Result<(), GatewayError> {
+    let gatekeeper_network = context.gatekeeper_network.as_ref().unwrap().pubkey();
+    let (gateway_token, _) =
+        get_gateway_token_address_with_seed(&context.owner.pubkey(), &None, &gatekeeper_network);
+    let gatekeeper_account = context.gatekeeper_account.unwrap();
+
+    let mut gateway_token_account = get_account(context, &gateway_token).await;
+    let mut gatekeeper_account_account = get_account(context, &gatekeeper_account).await;
+
+    let program_test_stubs = set_syscall_stubs(Box::new(VerificationStubs));
+    let result = Gateway::verify_gateway_token_account_info_with_gatekeeper(
+        &(&gateway_token, &mut gateway_token_account).into_account_info(),
+        &(&gatekeeper_account, &mut gatekeeper_account_account).into_account_info(),
+        &context.owner.pubkey(),
+        &gatekeeper_network,
+        Some(VerificationOptions {
+            check_gatekeeper_account: true,
+            ..DEFAULT_VERIFICATION_OPTIONS
+        }),
+    );
+    set_syscall_stubs(program_test_stubs);
+
+    result
+}
+
+#[tokio::test]
+async fn verify_with_gatekeeper_should_fail_once_the_gatekeeper_is_halted_or_removed() {
+    let mut context = setup().await;
+    assert_eq!(verify_with_gatekeeper(&context).await, Ok(()));
+
+    context.set_gatekeeper_state(GatekeeperState::Halted).await;
+    assert_eq!(
+        verify_with_gatekeeper(&context).await,
+        Err(GatewayError::InvalidGatekeeperState)
+    );
+
+    context.remove_gatekeeper().await;
+    assert_eq!(
+        verify_with_gatekeeper(&context).await,
+        Err(GatewayError::GatekeeperRemoved)
+    );
+}
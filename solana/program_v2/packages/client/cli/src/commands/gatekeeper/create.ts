@@ -4,7 +4,7 @@ import {
 } from '@identity.com/gateway-solana-client';
 import { Command, Flags } from '@oclif/core';
 import { Wallet } from '@coral-xyz/anchor';
-import { Keypair, PublicKey } from '@solana/web3.js';
+import { PublicKey } from '@solana/web3.js';
 import { readKeyFromFile } from '../../util/util';
 
 export default class Create extends Command {
@@ -54,7 +54,6 @@ export default class Create extends Command {
   async run(): Promise<void> {
     const { flags } = await this.parse(Create);
     const gatekeeperAddress = new PublicKey(flags.gatekeeper);
-    const stakingAccount = Keypair.generate().publicKey;
     const cluster =
       flags.cluster === 'localnet' ||
       flags.cluster === 'devnet' ||
@@ -109,11 +108,10 @@ export default class Create extends Command {
     };
 
     const gatekeeperSignature = await networkService
-      .createGatekeeper(stakingAccount, gatekeeperData)
+      .createGatekeeper(gatekeeperData)
       .withPartialSigners(networkAuthPair)
       .rpc();
 
-    this.log(`Staking Account: ${stakingAccount}`);
     this.log(`Gatekeeper Signature: ${gatekeeperSignature}`);
     const gkAccount = await networkService.getGatekeeperAccount();
     this.log(`AuthKey Flags: ${gkAccount?.authKeys.map((key) => key.flags)}`);
@@ -54,7 +54,6 @@ export default class Update extends Command {
 
     const gatekeeper = new PublicKey(flags.gatekeeper);
     const network = new PublicKey(flags.network);
-    const stakingAccount = new PublicKey(flags.stake);
     const cluster =
       flags.cluster === 'localnet' ||
       flags.cluster === 'devnet' ||
@@ -85,7 +84,7 @@ export default class Update extends Command {
     const parsedData = parseGatekeeperUpdateData(updateData);
 
     const updateGatekeeperSignature = await networkService
-      .updateGatekeeper(parsedData, stakingAccount) // data.json, stakingAccount and autority key?
+      .updateGatekeeper(parsedData)
       .rpc();
 
     this.log(updateGatekeeperSignature);
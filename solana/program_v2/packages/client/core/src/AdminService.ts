@@ -20,6 +20,17 @@ import {
   ServiceBuilder,
 } from './utils/AbstractService';
 
+const mapNetworkFees = (fee: FeeStructure) => ({
+  token: fee.token,
+  issue: fee.issue,
+  refresh: fee.refresh,
+  expire: fee.expire,
+  verify: fee.verify,
+  issueReferrer: fee.issueReferrer ?? 0,
+  verifyReferrer: fee.verifyReferrer ?? 0,
+  effectiveFrom: new anchor.BN(fee.effectiveFrom ?? 0),
+});
+
 /**
  * The AdminService is responsible for administrative functions for creating and managing gatekeepers within a network
  */
@@ -146,10 +157,11 @@ export class AdminService extends AbstractService {
       .createNetwork({
         authThreshold: data.authThreshold,
         passExpireTime: new anchor.BN(data.passExpireTime),
-        fees: data.fees,
+        fees: data.fees.map(mapNetworkFees),
         authKeys: data.authKeys,
         supportedTokens: data.supportedTokens,
         networkFeatures: data.networkFeatures,
+        feeNoticePeriod: new anchor.BN(data.feeNoticePeriod ?? 0),
       })
       .accounts({
         network: this._network,
@@ -183,10 +195,20 @@ export class AdminService extends AbstractService {
       .updateNetwork({
         authThreshold: data.authThreshold,
         passExpireTime: new anchor.BN(data.passExpireTime),
-        fees: data.fees,
+        fees: {
+          add: data.fees.add.map(mapNetworkFees),
+          remove: data.fees.remove.map((key) => ({
+            token: key.token,
+            effectiveFrom: new anchor.BN(key.effectiveFrom),
+          })),
+        },
         authKeys: data.authKeys,
         supportedTokens: data.supportedTokens,
         networkFeatures: data.networkFeatures,
+        feeNoticePeriod:
+          data.feeNoticePeriod === undefined
+            ? null
+            : new anchor.BN(data.feeNoticePeriod),
       })
       .accounts({
         network: this._network,
@@ -201,6 +201,7 @@ export class GatekeeperService extends AbstractService {
         pass: passAccount,
         authority,
         gatekeeper: this._gatekeeper,
+        network: this._network,
       })
       .instruction();
 
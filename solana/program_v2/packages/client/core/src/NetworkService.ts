@@ -15,6 +15,7 @@ import { ExtendedCluster, getConnectionByCluster } from './lib/connection';
 import { EnumMapper } from './lib/utils';
 import {
   GATEKEEPER_SEED,
+  GATEKEEPER_STAKE_SEED,
   GATEWAY_PROGRAM,
   SOLANA_MAINNET,
 } from './lib/constants';
@@ -136,12 +137,22 @@ export class NetworkService extends AbstractService {
     );
   }
 
-  //TODO! seeds derivation program side
-  static async createStakingAddress(
-    network: PublicKey
+  /**
+   * Derives the vault holding a gatekeeper's stake in the given mint
+   *
+   * @param gatekeeperAccount The PDA for the gatekeeper
+   * @param mint The mint the stake is held in
+   */
+  static async createStakeVaultAddress(
+    gatekeeperAccount: PublicKey,
+    mint: PublicKey
   ): Promise<[PublicKey, number]> {
     return PublicKey.findProgramAddress(
-      [anchor.utils.bytes.utf8.encode('gw-stake'), network.toBuffer()],
+      [
+        anchor.utils.bytes.utf8.encode(GATEKEEPER_STAKE_SEED),
+        gatekeeperAccount.toBuffer(),
+        mint.toBuffer(),
+      ],
       GATEWAY_PROGRAM
     );
   }
@@ -149,13 +160,11 @@ export class NetworkService extends AbstractService {
   /**
    * Creates a gatekeeper within the network
    *
-   * @param stakingAccount The staking account for the gatekeeper
    * @param data The initial state to create the gatekeeper with
    * @param payer The fee payer
    * @param network_authority The authority used to create the gatekeeper
    */
   createGatekeeper(
-    stakingAccount: PublicKey,
     data: CreateGatekeeperData = {
       tokenFees: [],
       authThreshold: 1,
@@ -182,6 +191,7 @@ export class NetworkService extends AbstractService {
           expire: new anchor.BN(fee.expire),
           verify: new anchor.BN(fee.verify),
           refresh: new anchor.BN(fee.refresh),
+          effectiveFrom: new anchor.BN(fee.effectiveFrom ?? 0),
         })),
         authThreshold: data.authThreshold,
         authKeys: data.authKeys,
@@ -190,7 +200,6 @@ export class NetworkService extends AbstractService {
         gatekeeper: this._gatekeeperAccount,
         authority: network_authority,
         network: this._network,
-        stakingAccount,
         systemProgram: anchor.web3.SystemProgram.programId,
         payer,
         subject: this._gatekeeper,
@@ -207,13 +216,11 @@ export class NetworkService extends AbstractService {
    * Updates the gatekeeper state within the network
    *
    * @param data The new state for the gatekeeper
-   * @param stakingAccount The staking account for the gatekeeper
    * @param payer The fee payer for the update in case of an account resize
    * @param authority The authority for the making the change
    */
   updateGatekeeper(
     data: UpdateGatekeeperData,
-    stakingAccount: PublicKey,
     payer: PublicKey = this._wallet.publicKey,
     authority: PublicKey = this._wallet.publicKey
   ): ServiceBuilder {
@@ -230,16 +237,20 @@ export class NetworkService extends AbstractService {
             expire: new anchor.BN(fee.expire),
             verify: new anchor.BN(fee.verify),
             refresh: new anchor.BN(fee.refresh),
+            effectiveFrom: new anchor.BN(fee.effectiveFrom ?? 0),
+          })),
+          remove: data.tokenFees.remove.map((key) => ({
+            token: key.token,
+            effectiveFrom: new anchor.BN(key.effectiveFrom),
           })),
-          remove: data.tokenFees.remove,
         },
         authKeys: data.authKeys,
       })
       .accounts({
         gatekeeper: this._gatekeeperAccount,
+        network: this._network,
         systemProgram: anchor.web3.SystemProgram.programId,
         authority,
-        stakingAccount,
         payer,
       })
       .instruction();
@@ -256,11 +267,13 @@ export class NetworkService extends AbstractService {
    * @param receiver The receiver of the rent reclaimed
    * @param payer The fee payer
    * @param network_authority the network authority required to create the gatekeeper
+   * @param addresses The gatekeeper's addresses account, closed alongside it if set
    */
   closeGatekeeper(
     receiver: PublicKey = this._network,
     payer: PublicKey = this._wallet.publicKey,
-    network_authority: PublicKey = this._network
+    network_authority: PublicKey = this._network,
+    addresses: PublicKey | null = null
   ): ServiceBuilder {
     const instructionPromise = this._program.methods
       //anchor IDL does not work with nested types
@@ -269,6 +282,7 @@ export class NetworkService extends AbstractService {
       .closeGatekeeper()
       .accounts({
         gatekeeper: this._gatekeeperAccount,
+        addresses,
         systemProgram: anchor.web3.SystemProgram.programId,
         destination: receiver,
         authority: network_authority,
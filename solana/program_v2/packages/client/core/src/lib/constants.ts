@@ -6,6 +6,7 @@ export const GATEWAY_PROGRAM = new PublicKey(
 
 export const GATEKEEPER_SEED = 'gw-gatekeeper';
 export const DEFAULT_PASS_SEED = 'gw-pass';
+export const GATEKEEPER_STAKE_SEED = 'gw-stake';
 
 export const SOLANA_MAINNET = 'mainnet-beta';
 
@@ -8,11 +8,21 @@ export type FeeStructure = {
   refresh: number;
   expire: number;
   verify: number;
+  // Only used for network fees
+  issueReferrer?: number;
+  verifyReferrer?: number;
+  // Unix timestamp the fees take effect at, immediately if omitted
+  effectiveFrom?: number;
+};
+
+export type FeeScheduleKey = {
+  token: PublicKey;
+  effectiveFrom: number;
 };
 
 export type UpdateFeeStructure = {
   add: FeeStructure[];
-  remove: PublicKey[];
+  remove: FeeScheduleKey[];
 };
 
 export type UpdateAuthKeyStructure = {
@@ -32,6 +42,7 @@ export type CreateNetworkData = {
   authKeys: AuthKeyStructure[];
   supportedTokens: SupportedToken[];
   networkFeatures: number;
+  feeNoticePeriod?: number;
 };
 
 export type UpdateNetworkData = {
@@ -41,6 +52,7 @@ export type UpdateNetworkData = {
   authKeys: UpdateAuthKeyStructure;
   networkFeatures: number;
   supportedTokens: UpdateSupportedTokens;
+  feeNoticePeriod?: number;
 };
 
 export type UpdateSupportedTokens = {
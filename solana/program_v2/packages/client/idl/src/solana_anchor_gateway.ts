@@ -35,6 +35,48 @@ export type SolanaAnchorGateway = {
         }
       ]
     },
+    {
+      "name": "createIndexedNetwork",
+      "accounts": [
+        {
+          "name": "registry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "networkIndex",
+          "type": "u16"
+        },
+        {
+          "name": "data",
+          "type": {
+            "defined": "CreateNetworkData"
+          }
+        }
+      ]
+    },
     {
       "name": "updateNetwork",
       "accounts": [
@@ -117,11 +159,6 @@ export type SolanaAnchorGateway = {
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "stakingAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -145,6 +182,11 @@ export type SolanaAnchorGateway = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": true,
@@ -155,11 +197,6 @@ export type SolanaAnchorGateway = {
           "isMut": true,
           "isSigner": true
         },
-        {
-          "name": "stakingAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -183,6 +220,12 @@ export type SolanaAnchorGateway = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "addresses",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "network",
           "isMut": true,
@@ -281,113 +324,168 @@ export type SolanaAnchorGateway = {
       ]
     },
     {
-      "name": "issuePass",
+      "name": "createGatekeeperAddresses",
       "accounts": [
         {
-          "name": "pass",
+          "name": "addresses",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "network",
-          "isMut": false,
+          "name": "gatekeeper",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gatekeeper",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "payer",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "CreateGatekeeperAddressesData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGatekeeperAddresses",
+      "accounts": [
+        {
+          "name": "addresses",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "funder",
-          "isMut": false,
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
         },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "UpdateGatekeeperAddressesData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "gatekeeperStake",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "splTokenProgram",
+          "name": "network",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "stakeVault",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "mint",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "funderTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "networkTokenAccount",
+          "name": "payer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gatekeeperTokenAccount",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "subject",
-          "type": "publicKey"
-        },
-        {
-          "name": "passNumber",
-          "type": "u16"
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "setPassState",
+      "name": "gatekeeperUnstake",
       "accounts": [
         {
-          "name": "pass",
+          "name": "gatekeeper",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "network",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "gatekeeper",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "state",
-          "type": {
-            "defined": "PassState"
-          }
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "refreshPass",
+      "name": "gatekeeperWithdrawStake",
       "accounts": [
         {
-          "name": "pass",
+          "name": "gatekeeper",
           "isMut": true,
           "isSigner": false
         },
@@ -396,54 +494,34 @@ export type SolanaAnchorGateway = {
           "isMut": false,
           "isSigner": true
         },
-        {
-          "name": "funder",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "network",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeper",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "splTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "funderTokenAccount",
+          "name": "receiverTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "networkTokenAccount",
+          "name": "stakeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gatekeeperTokenAccount",
-          "isMut": true,
+          "name": "mint",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "changePassGatekeeper",
+      "name": "slashGatekeeper",
       "accounts": [
         {
-          "name": "pass",
+          "name": "gatekeeper",
           "isMut": true,
           "isSigner": false
         },
@@ -458,64 +536,52 @@ export type SolanaAnchorGateway = {
           "isSigner": true
         },
         {
-          "name": "oldGatekeeper",
-          "isMut": false,
+          "name": "slashRecord",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newGatekeeper",
-          "isMut": false,
+          "name": "stakeVault",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "setPassData",
-      "accounts": [
+        },
         {
-          "name": "pass",
+          "name": "networkTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "gatekeeper",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "gatekeeperData",
-          "type": {
-            "option": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          }
-        },
-        {
-          "name": "networkData",
+          "name": "data",
           "type": {
-            "option": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "defined": "SlashGatekeeperData"
           }
         }
       ]
     },
     {
-      "name": "expirePass",
+      "name": "issuePass",
       "accounts": [
         {
           "name": "pass",
@@ -538,14 +604,20 @@ export type SolanaAnchorGateway = {
           "isSigner": true
         },
         {
-          "name": "funder",
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "funder",
           "isMut": false,
-          "isSigner": true
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "splTokenProgram",
@@ -560,7 +632,8 @@ export type SolanaAnchorGateway = {
         {
           "name": "funderTokenAccount",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "networkTokenAccount",
@@ -571,43 +644,239 @@ export type SolanaAnchorGateway = {
           "name": "gatekeeperTokenAccount",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "verifyPass",
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees in place of the funder"
+          ]
+        },
+        {
+          "name": "sponsorshipVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "passRequest",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from a subject's escrow, in place of the funder"
+          ]
+        },
+        {
+          "name": "passRequestEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from prepaid credit, in place of the funder's token account"
+          ]
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "subject",
+          "type": "publicKey"
+        },
+        {
+          "name": "passNumber",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setPassState",
       "accounts": [
         {
           "name": "pass",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "network",
           "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed to revoke a pass with an issue fee, to check the refund grace period"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "state",
+          "type": {
+            "defined": "PassState"
+          }
+        }
+      ]
+    },
+    {
+      "name": "refundPassFee",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "gatekeeper",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "networkTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "funder",
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectPassRequest",
+      "accounts": [
+        {
+          "name": "passRequest",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "subject",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subjectTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refreshPass",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "splTokenProgram",
           "isMut": false,
@@ -621,7 +890,8 @@ export type SolanaAnchorGateway = {
         {
           "name": "funderTokenAccount",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "networkTokenAccount",
@@ -632,942 +902,5117 @@ export type SolanaAnchorGateway = {
           "name": "gatekeeperTokenAccount",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees in place of the funder"
+          ]
+        },
+        {
+          "name": "sponsorshipVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from prepaid credit, in place of the funder's token account"
+          ]
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "gatekeeper",
-      "docs": [
-        "A gatekeeper on a [`GatekeeperNetwork`] that can issue passes"
+      "name": "changePassGatekeeper",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "oldGatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newGatekeeper",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "version",
-            "docs": [
-              "The version of this struct, should be 0 until a new version is released"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "subject",
-            "docs": [
-              "the authority for this gatekeeper"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "gatekeeperBump",
-            "docs": [
-              "The bump for the signer of this gatekeeper"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "gatekeeperNetwork",
-            "docs": [
-              "The [`GatekeeperNetwork`] this gatekeeper is on"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "stakingAccount",
-            "docs": [
-              "The staking account of this gatekeeper"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "gatekeeperState",
-            "docs": [
-              "The state of this gatekeeper"
-            ],
-            "type": {
-              "defined": "GatekeeperState"
-            }
-          },
-          {
-            "name": "tokenFees",
-            "docs": [
-              "The fees for this gatekeeper"
-            ],
-            "type": {
-              "vec": {
-                "defined": "GatekeeperFees"
-              }
-            }
-          },
-          {
-            "name": "authThreshold",
-            "docs": [
-              "The number of keys needed to change the `auth_keys`"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "authKeys",
-            "docs": [
-              "The keys with permissions on this gatekeeper"
-            ],
-            "type": {
-              "vec": {
-                "defined": "GatekeeperAuthKey"
-              }
-            }
-          }
-        ]
-      }
+      "args": []
     },
     {
-      "name": "gatekeeperNetwork",
-      "docs": [
-        "A gatekeeper network which manages many [`Gatekeeper`]s."
+      "name": "setPassData",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "version",
-            "docs": [
-              "The version of this struct, should be 0 until a new version is released"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "authority",
-            "docs": [
-              "The initial authority key"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "networkIndex",
-            "docs": [
-              "the index of the network"
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "passExpireTime",
-            "docs": [
-              "The length of time a pass lasts in seconds. `0` means does not expire."
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "networkFeatures",
-            "docs": [
-              "Features on the network, index relates to which feature it is. There are 32 bytes of data available for each feature."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "fees",
-            "docs": [
-              "The fees for this network"
-            ],
-            "type": {
-              "vec": {
-                "defined": "NetworkFeesPercentage"
-              }
-            }
-          },
-          {
-            "name": "supportedTokens",
-            "type": {
-              "vec": {
-                "defined": "SupportedToken"
-              }
-            }
-          },
-          {
-            "name": "gatekeepers",
-            "docs": [
-              "A set of all active gatekeepers in the network"
-            ],
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "authThreshold",
-            "docs": [
-              "The number of auth keys needed to change the `auth_keys`"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "authKeys",
-            "docs": [
-              "Keys with permissions on the network"
-            ],
-            "type": {
-              "vec": {
-                "defined": "AuthKey"
-              }
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "pass",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "version",
-            "docs": [
-              "The version of this struct, should be 0 until a new version is released"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "subject",
-            "docs": [
-              "The initial authority"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "network",
-            "docs": [
-              "The network this pass belongs to"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "passNumber",
-            "docs": [
-              "The pass number"
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "signerBump",
-            "docs": [
-              "The bump for the signer"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "gatekeeper",
-            "docs": [
-              "The gatekeeper that issued this pass"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "issueTime",
-            "docs": [
-              "The issue time of this pass, used for expiry"
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "state",
-            "docs": [
-              "The state of this pass"
-            ],
-            "type": {
-              "defined": "PassState"
-            }
-          },
-          {
-            "name": "networkData",
-            "docs": [
-              "Additional data from the network"
-            ],
-            "type": {
+      "args": [
+        {
+          "name": "gatekeeperData",
+          "type": {
+            "option": {
               "array": [
                 "u8",
                 32
               ]
             }
-          },
-          {
-            "name": "gatekeeperData",
-            "docs": [
-              "Additional data from the gatekeeper"
-            ],
-            "type": {
+          }
+        },
+        {
+          "name": "networkData",
+          "type": {
+            "option": {
               "array": [
                 "u8",
                 32
               ]
             }
           }
-        ]
-      }
-    }
-  ],
-  "types": [
+        }
+      ]
+    },
     {
-      "name": "CreateNetworkData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authThreshold",
-            "docs": [
-              "The [`GatekeeperNetwork::auth_threshold`]."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "passExpireTime",
-            "docs": [
-              "The [`GatekeeperNetwork::pass_expire_time`]."
-            ],
-            "type": "i64"
-          },
-          {
+      "name": "setPassExtendedData",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "section",
+          "type": {
+            "defined": "PassDataSection"
+          }
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "expirePass",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from prepaid credit, in place of the funder's token account"
+          ]
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "verifyPass",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees in place of the funder, if the pass subject or the sponsor verifies the pass"
+          ]
+        },
+        {
+          "name": "sponsorshipVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from prepaid credit, in place of the funder's token account"
+          ]
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSponsorship",
+      "accounts": [
+        {
+          "name": "sponsorship",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u16"
+        },
+        {
+          "name": "data",
+          "type": {
+            "defined": "CreateSponsorshipData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "fundSponsorship",
+      "accounts": [
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawSponsorship",
+      "accounts": [
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createSponsoredSubject",
+      "accounts": [
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "subject",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createFeeCredit",
+      "accounts": [
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "topUpFeeCredit",
+      "accounts": [
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawFeeCredit",
+      "accounts": [
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleFeeCredit",
+      "accounts": [
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createPassRequest",
+      "accounts": [
+        {
+          "name": "passRequest",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "subjectTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "deadline",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "reclaimPassRequest",
+      "accounts": [
+        {
+          "name": "passRequest",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subjectTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeemPassVoucher",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "redeemedVoucher",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees in place of the funder"
+          ]
+        },
+        {
+          "name": "sponsorshipVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "voucher",
+          "type": {
+            "defined": "PassVoucher"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeRedeemedVoucher",
+      "accounts": [
+        {
+          "name": "redeemedVoucher",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateAccount",
+      "accounts": [
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "feeCredit",
+      "docs": [
+        "Tokens prepaid by a funder for a single gatekeeper's fees. Operations debit the balance",
+        "without a token transfer, and the gatekeeper's and network's shares are paid out in bulk",
+        "by `settle_fee_credit`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "funder",
+            "docs": [
+              "The funder that prepaid the credit, and the only key that can spend or withdraw it"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The gatekeeper whose fees the credit pays"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "network",
+            "docs": [
+              "The network of the gatekeeper"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the prepaid tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "The token account holding the prepaid tokens, owned by this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "balance",
+            "docs": [
+              "The tokens still available to the funder"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gatekeeperOwed",
+            "docs": [
+              "Fees owed to the gatekeeper that have not yet been settled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "networkOwed",
+            "docs": [
+              "Fees owed to the network that have not yet been settled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gatekeeperAddresses",
+      "docs": [
+        "Discoverability information for a [`crate::state::Gatekeeper`], linked from",
+        "[`crate::state::Gatekeeper::addresses`].",
+        "",
+        "The fixed-size fields come first so clients can filter these accounts by gatekeeper network."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The [`crate::state::Gatekeeper`] these addresses belong to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeperNetwork",
+            "docs": [
+              "The [`crate::state::GatekeeperNetwork`] the gatekeeper is on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "termsOfServiceHash",
+            "docs": [
+              "A hash of the terms of service a subject agrees to when requesting a pass"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "did",
+            "docs": [
+              "The DID of the gatekeeper, empty if not set"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "serviceEndpoints",
+            "docs": [
+              "Endpoints (e.g. URLs) where the gatekeeper's services can be reached"
+            ],
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "supportedJurisdictions",
+            "docs": [
+              "The jurisdictions the gatekeeper can issue passes in, as ISO 3166-1 alpha-2 codes"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  2
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "gatekeeper",
+      "docs": [
+        "A gatekeeper on a [`GatekeeperNetwork`] that can issue passes"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct. Older accounts must be migrated to [`Self::VERSION`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "the authority for this gatekeeper"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeperBump",
+            "docs": [
+              "The bump for the signer of this gatekeeper"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "gatekeeperNetwork",
+            "docs": [
+              "The [`GatekeeperNetwork`] this gatekeeper is on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingAccount",
+            "docs": [
+              "The vault holding this gatekeeper's stake, default until tokens are first staked"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeperState",
+            "docs": [
+              "The state of this gatekeeper"
+            ],
+            "type": {
+              "defined": "GatekeeperState"
+            }
+          },
+          {
+            "name": "tokenFees",
+            "docs": [
+              "The fees for this gatekeeper"
+            ],
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFees"
+              }
+            }
+          },
+          {
+            "name": "authThreshold",
+            "docs": [
+              "The number of keys needed to change the `auth_keys`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "authKeys",
+            "docs": [
+              "The keys with permissions on this gatekeeper"
+            ],
+            "type": {
+              "vec": {
+                "defined": "GatekeeperAuthKey"
+              }
+            }
+          },
+          {
+            "name": "addresses",
+            "docs": [
+              "The [`GatekeeperAddresses`] account used for discoverability, default if not set"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "The tokens this gatekeeper has staked in the `staking_account`"
+            ],
+            "type": {
+              "defined": "GatekeeperStake"
+            }
+          },
+          {
+            "name": "referenceFees",
+            "docs": [
+              "Fees in the network's reference currency, charged for tokens without an entry in",
+              "`token_fees` using the network's price feed for the token"
+            ],
+            "type": {
+              "option": {
+                "defined": "ReferenceFees"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "networkRegistry",
+      "docs": [
+        "The global counter that keeps [`crate::state::GatekeeperNetwork::network_index`] unique"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "networkCount",
+            "docs": [
+              "The number of networks created from the registry, also the last index handed out"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gatekeeperNetwork",
+      "docs": [
+        "A gatekeeper network which manages many [`Gatekeeper`]s."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct. Older accounts must be migrated to [`Self::VERSION`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "The initial authority key"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "networkIndex",
+            "docs": [
+              "The index the network address is derived from, `0` if the network is not a PDA"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "passExpireTime",
+            "docs": [
+              "The length of time a pass lasts in seconds. `0` means does not expire."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "networkFeatures",
+            "docs": [
+              "Features on the network, index relates to which feature it is. There are 32 bytes of data available for each feature."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "The fees for this network"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NetworkFeesPercentage"
+              }
+            }
+          },
+          {
+            "name": "supportedTokens",
+            "type": {
+              "vec": {
+                "defined": "SupportedToken"
+              }
+            }
+          },
+          {
+            "name": "gatekeepers",
+            "docs": [
+              "A set of all active gatekeepers in the network"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "authThreshold",
+            "docs": [
+              "The number of auth keys needed to change the `auth_keys`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "authKeys",
+            "docs": [
+              "Keys with permissions on the network"
+            ],
+            "type": {
+              "vec": {
+                "defined": "AuthKey"
+              }
+            }
+          },
+          {
+            "name": "staking",
+            "docs": [
+              "The stake each gatekeeper must hold to issue passes on this network"
+            ],
+            "type": {
+              "defined": "NetworkStaking"
+            }
+          },
+          {
+            "name": "maxPassDataSize",
+            "docs": [
+              "The maximum size in bytes of each extended data section on a [`crate::state::Pass`]"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "signerBump",
+            "docs": [
+              "The bump for the signer, if the network is a PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "priceFeeds",
+            "docs": [
+              "The price feeds used to convert [`crate::state::ReferenceFees`] into each token"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NetworkPriceFeed"
+              }
+            }
+          },
+          {
+            "name": "feeBounds",
+            "docs": [
+              "The limits on the [`crate::state::GatekeeperFees`] gatekeepers can set for each token"
+            ],
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFeeBounds"
+              }
+            }
+          },
+          {
+            "name": "feeNoticePeriod",
+            "docs": [
+              "How far in seconds a change to the fees of a token that is already charged has to be",
+              "scheduled ahead, see [`crate::state::GatekeeperFees::effective_from`]"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "refundGracePeriod",
+            "docs": [
+              "How long after a pass is issued its issue fee is refunded on revoking it, `0` disables",
+              "refunds. Only indexed networks can sign for refunds out of their token accounts."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "passRequest",
+      "docs": [
+        "A subject's request for a [`crate::state::Pass`] from a gatekeeper, with the issue fee held",
+        "in escrow until the gatekeeper issues the pass or rejects the request"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "The subject asking for a pass, who paid the escrow"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "network",
+            "docs": [
+              "The network the pass is requested on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The gatekeeper asked to issue the pass"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the escrowed fee"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "escrow",
+            "docs": [
+              "The token account holding the escrowed fee, owned by this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "subjectTokenAccount",
+            "docs": [
+              "The token account the escrow came from, and the one refunds are sent to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The issue fee quoted when the request was made, which is what the subject pays"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "The time after which the subject can reclaim a pending request's escrow"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "state",
+            "docs": [
+              "The state of this request"
+            ],
+            "type": {
+              "defined": "PassRequestState"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pass",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct. Older accounts must be migrated to [`Self::VERSION`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "The initial authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "network",
+            "docs": [
+              "The network this pass belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "passNumber",
+            "docs": [
+              "The pass number"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "signerBump",
+            "docs": [
+              "The bump for the signer"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The gatekeeper that issued this pass"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "issueTime",
+            "docs": [
+              "The issue time of this pass, used for expiry"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "state",
+            "docs": [
+              "The state of this pass"
+            ],
+            "type": {
+              "defined": "PassState"
+            }
+          },
+          {
+            "name": "networkData",
+            "docs": [
+              "Additional data from the network"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "gatekeeperData",
+            "docs": [
+              "Additional data from the gatekeeper"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "extendedNetworkData",
+            "docs": [
+              "Variable-length data from the network, limited by",
+              "[`crate::state::GatekeeperNetwork::max_pass_data_size`]"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "extendedGatekeeperData",
+            "docs": [
+              "Variable-length data from the gatekeeper, limited by",
+              "[`crate::state::GatekeeperNetwork::max_pass_data_size`]"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "issueFee",
+            "docs": [
+              "The issue fee that can be refunded by the issuing gatekeeper within the network's",
+              "[`crate::state::GatekeeperNetwork::refund_grace_period`]"
+            ],
+            "type": {
+              "option": {
+                "defined": "PassIssueFee"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "redeemedVoucher",
+      "docs": [
+        "Marks a [`crate::voucher::PassVoucher`] nonce as used, so each voucher is only redeemed once.",
+        "It can be closed once the voucher has expired, as it can no longer be redeemed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pass",
+            "docs": [
+              "The pass the voucher was redeemed for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The account that paid the rent, and receives it when this account is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "The [`crate::voucher::PassVoucher::expiry`]"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "slashRecord",
+      "docs": [
+        "Evidence recorded when a [`crate::state::GatekeeperNetwork`] slashes a",
+        "[`crate::state::Gatekeeper`]'s stake"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The gatekeeper that was slashed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "network",
+            "docs": [
+              "The network that slashed the gatekeeper"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "The network key that authorised the slash"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of stake moved to the network"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "A network-defined code for the reason of the slash"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "evidenceHash",
+            "docs": [
+              "A hash of the off-chain evidence for the slash"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "affectedPassesHash",
+            "docs": [
+              "A hash of the list of passes affected by the gatekeeper's misbehaviour"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "The time of the slash"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sponsorship",
+      "docs": [
+        "A fee budget deposited by a dApp, from which pass fees are paid without the dApp signing"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "sponsor",
+            "docs": [
+              "The dApp that funds the sponsorship, and the only key that can withdraw from it"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "Distinguishes sponsorships created by the same sponsor"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the tokens held for fees"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "The token account holding the budget, owned by this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "networks",
+            "docs": [
+              "The networks whose passes may be sponsored, empty for any network"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "gatekeepers",
+            "docs": [
+              "The gatekeepers whose fees may be sponsored, empty for none"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "perSubjectCap",
+            "docs": [
+              "The most that may be spent on a single subject, `0` for no limit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "The time after which no more fees are paid, `0` if the sponsorship never expires"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sponsoredSubject",
+      "docs": [
+        "Tracks how much a [`Sponsorship`] has spent on a single subject"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "sponsorship",
+            "docs": [
+              "The sponsorship paying the subject's fees"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "The subject whose fees are paid"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "spent",
+            "docs": [
+              "The total fees paid for the subject so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "CreateNetworkData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authThreshold",
+            "docs": [
+              "The [`GatekeeperNetwork::auth_threshold`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "passExpireTime",
+            "docs": [
+              "The [`GatekeeperNetwork::pass_expire_time`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "The [`GatekeeperNetwork::fees`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "NetworkFeesPercentage"
+              }
+            }
+          },
+          {
+            "name": "priceFeeds",
+            "docs": [
+              "The [`GatekeeperNetwork::price_feeds`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "NetworkPriceFeed"
+              }
+            }
+          },
+          {
+            "name": "feeBounds",
+            "docs": [
+              "The [`GatekeeperNetwork::fee_bounds`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFeeBounds"
+              }
+            }
+          },
+          {
+            "name": "authKeys",
+            "docs": [
+              "The [`GatekeeperNetwork::auth_keys`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "AuthKey"
+              }
+            }
+          },
+          {
+            "name": "supportedTokens",
+            "docs": [
+              "The [`GatekeeperNetwork::supported_tokens`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "SupportedToken"
+              }
+            }
+          },
+          {
+            "name": "networkFeatures",
+            "docs": [
+              "The [`GatekeeperNetwork::network_features`]."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "staking",
+            "docs": [
+              "The [`GatekeeperNetwork::staking`]."
+            ],
+            "type": {
+              "defined": "NetworkStaking"
+            }
+          },
+          {
+            "name": "maxPassDataSize",
+            "docs": [
+              "The [`GatekeeperNetwork::max_pass_data_size`]."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "refundGracePeriod",
+            "docs": [
+              "The [`GatekeeperNetwork::refund_grace_period`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feeNoticePeriod",
+            "docs": [
+              "The [`GatekeeperNetwork::fee_notice_period`]."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateNetworkData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authThreshold",
+            "docs": [
+              "The [`GatekeeperNetwork::auth_threshold`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "passExpireTime",
+            "docs": [
+              "The [`GatekeeperNetwork::pass_expire_time`]."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
             "name": "fees",
             "docs": [
-              "The [`GatekeeperNetwork::fees`]."
+              "The [`GatekeeperNetwork::fees`]."
+            ],
+            "type": {
+              "defined": "UpdateFees"
+            }
+          },
+          {
+            "name": "priceFeeds",
+            "docs": [
+              "The [`GatekeeperNetwork::price_feeds`]."
+            ],
+            "type": {
+              "defined": "UpdatePriceFeeds"
+            }
+          },
+          {
+            "name": "feeBounds",
+            "docs": [
+              "The [`GatekeeperNetwork::fee_bounds`]. Existing gatekeeper fees outside them can not be charged."
+            ],
+            "type": {
+              "defined": "UpdateFeeBounds"
+            }
+          },
+          {
+            "name": "authKeys",
+            "docs": [
+              "The [`GatekeeperNetwork::auth_keys`]."
+            ],
+            "type": {
+              "defined": "UpdateKeys"
+            }
+          },
+          {
+            "name": "networkFeatures",
+            "docs": [
+              "The [`GatekeeperNetwork::network_features`]."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "supportedTokens",
+            "docs": [
+              "The [`GatekeeperNetwork::supported_tokens`]."
+            ],
+            "type": {
+              "defined": "UpdateSupportedTokens"
+            }
+          },
+          {
+            "name": "staking",
+            "docs": [
+              "The [`GatekeeperNetwork::staking`]."
+            ],
+            "type": {
+              "option": {
+                "defined": "NetworkStaking"
+              }
+            }
+          },
+          {
+            "name": "maxPassDataSize",
+            "docs": [
+              "The [`GatekeeperNetwork::max_pass_data_size`]."
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "refundGracePeriod",
+            "docs": [
+              "The [`GatekeeperNetwork::refund_grace_period`]."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "feeNoticePeriod",
+            "docs": [
+              "The [`GatekeeperNetwork::fee_notice_period`]."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateSupportedTokens",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "SupportedToken"
+              }
+            }
+          },
+          {
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGatekeepers",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFees",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "NetworkFeesPercentage"
+              }
+            }
+          },
+          {
+            "name": "remove",
+            "type": {
+              "vec": {
+                "defined": "FeeScheduleKey"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFeeBounds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFeeBounds"
+              }
+            }
+          },
+          {
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePriceFeeds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "NetworkPriceFeed"
+              }
+            }
+          },
+          {
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateKeys",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "AuthKey"
+              }
+            }
+          },
+          {
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateGatekeeperAddressesData",
+      "docs": [
+        "Data for [`CreateGatekeeperAddressesAccount`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "termsOfServiceHash",
+            "docs": [
+              "The [`GatekeeperAddresses::terms_of_service_hash`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "did",
+            "docs": [
+              "The [`GatekeeperAddresses::did`]."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "serviceEndpoints",
+            "docs": [
+              "The [`GatekeeperAddresses::service_endpoints`]."
+            ],
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "supportedJurisdictions",
+            "docs": [
+              "The [`GatekeeperAddresses::supported_jurisdictions`]."
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  2
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateGatekeeperData",
+      "docs": [
+        "Data for [`CreateGatekeeper`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenFees",
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFees"
+              }
+            }
+          },
+          {
+            "name": "referenceFees",
+            "docs": [
+              "The [`Gatekeeper::reference_fees`]."
+            ],
+            "type": {
+              "option": {
+                "defined": "ReferenceFees"
+              }
+            }
+          },
+          {
+            "name": "authThreshold",
+            "type": "u8"
+          },
+          {
+            "name": "authKeys",
+            "type": {
+              "vec": {
+                "defined": "GatekeeperAuthKey"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SlashGatekeeperData",
+      "docs": [
+        "Data for [`SlashGatekeeperAccount`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of stake to slash, taken from unbonding stake once the staked amount is exhausted"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "The [`SlashRecord::reason`]."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "evidenceHash",
+            "docs": [
+              "The [`SlashRecord::evidence_hash`]. Also seeds the [`SlashRecord`] address."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "affectedPassesHash",
+            "docs": [
+              "The [`SlashRecord::affected_passes_hash`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "halt",
+            "docs": [
+              "Whether to also set the gatekeeper to [`GatekeeperState::Halted`]"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGatekeeperAddressesData",
+      "docs": [
+        "Data for [`UpdateGatekeeperAddressesAccount`]. Fields that are `None` are left unchanged."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "termsOfServiceHash",
+            "docs": [
+              "The [`GatekeeperAddresses::terms_of_service_hash`]."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "did",
+            "docs": [
+              "The [`GatekeeperAddresses::did`]."
+            ],
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "serviceEndpoints",
+            "docs": [
+              "The [`GatekeeperAddresses::service_endpoints`]."
+            ],
+            "type": {
+              "option": {
+                "vec": "string"
+              }
+            }
+          },
+          {
+            "name": "supportedJurisdictions",
+            "docs": [
+              "The [`GatekeeperAddresses::supported_jurisdictions`]."
+            ],
+            "type": {
+              "option": {
+                "vec": {
+                  "array": [
+                    "u8",
+                    2
+                  ]
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGatekeeperData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenFees",
+            "docs": [
+              "The fees for this gatekeeper"
+            ],
+            "type": {
+              "defined": "UpdateGatekeeperFees"
+            }
+          },
+          {
+            "name": "referenceFees",
+            "docs": [
+              "The [`Gatekeeper::reference_fees`]. `Some(None)` removes the reference fees."
+            ],
+            "type": {
+              "option": {
+                "option": {
+                  "defined": "ReferenceFees"
+                }
+              }
+            }
+          },
+          {
+            "name": "authThreshold",
+            "docs": [
+              "The [`Gatekeeper::auth_threshold`]."
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "authKeys",
+            "docs": [
+              "The keys with permissions on this gatekeeper"
+            ],
+            "type": {
+              "defined": "UpdateGatekeeperKeys"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGatekeeperFees",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFees"
+              }
+            }
+          },
+          {
+            "name": "remove",
+            "type": {
+              "vec": {
+                "defined": "FeeScheduleKey"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGatekeeperKeys",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "GatekeeperAuthKey"
+              }
+            }
+          },
+          {
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateSponsorshipData",
+      "docs": [
+        "Data for [`CreateSponsorshipAccount`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "networks",
+            "docs": [
+              "The [`Sponsorship::networks`]."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "gatekeepers",
+            "docs": [
+              "The [`Sponsorship::gatekeepers`]."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "perSubjectCap",
+            "docs": [
+              "The [`Sponsorship::per_subject_cap`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "The [`Sponsorship::expires_at`]."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeScheduleKey",
+      "docs": [
+        "Identifies a single entry in a fee schedule"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "token",
+            "docs": [
+              "The token of the entry"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "effectiveFrom",
+            "docs": [
+              "The time the entry takes effect"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GatekeeperAuthKey",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "flags",
+            "docs": [
+              "The permissions this key has"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "key",
+            "docs": [
+              "The key"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GatekeeperStake",
+      "docs": [
+        "Tokens a [`Gatekeeper`] has staked on its [`GatekeeperNetwork`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the staked tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The staked amount that counts towards the network's minimum stake"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingAmount",
+            "docs": [
+              "Unstaked tokens that are still held in the vault until `unbonding_end_time`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingEndTime",
+            "docs": [
+              "The time after which the `unbonding_amount` can be withdrawn, restarted by every unstake"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GatekeeperFees",
+      "docs": [
+        "The fees a gatekeeper/network can take"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "token",
+            "docs": [
+              "The token for these fees. None value for this means native SOL price"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "issue",
+            "docs": [
+              "Fees taken at issuance of a new pass in token units or lamports for SOL."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refresh",
+            "docs": [
+              "Fees taken when a pass is refreshed in token units or lamports for SOL."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expire",
+            "docs": [
+              "The fee taken when a pass is expired in token units or lamports for SOL.",
+              "This should only be used where pass value comes from one-time use."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "verify",
+            "docs": [
+              "The fee taken when a pass is verified in token units or lamports for SOL.",
+              "This should only be used where pass value comes from proper use"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "effectiveFrom",
+            "docs": [
+              "The time these fees take effect. Entries for the same token with different times",
+              "form a schedule, and the latest entry that has taken effect is charged."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReferenceFees",
+      "docs": [
+        "Gatekeeper fees denominated in the reference currency of the network's price feeds,",
+        "with [`crate::oracle::REFERENCE_CURRENCY_DECIMALS`] decimals"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "issue",
+            "docs": [
+              "Fees taken at issuance of a new pass"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refresh",
+            "docs": [
+              "Fees taken when a pass is refreshed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expire",
+            "docs": [
+              "The fee taken when a pass is expired"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "verify",
+            "docs": [
+              "The fee taken when a pass is verified"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NetworkStaking",
+      "docs": [
+        "The staking requirements a [`GatekeeperNetwork`] places on its [`Gatekeeper`]s"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint gatekeepers stake in. Must be a supported token if `minimum_stake` is set."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "minimumStake",
+            "docs": [
+              "The amount a gatekeeper needs staked to issue passes. `0` means staking is not required."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingPeriod",
+            "docs": [
+              "The length of time in seconds between unstaking and being able to withdraw the stake"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GatekeeperFeeBounds",
+      "docs": [
+        "The lowest and highest fees a gatekeeper on a [`GatekeeperNetwork`] can charge in a token"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "token",
+            "docs": [
+              "The token the bounds apply to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "issueMin",
+            "docs": [
+              "The minimum [`GatekeeperFees::issue`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "issueMax",
+            "docs": [
+              "The maximum [`GatekeeperFees::issue`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refreshMin",
+            "docs": [
+              "The minimum [`GatekeeperFees::refresh`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refreshMax",
+            "docs": [
+              "The maximum [`GatekeeperFees::refresh`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expireMin",
+            "docs": [
+              "The minimum [`GatekeeperFees::expire`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expireMax",
+            "docs": [
+              "The maximum [`GatekeeperFees::expire`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "verifyMin",
+            "docs": [
+              "The minimum [`GatekeeperFees::verify`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "verifyMax",
+            "docs": [
+              "The maximum [`GatekeeperFees::verify`]"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NetworkPriceFeed",
+      "docs": [
+        "A Pyth price account approved by a [`GatekeeperNetwork`] for pricing a token"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "token",
+            "docs": [
+              "The token that is priced"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "priceAccount",
+            "docs": [
+              "The Pyth price account giving the price of one token in the reference currency"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "maxSlotAge",
+            "docs": [
+              "The maximum number of slots since the price was published"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxConfidenceRatio",
+            "docs": [
+              "The maximum confidence interval, in basis points of the price"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SupportedToken",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NetworkFeesPercentage",
+      "docs": [
+        "Fees that a [`GatekeeperNetwork`] can charge"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "token",
+            "docs": [
+              "The token for the fee, `None` means fee is invalid"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "issue",
+            "docs": [
+              "Percentage taken on issue. In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "refresh",
+            "docs": [
+              "Percentage taken on refresh. In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "expire",
+            "docs": [
+              "Percentage taken on expire. In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "verify",
+            "docs": [
+              "Percentage taken on verify. In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "issueReferrer",
+            "docs": [
+              "Percentage of the issue fee paid to a referrer out of the gatekeeper's share.",
+              "In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "verifyReferrer",
+            "docs": [
+              "Percentage of the verify fee paid to a referrer out of the gatekeeper's share.",
+              "In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "effectiveFrom",
+            "docs": [
+              "The time these fees take effect. Entries for the same token with different times",
+              "form a schedule, and the latest entry that has taken effect is charged."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PassIssueFee",
+      "docs": [
+        "The shares of an issue fee paid for a [`Pass`], kept so they can be refunded"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "funderTokenAccount",
+            "docs": [
+              "The token account the fee was paid from, and the one it is refunded to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint the fee was paid in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "networkFee",
+            "docs": [
+              "The network's share of the fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gatekeeperFee",
+            "docs": [
+              "The gatekeeper's share of the fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paidAt",
+            "docs": [
+              "The time the fee was paid"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuthKey",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "flags",
+            "docs": [
+              "The permissions this key has"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "key",
+            "docs": [
+              "The key"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PassVoucher",
+      "docs": [
+        "The message a gatekeeper signs to let the subject redeem a pass without the gatekeeper online"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subject",
+            "docs": [
+              "The subject the pass is issued to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "network",
+            "docs": [
+              "The network the pass is issued on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The gatekeeper the pass is issued by, whose key signs the voucher"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "passNumber",
+            "docs": [
+              "The number of the issued pass"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "The time after which the voucher can no longer be redeemed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Stored in [`crate::state::Pass::gatekeeper_data`]"
             ],
             "type": {
-              "vec": {
-                "defined": "NetworkFeesPercentage"
-              }
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "authKeys",
-            "docs": [
-              "The [`GatekeeperNetwork::auth_keys`]."
-            ],
-            "type": {
-              "vec": {
-                "defined": "AuthKey"
-              }
-            }
+            "name": "nonce",
+            "docs": [
+              "Unique per gatekeeper, so each voucher can only be redeemed once"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GatekeeperErrors",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NoAuthKeys"
+          },
+          {
+            "name": "InsufficientAuthKeys"
+          },
+          {
+            "name": "InsufficientAccessAuthKeys"
+          },
+          {
+            "name": "AuthKeyNotFound"
+          },
+          {
+            "name": "InvalidKey"
+          },
+          {
+            "name": "InvalidGatekeeper"
+          },
+          {
+            "name": "FeesNotProvided"
+          },
+          {
+            "name": "InvalidState"
+          },
+          {
+            "name": "InvalidDid"
+          },
+          {
+            "name": "InsufficientStake"
+          },
+          {
+            "name": "InsufficientStakeToUnstake"
+          },
+          {
+            "name": "UnbondingPeriodNotEnded"
+          },
+          {
+            "name": "StakeMintMismatch"
+          },
+          {
+            "name": "InsufficientStakeToSlash"
+          },
+          {
+            "name": "FeesOutOfBounds"
+          },
+          {
+            "name": "InvalidGatekeeperAddresses"
+          },
+          {
+            "name": "StakeOverflow"
+          },
+          {
+            "name": "InvalidSlashAmount"
+          },
+          {
+            "name": "InsufficientAccessPassData"
+          },
+          {
+            "name": "FeeNoticeTooShort"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PassErrors",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "InvalidStateChange"
+          },
+          {
+            "name": "PassNotActive"
           },
           {
-            "name": "supportedTokens",
-            "docs": [
-              "The [`GatekeeperNetwork::supported_tokens`]."
-            ],
-            "type": {
-              "vec": {
-                "defined": "SupportedToken"
-              }
-            }
+            "name": "InvalidGatekeeper"
           },
           {
-            "name": "networkFeatures",
-            "docs": [
-              "The [`GatekeeperNetwork::network_features`]."
-            ],
-            "type": "u32"
+            "name": "InvalidNetwork"
+          },
+          {
+            "name": "InvalidPass"
+          },
+          {
+            "name": "PassDataTooLarge"
+          },
+          {
+            "name": "NoRefundableFee"
+          },
+          {
+            "name": "RefundPeriodEnded"
+          },
+          {
+            "name": "InvalidRefundAccount"
+          },
+          {
+            "name": "InvalidPassRequest"
+          },
+          {
+            "name": "PassRequestNotPending"
+          },
+          {
+            "name": "PassRequestNotReclaimable"
+          },
+          {
+            "name": "InvalidDeadline"
+          },
+          {
+            "name": "InvalidVoucherSignature"
+          },
+          {
+            "name": "InvalidVoucherSigner"
+          },
+          {
+            "name": "VoucherExpired"
+          },
+          {
+            "name": "RefundRequired"
+          },
+          {
+            "name": "PassRequestExpired"
+          },
+          {
+            "name": "ConflictingFeePayers"
+          },
+          {
+            "name": "VoucherNotExpired"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SponsorshipErrors",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SponsorshipExpired"
+          },
+          {
+            "name": "NetworkNotSponsored"
+          },
+          {
+            "name": "GatekeeperNotSponsored"
+          },
+          {
+            "name": "SubjectCapExceeded"
+          },
+          {
+            "name": "InvalidSponsorship"
+          },
+          {
+            "name": "NoFeePayer"
+          },
+          {
+            "name": "UnauthorizedVerifier"
+          },
+          {
+            "name": "InvalidAmount"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeCreditErrors",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "InvalidFeeCredit"
+          },
+          {
+            "name": "InsufficientCredit"
+          },
+          {
+            "name": "ReferrerNotSupported"
+          },
+          {
+            "name": "InvalidAmount"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MigrationErrors",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "UnsupportedAccount"
+          },
+          {
+            "name": "AlreadyMigrated"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GatekeeperState",
+      "docs": [
+        "The state of a [`Gatekeeper`]"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Frozen"
+          },
+          {
+            "name": "Halted"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PassRequestState",
+      "docs": [
+        "The state of a [`PassRequest`]."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Fulfilled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PassState",
+      "docs": [
+        "The state of a [`Pass`]."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Frozen"
+          },
+          {
+            "name": "Revoked"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PassDataSection",
+      "docs": [
+        "The owner of an extended data section on a [`Pass`]."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Network"
+          },
+          {
+            "name": "Gatekeeper"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeOperation",
+      "docs": [
+        "The pass operations a fee can be charged for"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Issue"
+          },
+          {
+            "name": "Refresh"
+          },
+          {
+            "name": "Expire"
+          },
+          {
+            "name": "Verify"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeePayer",
+      "docs": [
+        "The source of the tokens paying a pass fee"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Funder",
+            "fields": [
+              {
+                "name": "funder",
+                "type": {
+                  "defined": "Signer<'a>"
+                }
+              },
+              {
+                "name": "token_account",
+                "type": {
+                  "defined": "InterfaceAccount<'a,TokenAccount>"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Sponsorship",
+            "fields": [
+              {
+                "name": "sponsorship",
+                "type": {
+                  "defined": "Account<'a,Sponsorship>"
+                }
+              },
+              {
+                "name": "vault",
+                "type": {
+                  "defined": "InterfaceAccount<'a,TokenAccount>"
+                }
+              }
+            ]
+          },
+          {
+            "name": "PassRequest",
+            "fields": [
+              {
+                "name": "pass_request",
+                "type": {
+                  "defined": "Account<'a,PassRequest>"
+                }
+              },
+              {
+                "name": "escrow",
+                "type": {
+                  "defined": "InterfaceAccount<'a,TokenAccount>"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "NoAuthKeys",
+      "msg": "No auth keys provided"
+    },
+    {
+      "code": 6001,
+      "name": "InsufficientAuthKeys",
+      "msg": "Not enough auth keys provided"
+    },
+    {
+      "code": 6002,
+      "name": "InsufficientAccessAuthKeys",
+      "msg": "Insufficient access to update auth keys"
+    },
+    {
+      "code": 6003,
+      "name": "InsufficientAccessExpiry",
+      "msg": "Insufficient access to set expiry time"
+    },
+    {
+      "code": 6004,
+      "name": "InsufficientAccessFeatures",
+      "msg": "Insufficient access to set features"
+    },
+    {
+      "code": 6005,
+      "name": "InsufficientAccessTokens",
+      "msg": "Insufficient access to set supported tokens"
+    },
+    {
+      "code": 6006,
+      "name": "InsufficientAccessFees",
+      "msg": "Insufficient access to set fees"
+    },
+    {
+      "code": 6007,
+      "name": "InsufficientAccessCreateGatekeeper",
+      "msg": "Insufficient access to create gatekeeper"
+    },
+    {
+      "code": 6008,
+      "name": "AuthKeyNotFound",
+      "msg": "Auth key not found"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidKey",
+      "msg": "Invalid key provided"
+    },
+    {
+      "code": 6010,
+      "name": "AccountInUse",
+      "msg": "The network account is in use"
+    },
+    {
+      "code": 6011,
+      "name": "FeesNotProvided",
+      "msg": "Network Fee was not provided"
+    },
+    {
+      "code": 6012,
+      "name": "NetworkFeeOutOfBounds",
+      "msg": "Network Fee more than 100%"
+    },
+    {
+      "code": 6013,
+      "name": "TokenNotSupported",
+      "msg": "Token not supported"
+    },
+    {
+      "code": 6014,
+      "name": "UnsupportedNetworkFeature",
+      "msg": "A network feature is not enabled for this instruction"
+    },
+    {
+      "code": 6015,
+      "name": "InsufficientAccessStaking",
+      "msg": "Insufficient access to set staking"
+    },
+    {
+      "code": 6016,
+      "name": "InsufficientAccessSlash",
+      "msg": "Insufficient access to slash gatekeepers"
+    },
+    {
+      "code": 6017,
+      "name": "InsufficientAccessHaltGatekeeper",
+      "msg": "Insufficient access to halt gatekeepers"
+    },
+    {
+      "code": 6018,
+      "name": "InsufficientAccessPassDataSize",
+      "msg": "Insufficient access to set the maximum pass data size"
+    },
+    {
+      "code": 6019,
+      "name": "InsufficientAccessPassData",
+      "msg": "Insufficient access to set pass data"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidNetworkIndex",
+      "msg": "The network index is not the next index in the registry"
+    },
+    {
+      "code": 6021,
+      "name": "PriceFeedNotApproved",
+      "msg": "No price feed is approved for this token"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidPriceFeed",
+      "msg": "The price account is not the approved price feed"
+    },
+    {
+      "code": 6023,
+      "name": "PriceNotTrading",
+      "msg": "The price feed is not trading"
+    },
+    {
+      "code": 6024,
+      "name": "StalePrice",
+      "msg": "The price feed has not been updated recently enough"
+    },
+    {
+      "code": 6025,
+      "name": "PriceConfidenceTooWide",
+      "msg": "The price feed confidence interval is too wide"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidPrice",
+      "msg": "The price feed returned an unusable price"
+    },
+    {
+      "code": 6027,
+      "name": "RefundsRequireIndexedNetwork",
+      "msg": "Fee refunds can only be enabled on indexed networks"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidReferrer",
+      "msg": "The referrer can not be the funder or the subject of the pass"
+    },
+    {
+      "code": 6029,
+      "name": "FeeNoticeTooShort",
+      "msg": "Fee changes must be scheduled at least the network's fee notice period ahead"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidFeeNoticePeriod",
+      "msg": "The fee notice period can not be negative"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidFeeBounds",
+      "msg": "A fee bound minimum is above its maximum"
+    }
+  ]
+};
+
+export const IDL: SolanaAnchorGateway = {
+  "version": "2.0.5",
+  "name": "solana_anchor_gateway",
+  "instructions": [
+    {
+      "name": "createNetwork",
+      "accounts": [
+        {
+          "name": "network",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "CreateNetworkData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createIndexedNetwork",
+      "accounts": [
+        {
+          "name": "registry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "networkIndex",
+          "type": "u16"
+        },
+        {
+          "name": "data",
+          "type": {
+            "defined": "CreateNetworkData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateNetwork",
+      "accounts": [
+        {
+          "name": "network",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "UpdateNetworkData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeNetwork",
+      "accounts": [
+        {
+          "name": "network",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createGatekeeper",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "network",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "CreateGatekeeperData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGatekeeper",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "UpdateGatekeeperData"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "UpdateNetworkData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authThreshold",
-            "docs": [
-              "The [`GatekeeperNetwork::auth_threshold`]."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "passExpireTime",
-            "docs": [
-              "The [`GatekeeperNetwork::pass_expire_time`]."
-            ],
-            "type": {
-              "option": "i64"
-            }
-          },
-          {
-            "name": "fees",
-            "docs": [
-              "The [`GatekeeperNetwork::fees`]."
-            ],
-            "type": {
-              "defined": "UpdateFees"
-            }
-          },
-          {
-            "name": "authKeys",
-            "docs": [
-              "The [`GatekeeperNetwork::auth_keys`]."
-            ],
-            "type": {
-              "defined": "UpdateKeys"
-            }
-          },
-          {
-            "name": "networkFeatures",
-            "docs": [
-              "The [`GatekeeperNetwork::network_features`]."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "supportedTokens",
-            "docs": [
-              "The [`GatekeeperNetwork::supported_tokens`]."
-            ],
-            "type": {
-              "defined": "UpdateSupportedTokens"
-            }
+      "name": "closeGatekeeper",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "addresses",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "network",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setGatekeeperState",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "state",
+          "type": {
+            "defined": "GatekeeperState"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "UpdateSupportedTokens",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "add",
-            "type": {
-              "vec": {
-                "defined": "SupportedToken"
-              }
-            }
-          },
-          {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
+      "name": "gatekeeperWithdraw",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createGatekeeperAddresses",
+      "accounts": [
+        {
+          "name": "addresses",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "CreateGatekeeperAddressesData"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "UpdateGatekeepers",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "add",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
+      "name": "updateGatekeeperAddresses",
+      "accounts": [
+        {
+          "name": "addresses",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "UpdateGatekeeperAddressesData"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "UpdateFees",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "add",
-            "type": {
-              "vec": {
-                "defined": "NetworkFeesPercentage"
-              }
-            }
-          },
-          {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
-          }
-        ]
-      }
+      "name": "gatekeeperStake",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "UpdateKeys",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "add",
-            "type": {
-              "vec": {
-                "defined": "AuthKey"
-              }
-            }
-          },
-          {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
-          }
-        ]
-      }
+      "name": "gatekeeperUnstake",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "CreateGatekeeperData",
-      "docs": [
-        "Data for [`CreateGatekeeper`]"
+      "name": "gatekeeperWithdrawStake",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tokenFees",
-            "type": {
-              "vec": {
-                "defined": "GatekeeperFees"
-              }
-            }
-          },
-          {
-            "name": "authThreshold",
-            "type": "u8"
-          },
-          {
-            "name": "authKeys",
-            "type": {
-              "vec": {
-                "defined": "GatekeeperAuthKey"
-              }
-            }
-          }
-        ]
-      }
+      "args": []
     },
     {
-      "name": "UpdateGatekeeperData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tokenFees",
-            "docs": [
-              "The fees for this gatekeeper"
-            ],
-            "type": {
-              "defined": "UpdateGatekeeperFees"
-            }
-          },
-          {
-            "name": "authThreshold",
-            "docs": [
-              "The [`Gatekeeper::auth_threshold`]."
-            ],
-            "type": {
-              "option": "u8"
-            }
-          },
-          {
-            "name": "authKeys",
-            "docs": [
-              "The keys with permissions on this gatekeeper"
-            ],
-            "type": {
-              "defined": "UpdateGatekeeperKeys"
-            }
+      "name": "slashGatekeeper",
+      "accounts": [
+        {
+          "name": "gatekeeper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "slashRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "SlashGatekeeperData"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "UpdateGatekeeperFees",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "add",
-            "type": {
-              "vec": {
-                "defined": "GatekeeperFees"
-              }
-            }
-          },
-          {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
-          }
-        ]
-      }
+      "name": "issuePass",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees in place of the funder"
+          ]
+        },
+        {
+          "name": "sponsorshipVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "passRequest",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from a subject's escrow, in place of the funder"
+          ]
+        },
+        {
+          "name": "passRequestEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from prepaid credit, in place of the funder's token account"
+          ]
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "subject",
+          "type": "publicKey"
+        },
+        {
+          "name": "passNumber",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "UpdateGatekeeperKeys",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "add",
-            "type": {
-              "vec": {
-                "defined": "GatekeeperAuthKey"
-              }
-            }
-          },
-          {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
+      "name": "setPassState",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed to revoke a pass with an issue fee, to check the refund grace period"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "state",
+          "type": {
+            "defined": "PassState"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "GatekeeperAuthKey",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "flags",
-            "docs": [
-              "The permissions this key has"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "key",
-            "docs": [
-              "The key"
-            ],
-            "type": "publicKey"
-          }
-        ]
-      }
+      "name": "refundPassFee",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "GatekeeperFees",
-      "docs": [
-        "The fees a gatekeeper/network can take"
+      "name": "rejectPassRequest",
+      "accounts": [
+        {
+          "name": "passRequest",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "subject",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subjectTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "token",
-            "docs": [
-              "The token for these fees. None value for this means native SOL price"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "issue",
-            "docs": [
-              "Fees taken at issuance of a new pass in token units or lamports for SOL."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "refresh",
-            "docs": [
-              "Fees taken when a pass is refreshed in token units or lamports for SOL."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "expire",
-            "docs": [
-              "The fee taken when a pass is expired in token units or lamports for SOL.",
-              "This should only be used where pass value comes from one-time use."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "verify",
-            "docs": [
-              "The fee taken when a pass is verified in token units or lamports for SOL.",
-              "This should only be used where pass value comes from proper use"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
+      "args": []
     },
     {
-      "name": "SupportedToken",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "key",
-            "type": "publicKey"
-          }
-        ]
-      }
+      "name": "refreshPass",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees in place of the funder"
+          ]
+        },
+        {
+          "name": "sponsorshipVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from prepaid credit, in place of the funder's token account"
+          ]
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "NetworkFeesPercentage",
-      "docs": [
-        "Fees that a [`GatekeeperNetwork`] can charge"
+      "name": "changePassGatekeeper",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "oldGatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newGatekeeper",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "token",
-            "docs": [
-              "The token for the fee, `None` means fee is invalid"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "issue",
-            "docs": [
-              "Percentage taken on issue. In Hundredths of a percent (0.01% or 0.0001)."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "refresh",
-            "docs": [
-              "Percentage taken on refresh. In Hundredths of a percent (0.01% or 0.0001)."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "expire",
-            "docs": [
-              "Percentage taken on expire. In Hundredths of a percent (0.01% or 0.0001)."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "verify",
-            "docs": [
-              "Percentage taken on verify. In Hundredths of a percent (0.01% or 0.0001)."
-            ],
-            "type": "u16"
+      "args": []
+    },
+    {
+      "name": "setPassData",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "gatekeeperData",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
-        ]
-      }
-    },
-    {
-      "name": "AuthKey",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "flags",
-            "docs": [
-              "The permissions this key has"
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "key",
-            "docs": [
-              "The key"
-            ],
-            "type": "publicKey"
+        },
+        {
+          "name": "networkData",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "GatekeeperErrors",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "NoAuthKeys"
-          },
-          {
-            "name": "InsufficientAuthKeys"
-          },
-          {
-            "name": "InsufficientAccessAuthKeys"
-          },
-          {
-            "name": "AuthKeyNotFound"
-          },
-          {
-            "name": "InvalidKey"
-          },
-          {
-            "name": "InvalidGatekeeper"
-          },
-          {
-            "name": "FeesNotProvided"
-          },
-          {
-            "name": "InvalidState"
+      "name": "setPassExtendedData",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "section",
+          "type": {
+            "defined": "PassDataSection"
           }
-        ]
-      }
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        }
+      ]
     },
     {
-      "name": "PassErrors",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "InvalidStateChange"
-          },
-          {
-            "name": "PassNotActive"
-          },
-          {
-            "name": "InvalidGatekeeper"
-          },
-          {
-            "name": "InvalidNetwork"
-          },
-          {
-            "name": "InvalidPass"
-          }
-        ]
-      }
+      "name": "expirePass",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from prepaid credit, in place of the funder's token account"
+          ]
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "GatekeeperState",
-      "docs": [
-        "The state of a [`Gatekeeper`]"
+      "name": "verifyPass",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees in place of the funder, if the pass subject or the sponsor verifies the pass"
+          ]
+        },
+        {
+          "name": "sponsorshipVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees from prepaid credit, in place of the funder's token account"
+          ]
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
       ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Active"
-          },
-          {
-            "name": "Frozen"
-          },
-          {
-            "name": "Halted"
-          }
-        ]
-      }
+      "args": []
     },
     {
-      "name": "PassState",
-      "docs": [
-        "The state of a [`Pass`]."
+      "name": "createSponsorship",
+      "accounts": [
+        {
+          "name": "sponsorship",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Active"
-          },
-          {
-            "name": "Frozen"
-          },
-          {
-            "name": "Revoked"
+      "args": [
+        {
+          "name": "index",
+          "type": "u16"
+        },
+        {
+          "name": "data",
+          "type": {
+            "defined": "CreateSponsorshipData"
           }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "NoAuthKeys",
-      "msg": "No auth keys provided"
-    },
-    {
-      "code": 6001,
-      "name": "InsufficientAuthKeys",
-      "msg": "Not enough auth keys provided"
-    },
-    {
-      "code": 6002,
-      "name": "InsufficientAccessAuthKeys",
-      "msg": "Insufficient access to update auth keys"
-    },
-    {
-      "code": 6003,
-      "name": "InsufficientAccessExpiry",
-      "msg": "Insufficient access to set expiry time"
-    },
-    {
-      "code": 6004,
-      "name": "InsufficientAccessFeatures",
-      "msg": "Insufficient access to set features"
-    },
-    {
-      "code": 6005,
-      "name": "InsufficientAccessTokens",
-      "msg": "Insufficient access to set supported tokens"
-    },
-    {
-      "code": 6006,
-      "name": "InsufficientAccessFees",
-      "msg": "Insufficient access to set fees"
-    },
-    {
-      "code": 6007,
-      "name": "InsufficientAccessCreateGatekeeper",
-      "msg": "Insufficient access to create gatekeeper"
-    },
-    {
-      "code": 6008,
-      "name": "AuthKeyNotFound",
-      "msg": "Auth key not found"
-    },
-    {
-      "code": 6009,
-      "name": "InvalidKey",
-      "msg": "Invalid key provided"
-    },
-    {
-      "code": 6010,
-      "name": "AccountInUse",
-      "msg": "The network account is in use"
+        }
+      ]
     },
     {
-      "code": 6011,
-      "name": "FeesNotProvided",
-      "msg": "Network Fee was not provided"
+      "name": "fundSponsorship",
+      "accounts": [
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 6012,
-      "name": "NetworkFeeOutOfBounds",
-      "msg": "Network Fee more than 100%"
+      "name": "withdrawSponsorship",
+      "accounts": [
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiverTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 6013,
-      "name": "TokenNotSupported",
-      "msg": "Token not supported"
+      "name": "createSponsoredSubject",
+      "accounts": [
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "subject",
+          "type": "publicKey"
+        }
+      ]
     },
     {
-      "code": 6014,
-      "name": "UnsupportedNetworkFeature",
-      "msg": "A network feature is not enabled for this instruction"
-    }
-  ]
-};
-
-export const IDL: SolanaAnchorGateway = {
-  "version": "2.0.5",
-  "name": "solana_anchor_gateway",
-  "instructions": [
-    {
-      "name": "createNetwork",
+      "name": "createFeeCredit",
       "accounts": [
         {
-          "name": "network",
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "tokenProgram",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "systemProgram",
@@ -1575,100 +6020,173 @@ export const IDL: SolanaAnchorGateway = {
           "isSigner": false
         }
       ],
+      "args": []
+    },
+    {
+      "name": "topUpFeeCredit",
+      "accounts": [
+        {
+          "name": "feeCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
       "args": [
         {
-          "name": "data",
-          "type": {
-            "defined": "CreateNetworkData"
-          }
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "updateNetwork",
+      "name": "withdrawFeeCredit",
       "accounts": [
         {
-          "name": "network",
+          "name": "feeCredit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
-          "isMut": true,
+          "name": "funder",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "receiverTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "data",
-          "type": {
-            "defined": "UpdateNetworkData"
-          }
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "closeNetwork",
+      "name": "settleFeeCredit",
       "accounts": [
         {
-          "name": "network",
+          "name": "feeCredit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destination",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "mint",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "networkTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "createGatekeeper",
+      "name": "createPassRequest",
       "accounts": [
         {
-          "name": "gatekeeper",
+          "name": "passRequest",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "subject",
-          "isMut": false,
-          "isSigner": false
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "payer",
+          "name": "subjectTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "network",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "stakingAccount",
-          "isMut": true,
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1679,518 +6197,1094 @@ export const IDL: SolanaAnchorGateway = {
       ],
       "args": [
         {
-          "name": "data",
-          "type": {
-            "defined": "CreateGatekeeperData"
-          }
+          "name": "deadline",
+          "type": "i64"
         }
       ]
     },
     {
-      "name": "updateGatekeeper",
+      "name": "reclaimPassRequest",
       "accounts": [
         {
-          "name": "gatekeeper",
+          "name": "passRequest",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "subject",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subjectTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeemPassVoucher",
+      "accounts": [
+        {
+          "name": "pass",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "redeemedVoucher",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "network",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gatekeeper",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "stakingAccount",
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "networkTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "gatekeeperTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsorship",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Pays the fees in place of the funder"
+          ]
+        },
+        {
+          "name": "sponsorshipVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsoredSubject",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "priceAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "instructions",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "data",
+          "name": "voucher",
           "type": {
-            "defined": "UpdateGatekeeperData"
+            "defined": "PassVoucher"
           }
         }
       ]
     },
     {
-      "name": "closeGatekeeper",
+      "name": "closeRedeemedVoucher",
       "accounts": [
         {
-          "name": "gatekeeper",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "network",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "destination",
+          "name": "redeemedVoucher",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "payer",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
           "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "setGatekeeperState",
+      "name": "migrateAccount",
       "accounts": [
         {
-          "name": "gatekeeper",
+          "name": "account",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "network",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "state",
-          "type": {
-            "defined": "GatekeeperState"
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "feeCredit",
+      "docs": [
+        "Tokens prepaid by a funder for a single gatekeeper's fees. Operations debit the balance",
+        "without a token transfer, and the gatekeeper's and network's shares are paid out in bulk",
+        "by `settle_fee_credit`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "funder",
+            "docs": [
+              "The funder that prepaid the credit, and the only key that can spend or withdraw it"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The gatekeeper whose fees the credit pays"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "network",
+            "docs": [
+              "The network of the gatekeeper"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the prepaid tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "The token account holding the prepaid tokens, owned by this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "balance",
+            "docs": [
+              "The tokens still available to the funder"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gatekeeperOwed",
+            "docs": [
+              "Fees owed to the gatekeeper that have not yet been settled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "networkOwed",
+            "docs": [
+              "Fees owed to the network that have not yet been settled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gatekeeperAddresses",
+      "docs": [
+        "Discoverability information for a [`crate::state::Gatekeeper`], linked from",
+        "[`crate::state::Gatekeeper::addresses`].",
+        "",
+        "The fixed-size fields come first so clients can filter these accounts by gatekeeper network."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The [`crate::state::Gatekeeper`] these addresses belong to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeperNetwork",
+            "docs": [
+              "The [`crate::state::GatekeeperNetwork`] the gatekeeper is on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "termsOfServiceHash",
+            "docs": [
+              "A hash of the terms of service a subject agrees to when requesting a pass"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "did",
+            "docs": [
+              "The DID of the gatekeeper, empty if not set"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "serviceEndpoints",
+            "docs": [
+              "Endpoints (e.g. URLs) where the gatekeeper's services can be reached"
+            ],
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "supportedJurisdictions",
+            "docs": [
+              "The jurisdictions the gatekeeper can issue passes in, as ISO 3166-1 alpha-2 codes"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  2
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "gatekeeper",
+      "docs": [
+        "A gatekeeper on a [`GatekeeperNetwork`] that can issue passes"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct. Older accounts must be migrated to [`Self::VERSION`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "the authority for this gatekeeper"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeperBump",
+            "docs": [
+              "The bump for the signer of this gatekeeper"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "gatekeeperNetwork",
+            "docs": [
+              "The [`GatekeeperNetwork`] this gatekeeper is on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingAccount",
+            "docs": [
+              "The vault holding this gatekeeper's stake, default until tokens are first staked"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeperState",
+            "docs": [
+              "The state of this gatekeeper"
+            ],
+            "type": {
+              "defined": "GatekeeperState"
+            }
+          },
+          {
+            "name": "tokenFees",
+            "docs": [
+              "The fees for this gatekeeper"
+            ],
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFees"
+              }
+            }
+          },
+          {
+            "name": "authThreshold",
+            "docs": [
+              "The number of keys needed to change the `auth_keys`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "authKeys",
+            "docs": [
+              "The keys with permissions on this gatekeeper"
+            ],
+            "type": {
+              "vec": {
+                "defined": "GatekeeperAuthKey"
+              }
+            }
+          },
+          {
+            "name": "addresses",
+            "docs": [
+              "The [`GatekeeperAddresses`] account used for discoverability, default if not set"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "The tokens this gatekeeper has staked in the `staking_account`"
+            ],
+            "type": {
+              "defined": "GatekeeperStake"
+            }
+          },
+          {
+            "name": "referenceFees",
+            "docs": [
+              "Fees in the network's reference currency, charged for tokens without an entry in",
+              "`token_fees` using the network's price feed for the token"
+            ],
+            "type": {
+              "option": {
+                "defined": "ReferenceFees"
+              }
+            }
           }
-        }
-      ]
-    },
-    {
-      "name": "gatekeeperWithdraw",
-      "accounts": [
-        {
-          "name": "gatekeeper",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "splTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "receiverTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeperTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "issuePass",
-      "accounts": [
-        {
-          "name": "pass",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "network",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeper",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "funder",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "splTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "funderTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "networkTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeperTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        }
+      "name": "networkRegistry",
+      "docs": [
+        "The global counter that keeps [`crate::state::GatekeeperNetwork::network_index`] unique"
       ],
-      "args": [
-        {
-          "name": "subject",
-          "type": "publicKey"
-        },
-        {
-          "name": "passNumber",
-          "type": "u16"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "networkCount",
+            "docs": [
+              "The number of networks created from the registry, also the last index handed out"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "name": "setPassState",
-      "accounts": [
-        {
-          "name": "pass",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "gatekeeper",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "gatekeeperNetwork",
+      "docs": [
+        "A gatekeeper network which manages many [`Gatekeeper`]s."
       ],
-      "args": [
-        {
-          "name": "state",
-          "type": {
-            "defined": "PassState"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct. Older accounts must be migrated to [`Self::VERSION`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "The initial authority key"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "networkIndex",
+            "docs": [
+              "The index the network address is derived from, `0` if the network is not a PDA"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "passExpireTime",
+            "docs": [
+              "The length of time a pass lasts in seconds. `0` means does not expire."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "networkFeatures",
+            "docs": [
+              "Features on the network, index relates to which feature it is. There are 32 bytes of data available for each feature."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "The fees for this network"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NetworkFeesPercentage"
+              }
+            }
+          },
+          {
+            "name": "supportedTokens",
+            "type": {
+              "vec": {
+                "defined": "SupportedToken"
+              }
+            }
+          },
+          {
+            "name": "gatekeepers",
+            "docs": [
+              "A set of all active gatekeepers in the network"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "authThreshold",
+            "docs": [
+              "The number of auth keys needed to change the `auth_keys`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "authKeys",
+            "docs": [
+              "Keys with permissions on the network"
+            ],
+            "type": {
+              "vec": {
+                "defined": "AuthKey"
+              }
+            }
+          },
+          {
+            "name": "staking",
+            "docs": [
+              "The stake each gatekeeper must hold to issue passes on this network"
+            ],
+            "type": {
+              "defined": "NetworkStaking"
+            }
+          },
+          {
+            "name": "maxPassDataSize",
+            "docs": [
+              "The maximum size in bytes of each extended data section on a [`crate::state::Pass`]"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "signerBump",
+            "docs": [
+              "The bump for the signer, if the network is a PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "priceFeeds",
+            "docs": [
+              "The price feeds used to convert [`crate::state::ReferenceFees`] into each token"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NetworkPriceFeed"
+              }
+            }
+          },
+          {
+            "name": "feeBounds",
+            "docs": [
+              "The limits on the [`crate::state::GatekeeperFees`] gatekeepers can set for each token"
+            ],
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFeeBounds"
+              }
+            }
+          },
+          {
+            "name": "feeNoticePeriod",
+            "docs": [
+              "How far in seconds a change to the fees of a token that is already charged has to be",
+              "scheduled ahead, see [`crate::state::GatekeeperFees::effective_from`]"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "refundGracePeriod",
+            "docs": [
+              "How long after a pass is issued its issue fee is refunded on revoking it, `0` disables",
+              "refunds. Only indexed networks can sign for refunds out of their token accounts."
+            ],
+            "type": "i64"
           }
-        }
-      ]
-    },
-    {
-      "name": "refreshPass",
-      "accounts": [
-        {
-          "name": "pass",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "funder",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "network",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeper",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "splTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "funderTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "networkTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeperTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": []
+        ]
+      }
     },
     {
-      "name": "changePassGatekeeper",
-      "accounts": [
-        {
-          "name": "pass",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "network",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "oldGatekeeper",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newGatekeeper",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "passRequest",
+      "docs": [
+        "A subject's request for a [`crate::state::Pass`] from a gatekeeper, with the issue fee held",
+        "in escrow until the gatekeeper issues the pass or rejects the request"
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "The subject asking for a pass, who paid the escrow"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "network",
+            "docs": [
+              "The network the pass is requested on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The gatekeeper asked to issue the pass"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the escrowed fee"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "escrow",
+            "docs": [
+              "The token account holding the escrowed fee, owned by this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "subjectTokenAccount",
+            "docs": [
+              "The token account the escrow came from, and the one refunds are sent to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The issue fee quoted when the request was made, which is what the subject pays"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "The time after which the subject can reclaim a pending request's escrow"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "state",
+            "docs": [
+              "The state of this request"
+            ],
+            "type": {
+              "defined": "PassRequestState"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "name": "setPassData",
-      "accounts": [
-        {
-          "name": "pass",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "gatekeeper",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "gatekeeperData",
-          "type": {
-            "option": {
+      "name": "pass",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct. Older accounts must be migrated to [`Self::VERSION`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "The initial authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "network",
+            "docs": [
+              "The network this pass belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "passNumber",
+            "docs": [
+              "The pass number"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "signerBump",
+            "docs": [
+              "The bump for the signer"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The gatekeeper that issued this pass"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "issueTime",
+            "docs": [
+              "The issue time of this pass, used for expiry"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "state",
+            "docs": [
+              "The state of this pass"
+            ],
+            "type": {
+              "defined": "PassState"
+            }
+          },
+          {
+            "name": "networkData",
+            "docs": [
+              "Additional data from the network"
+            ],
+            "type": {
               "array": [
                 "u8",
                 32
               ]
             }
-          }
-        },
-        {
-          "name": "networkData",
-          "type": {
-            "option": {
+          },
+          {
+            "name": "gatekeeperData",
+            "docs": [
+              "Additional data from the gatekeeper"
+            ],
+            "type": {
               "array": [
                 "u8",
                 32
               ]
             }
+          },
+          {
+            "name": "extendedNetworkData",
+            "docs": [
+              "Variable-length data from the network, limited by",
+              "[`crate::state::GatekeeperNetwork::max_pass_data_size`]"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "extendedGatekeeperData",
+            "docs": [
+              "Variable-length data from the gatekeeper, limited by",
+              "[`crate::state::GatekeeperNetwork::max_pass_data_size`]"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "issueFee",
+            "docs": [
+              "The issue fee that can be refunded by the issuing gatekeeper within the network's",
+              "[`crate::state::GatekeeperNetwork::refund_grace_period`]"
+            ],
+            "type": {
+              "option": {
+                "defined": "PassIssueFee"
+              }
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "expirePass",
-      "accounts": [
-        {
-          "name": "pass",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "network",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeper",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "funder",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "splTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "funderTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "networkTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeperTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        }
+      "name": "redeemedVoucher",
+      "docs": [
+        "Marks a [`crate::voucher::PassVoucher`] nonce as used, so each voucher is only redeemed once.",
+        "It can be closed once the voucher has expired, as it can no longer be redeemed."
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pass",
+            "docs": [
+              "The pass the voucher was redeemed for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The account that paid the rent, and receives it when this account is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "The [`crate::voucher::PassVoucher::expiry`]"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "name": "verifyPass",
-      "accounts": [
-        {
-          "name": "pass",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "network",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeper",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "funder",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "splTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "funderTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "networkTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gatekeeperTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        }
+      "name": "slashRecord",
+      "docs": [
+        "Evidence recorded when a [`crate::state::GatekeeperNetwork`] slashes a",
+        "[`crate::state::Gatekeeper`]'s stake"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "gatekeeper",
+            "docs": [
+              "The gatekeeper that was slashed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "network",
+            "docs": [
+              "The network that slashed the gatekeeper"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "The network key that authorised the slash"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of stake moved to the network"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "A network-defined code for the reason of the slash"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "evidenceHash",
+            "docs": [
+              "A hash of the off-chain evidence for the slash"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "affectedPassesHash",
+            "docs": [
+              "A hash of the list of passes affected by the gatekeeper's misbehaviour"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "The time of the slash"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sponsorship",
+      "docs": [
+        "A fee budget deposited by a dApp, from which pass fees are paid without the dApp signing"
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The version of this struct, should be 0 until a new version is released"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "sponsor",
+            "docs": [
+              "The dApp that funds the sponsorship, and the only key that can withdraw from it"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "Distinguishes sponsorships created by the same sponsor"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the tokens held for fees"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "The token account holding the budget, owned by this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "networks",
+            "docs": [
+              "The networks whose passes may be sponsored, empty for any network"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "gatekeepers",
+            "docs": [
+              "The gatekeepers whose fees may be sponsored, empty for none"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "perSubjectCap",
+            "docs": [
+              "The most that may be spent on a single subject, `0` for no limit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "The time after which no more fees are paid, `0` if the sponsorship never expires"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
-      "name": "gatekeeper",
+      "name": "sponsoredSubject",
       "docs": [
-        "A gatekeeper on a [`GatekeeperNetwork`] that can issue passes"
+        "Tracks how much a [`Sponsorship`] has spent on a single subject"
       ],
       "type": {
         "kind": "struct",
@@ -2202,123 +7296,316 @@ export const IDL: SolanaAnchorGateway = {
             ],
             "type": "u8"
           },
+          {
+            "name": "sponsorship",
+            "docs": [
+              "The sponsorship paying the subject's fees"
+            ],
+            "type": "publicKey"
+          },
           {
             "name": "subject",
             "docs": [
-              "the authority for this gatekeeper"
+              "The subject whose fees are paid"
             ],
             "type": "publicKey"
           },
           {
-            "name": "gatekeeperBump",
+            "name": "spent",
+            "docs": [
+              "The total fees paid for the subject so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "The bump for the signer of this account"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "CreateNetworkData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authThreshold",
+            "docs": [
+              "The [`GatekeeperNetwork::auth_threshold`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "passExpireTime",
+            "docs": [
+              "The [`GatekeeperNetwork::pass_expire_time`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "The [`GatekeeperNetwork::fees`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "NetworkFeesPercentage"
+              }
+            }
+          },
+          {
+            "name": "priceFeeds",
+            "docs": [
+              "The [`GatekeeperNetwork::price_feeds`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "NetworkPriceFeed"
+              }
+            }
+          },
+          {
+            "name": "feeBounds",
+            "docs": [
+              "The [`GatekeeperNetwork::fee_bounds`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFeeBounds"
+              }
+            }
+          },
+          {
+            "name": "authKeys",
+            "docs": [
+              "The [`GatekeeperNetwork::auth_keys`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "AuthKey"
+              }
+            }
+          },
+          {
+            "name": "supportedTokens",
+            "docs": [
+              "The [`GatekeeperNetwork::supported_tokens`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "SupportedToken"
+              }
+            }
+          },
+          {
+            "name": "networkFeatures",
+            "docs": [
+              "The [`GatekeeperNetwork::network_features`]."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "staking",
+            "docs": [
+              "The [`GatekeeperNetwork::staking`]."
+            ],
+            "type": {
+              "defined": "NetworkStaking"
+            }
+          },
+          {
+            "name": "maxPassDataSize",
+            "docs": [
+              "The [`GatekeeperNetwork::max_pass_data_size`]."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "refundGracePeriod",
+            "docs": [
+              "The [`GatekeeperNetwork::refund_grace_period`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feeNoticePeriod",
+            "docs": [
+              "The [`GatekeeperNetwork::fee_notice_period`]."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateNetworkData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authThreshold",
+            "docs": [
+              "The [`GatekeeperNetwork::auth_threshold`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "passExpireTime",
+            "docs": [
+              "The [`GatekeeperNetwork::pass_expire_time`]."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "The [`GatekeeperNetwork::fees`]."
+            ],
+            "type": {
+              "defined": "UpdateFees"
+            }
+          },
+          {
+            "name": "priceFeeds",
+            "docs": [
+              "The [`GatekeeperNetwork::price_feeds`]."
+            ],
+            "type": {
+              "defined": "UpdatePriceFeeds"
+            }
+          },
+          {
+            "name": "feeBounds",
+            "docs": [
+              "The [`GatekeeperNetwork::fee_bounds`]. Existing gatekeeper fees outside them can not be charged."
+            ],
+            "type": {
+              "defined": "UpdateFeeBounds"
+            }
+          },
+          {
+            "name": "authKeys",
             "docs": [
-              "The bump for the signer of this gatekeeper"
+              "The [`GatekeeperNetwork::auth_keys`]."
             ],
-            "type": "u8"
+            "type": {
+              "defined": "UpdateKeys"
+            }
           },
           {
-            "name": "gatekeeperNetwork",
+            "name": "networkFeatures",
             "docs": [
-              "The [`GatekeeperNetwork`] this gatekeeper is on"
+              "The [`GatekeeperNetwork::network_features`]."
             ],
-            "type": "publicKey"
+            "type": "u32"
           },
           {
-            "name": "stakingAccount",
+            "name": "supportedTokens",
             "docs": [
-              "The staking account of this gatekeeper"
+              "The [`GatekeeperNetwork::supported_tokens`]."
             ],
-            "type": "publicKey"
+            "type": {
+              "defined": "UpdateSupportedTokens"
+            }
           },
           {
-            "name": "gatekeeperState",
+            "name": "staking",
             "docs": [
-              "The state of this gatekeeper"
+              "The [`GatekeeperNetwork::staking`]."
             ],
             "type": {
-              "defined": "GatekeeperState"
+              "option": {
+                "defined": "NetworkStaking"
+              }
             }
           },
           {
-            "name": "tokenFees",
+            "name": "maxPassDataSize",
             "docs": [
-              "The fees for this gatekeeper"
+              "The [`GatekeeperNetwork::max_pass_data_size`]."
             ],
             "type": {
-              "vec": {
-                "defined": "GatekeeperFees"
-              }
+              "option": "u16"
             }
           },
           {
-            "name": "authThreshold",
+            "name": "refundGracePeriod",
             "docs": [
-              "The number of keys needed to change the `auth_keys`"
+              "The [`GatekeeperNetwork::refund_grace_period`]."
             ],
-            "type": "u8"
+            "type": {
+              "option": "i64"
+            }
           },
           {
-            "name": "authKeys",
+            "name": "feeNoticePeriod",
             "docs": [
-              "The keys with permissions on this gatekeeper"
+              "The [`GatekeeperNetwork::fee_notice_period`]."
             ],
             "type": {
-              "vec": {
-                "defined": "GatekeeperAuthKey"
-              }
+              "option": "i64"
             }
           }
         ]
       }
     },
     {
-      "name": "gatekeeperNetwork",
-      "docs": [
-        "A gatekeeper network which manages many [`Gatekeeper`]s."
-      ],
+      "name": "UpdateSupportedTokens",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "version",
-            "docs": [
-              "The version of this struct, should be 0 until a new version is released"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "authority",
-            "docs": [
-              "The initial authority key"
-            ],
-            "type": "publicKey"
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "SupportedToken"
+              }
+            }
           },
           {
-            "name": "networkIndex",
-            "docs": [
-              "the index of the network"
-            ],
-            "type": "u16"
-          },
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGatekeepers",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "passExpireTime",
-            "docs": [
-              "The length of time a pass lasts in seconds. `0` means does not expire."
-            ],
-            "type": "i64"
+            "name": "add",
+            "type": {
+              "vec": "publicKey"
+            }
           },
           {
-            "name": "networkFeatures",
-            "docs": [
-              "Features on the network, index relates to which feature it is. There are 32 bytes of data available for each feature."
-            ],
-            "type": "u32"
-          },
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFees",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "fees",
-            "docs": [
-              "The fees for this network"
-            ],
+            "name": "add",
             "type": {
               "vec": {
                 "defined": "NetworkFeesPercentage"
@@ -2326,110 +7613,203 @@ export const IDL: SolanaAnchorGateway = {
             }
           },
           {
-            "name": "supportedTokens",
+            "name": "remove",
             "type": {
               "vec": {
-                "defined": "SupportedToken"
+                "defined": "FeeScheduleKey"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFeeBounds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFeeBounds"
               }
             }
           },
           {
-            "name": "gatekeepers",
-            "docs": [
-              "A set of all active gatekeepers in the network"
-            ],
+            "name": "remove",
             "type": {
               "vec": "publicKey"
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePriceFeeds",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "authThreshold",
-            "docs": [
-              "The number of auth keys needed to change the `auth_keys`"
-            ],
-            "type": "u8"
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "NetworkPriceFeed"
+              }
+            }
           },
           {
-            "name": "authKeys",
-            "docs": [
-              "Keys with permissions on the network"
-            ],
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateKeys",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "add",
             "type": {
               "vec": {
                 "defined": "AuthKey"
               }
             }
+          },
+          {
+            "name": "remove",
+            "type": {
+              "vec": "publicKey"
+            }
           }
         ]
       }
     },
     {
-      "name": "pass",
+      "name": "CreateGatekeeperAddressesData",
+      "docs": [
+        "Data for [`CreateGatekeeperAddressesAccount`]"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "version",
+            "name": "termsOfServiceHash",
             "docs": [
-              "The version of this struct, should be 0 until a new version is released"
+              "The [`GatekeeperAddresses::terms_of_service_hash`]."
             ],
-            "type": "u8"
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "subject",
+            "name": "did",
             "docs": [
-              "The initial authority"
+              "The [`GatekeeperAddresses::did`]."
             ],
-            "type": "publicKey"
+            "type": "string"
           },
           {
-            "name": "network",
+            "name": "serviceEndpoints",
             "docs": [
-              "The network this pass belongs to"
+              "The [`GatekeeperAddresses::service_endpoints`]."
             ],
-            "type": "publicKey"
+            "type": {
+              "vec": "string"
+            }
           },
           {
-            "name": "passNumber",
+            "name": "supportedJurisdictions",
             "docs": [
-              "The pass number"
+              "The [`GatekeeperAddresses::supported_jurisdictions`]."
             ],
-            "type": "u16"
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  2
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateGatekeeperData",
+      "docs": [
+        "Data for [`CreateGatekeeper`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenFees",
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFees"
+              }
+            }
           },
           {
-            "name": "signerBump",
+            "name": "referenceFees",
             "docs": [
-              "The bump for the signer"
+              "The [`Gatekeeper::reference_fees`]."
             ],
-            "type": "u8"
+            "type": {
+              "option": {
+                "defined": "ReferenceFees"
+              }
+            }
           },
           {
-            "name": "gatekeeper",
-            "docs": [
-              "The gatekeeper that issued this pass"
-            ],
-            "type": "publicKey"
+            "name": "authThreshold",
+            "type": "u8"
           },
           {
-            "name": "issueTime",
+            "name": "authKeys",
+            "type": {
+              "vec": {
+                "defined": "GatekeeperAuthKey"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SlashGatekeeperData",
+      "docs": [
+        "Data for [`SlashGatekeeperAccount`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
             "docs": [
-              "The issue time of this pass, used for expiry"
+              "The amount of stake to slash, taken from unbonding stake once the staked amount is exhausted"
             ],
-            "type": "i64"
+            "type": "u64"
           },
           {
-            "name": "state",
+            "name": "reason",
             "docs": [
-              "The state of this pass"
+              "The [`SlashRecord::reason`]."
             ],
-            "type": {
-              "defined": "PassState"
-            }
+            "type": "u16"
           },
           {
-            "name": "networkData",
+            "name": "evidenceHash",
             "docs": [
-              "Additional data from the network"
+              "The [`SlashRecord::evidence_hash`]. Also seeds the [`SlashRecord`] address."
             ],
             "type": {
               "array": [
@@ -2439,9 +7819,9 @@ export const IDL: SolanaAnchorGateway = {
             }
           },
           {
-            "name": "gatekeeperData",
+            "name": "affectedPassesHash",
             "docs": [
-              "Additional data from the gatekeeper"
+              "The [`SlashRecord::affected_passes_hash`]."
             ],
             "type": {
               "array": [
@@ -2449,134 +7829,152 @@ export const IDL: SolanaAnchorGateway = {
                 32
               ]
             }
+          },
+          {
+            "name": "halt",
+            "docs": [
+              "Whether to also set the gatekeeper to [`GatekeeperState::Halted`]"
+            ],
+            "type": "bool"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "CreateNetworkData",
+      "name": "UpdateGatekeeperAddressesData",
+      "docs": [
+        "Data for [`UpdateGatekeeperAddressesAccount`]. Fields that are `None` are left unchanged."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authThreshold",
-            "docs": [
-              "The [`GatekeeperNetwork::auth_threshold`]."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "passExpireTime",
-            "docs": [
-              "The [`GatekeeperNetwork::pass_expire_time`]."
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "fees",
+            "name": "termsOfServiceHash",
             "docs": [
-              "The [`GatekeeperNetwork::fees`]."
+              "The [`GatekeeperAddresses::terms_of_service_hash`]."
             ],
             "type": {
-              "vec": {
-                "defined": "NetworkFeesPercentage"
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
               }
             }
           },
           {
-            "name": "authKeys",
+            "name": "did",
             "docs": [
-              "The [`GatekeeperNetwork::auth_keys`]."
+              "The [`GatekeeperAddresses::did`]."
             ],
             "type": {
-              "vec": {
-                "defined": "AuthKey"
-              }
+              "option": "string"
             }
           },
           {
-            "name": "supportedTokens",
+            "name": "serviceEndpoints",
             "docs": [
-              "The [`GatekeeperNetwork::supported_tokens`]."
+              "The [`GatekeeperAddresses::service_endpoints`]."
             ],
             "type": {
-              "vec": {
-                "defined": "SupportedToken"
+              "option": {
+                "vec": "string"
               }
             }
           },
           {
-            "name": "networkFeatures",
+            "name": "supportedJurisdictions",
             "docs": [
-              "The [`GatekeeperNetwork::network_features`]."
+              "The [`GatekeeperAddresses::supported_jurisdictions`]."
             ],
-            "type": "u32"
+            "type": {
+              "option": {
+                "vec": {
+                  "array": [
+                    "u8",
+                    2
+                  ]
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "UpdateNetworkData",
+      "name": "UpdateGatekeeperData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authThreshold",
+            "name": "tokenFees",
             "docs": [
-              "The [`GatekeeperNetwork::auth_threshold`]."
+              "The fees for this gatekeeper"
             ],
-            "type": "u8"
+            "type": {
+              "defined": "UpdateGatekeeperFees"
+            }
           },
           {
-            "name": "passExpireTime",
+            "name": "referenceFees",
             "docs": [
-              "The [`GatekeeperNetwork::pass_expire_time`]."
+              "The [`Gatekeeper::reference_fees`]. `Some(None)` removes the reference fees."
             ],
             "type": {
-              "option": "i64"
+              "option": {
+                "option": {
+                  "defined": "ReferenceFees"
+                }
+              }
             }
           },
           {
-            "name": "fees",
+            "name": "authThreshold",
             "docs": [
-              "The [`GatekeeperNetwork::fees`]."
+              "The [`Gatekeeper::auth_threshold`]."
             ],
             "type": {
-              "defined": "UpdateFees"
+              "option": "u8"
             }
           },
           {
             "name": "authKeys",
             "docs": [
-              "The [`GatekeeperNetwork::auth_keys`]."
+              "The keys with permissions on this gatekeeper"
             ],
             "type": {
-              "defined": "UpdateKeys"
+              "defined": "UpdateGatekeeperKeys"
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGatekeeperFees",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "networkFeatures",
-            "docs": [
-              "The [`GatekeeperNetwork::network_features`]."
-            ],
-            "type": "u32"
+            "name": "add",
+            "type": {
+              "vec": {
+                "defined": "GatekeeperFees"
+              }
+            }
           },
           {
-            "name": "supportedTokens",
-            "docs": [
-              "The [`GatekeeperNetwork::supported_tokens`]."
-            ],
+            "name": "remove",
             "type": {
-              "defined": "UpdateSupportedTokens"
+              "vec": {
+                "defined": "FeeScheduleKey"
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "UpdateSupportedTokens",
+      "name": "UpdateGatekeeperKeys",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2584,7 +7982,7 @@ export const IDL: SolanaAnchorGateway = {
             "name": "add",
             "type": {
               "vec": {
-                "defined": "SupportedToken"
+                "defined": "GatekeeperAuthKey"
               }
             }
           },
@@ -2598,325 +7996,586 @@ export const IDL: SolanaAnchorGateway = {
       }
     },
     {
-      "name": "UpdateGatekeepers",
+      "name": "CreateSponsorshipData",
+      "docs": [
+        "Data for [`CreateSponsorshipAccount`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "networks",
+            "docs": [
+              "The [`Sponsorship::networks`]."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "gatekeepers",
+            "docs": [
+              "The [`Sponsorship::gatekeepers`]."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "perSubjectCap",
+            "docs": [
+              "The [`Sponsorship::per_subject_cap`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "The [`Sponsorship::expires_at`]."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeScheduleKey",
+      "docs": [
+        "Identifies a single entry in a fee schedule"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "token",
+            "docs": [
+              "The token of the entry"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "effectiveFrom",
+            "docs": [
+              "The time the entry takes effect"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GatekeeperAuthKey",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "flags",
+            "docs": [
+              "The permissions this key has"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "key",
+            "docs": [
+              "The key"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GatekeeperStake",
+      "docs": [
+        "Tokens a [`Gatekeeper`] has staked on its [`GatekeeperNetwork`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the staked tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The staked amount that counts towards the network's minimum stake"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingAmount",
+            "docs": [
+              "Unstaked tokens that are still held in the vault until `unbonding_end_time`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingEndTime",
+            "docs": [
+              "The time after which the `unbonding_amount` can be withdrawn, restarted by every unstake"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GatekeeperFees",
+      "docs": [
+        "The fees a gatekeeper/network can take"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "add",
-            "type": {
-              "vec": "publicKey"
-            }
+            "name": "token",
+            "docs": [
+              "The token for these fees. None value for this means native SOL price"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "issue",
+            "docs": [
+              "Fees taken at issuance of a new pass in token units or lamports for SOL."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refresh",
+            "docs": [
+              "Fees taken when a pass is refreshed in token units or lamports for SOL."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expire",
+            "docs": [
+              "The fee taken when a pass is expired in token units or lamports for SOL.",
+              "This should only be used where pass value comes from one-time use."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "verify",
+            "docs": [
+              "The fee taken when a pass is verified in token units or lamports for SOL.",
+              "This should only be used where pass value comes from proper use"
+            ],
+            "type": "u64"
           },
           {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
+            "name": "effectiveFrom",
+            "docs": [
+              "The time these fees take effect. Entries for the same token with different times",
+              "form a schedule, and the latest entry that has taken effect is charged."
+            ],
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "UpdateFees",
+      "name": "ReferenceFees",
+      "docs": [
+        "Gatekeeper fees denominated in the reference currency of the network's price feeds,",
+        "with [`crate::oracle::REFERENCE_CURRENCY_DECIMALS`] decimals"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "add",
-            "type": {
-              "vec": {
-                "defined": "NetworkFeesPercentage"
-              }
-            }
+            "name": "issue",
+            "docs": [
+              "Fees taken at issuance of a new pass"
+            ],
+            "type": "u64"
           },
           {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "UpdateKeys",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "refresh",
+            "docs": [
+              "Fees taken when a pass is refreshed"
+            ],
+            "type": "u64"
+          },
           {
-            "name": "add",
-            "type": {
-              "vec": {
-                "defined": "AuthKey"
-              }
-            }
+            "name": "expire",
+            "docs": [
+              "The fee taken when a pass is expired"
+            ],
+            "type": "u64"
           },
           {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
+            "name": "verify",
+            "docs": [
+              "The fee taken when a pass is verified"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "CreateGatekeeperData",
+      "name": "NetworkStaking",
       "docs": [
-        "Data for [`CreateGatekeeper`]"
+        "The staking requirements a [`GatekeeperNetwork`] places on its [`Gatekeeper`]s"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenFees",
-            "type": {
-              "vec": {
-                "defined": "GatekeeperFees"
-              }
-            }
+            "name": "mint",
+            "docs": [
+              "The mint gatekeepers stake in. Must be a supported token if `minimum_stake` is set."
+            ],
+            "type": "publicKey"
           },
           {
-            "name": "authThreshold",
-            "type": "u8"
+            "name": "minimumStake",
+            "docs": [
+              "The amount a gatekeeper needs staked to issue passes. `0` means staking is not required."
+            ],
+            "type": "u64"
           },
           {
-            "name": "authKeys",
-            "type": {
-              "vec": {
-                "defined": "GatekeeperAuthKey"
-              }
-            }
+            "name": "unbondingPeriod",
+            "docs": [
+              "The length of time in seconds between unstaking and being able to withdraw the stake"
+            ],
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "UpdateGatekeeperData",
+      "name": "GatekeeperFeeBounds",
+      "docs": [
+        "The lowest and highest fees a gatekeeper on a [`GatekeeperNetwork`] can charge in a token"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenFees",
+            "name": "token",
             "docs": [
-              "The fees for this gatekeeper"
+              "The token the bounds apply to"
             ],
-            "type": {
-              "defined": "UpdateGatekeeperFees"
-            }
+            "type": "publicKey"
           },
           {
-            "name": "authThreshold",
+            "name": "issueMin",
             "docs": [
-              "The [`Gatekeeper::auth_threshold`]."
+              "The minimum [`GatekeeperFees::issue`]"
             ],
-            "type": {
-              "option": "u8"
-            }
+            "type": "u64"
           },
           {
-            "name": "authKeys",
+            "name": "issueMax",
             "docs": [
-              "The keys with permissions on this gatekeeper"
+              "The maximum [`GatekeeperFees::issue`]"
             ],
-            "type": {
-              "defined": "UpdateGatekeeperKeys"
-            }
+            "type": "u64"
+          },
+          {
+            "name": "refreshMin",
+            "docs": [
+              "The minimum [`GatekeeperFees::refresh`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refreshMax",
+            "docs": [
+              "The maximum [`GatekeeperFees::refresh`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expireMin",
+            "docs": [
+              "The minimum [`GatekeeperFees::expire`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expireMax",
+            "docs": [
+              "The maximum [`GatekeeperFees::expire`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "verifyMin",
+            "docs": [
+              "The minimum [`GatekeeperFees::verify`]"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "verifyMax",
+            "docs": [
+              "The maximum [`GatekeeperFees::verify`]"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "UpdateGatekeeperFees",
+      "name": "NetworkPriceFeed",
+      "docs": [
+        "A Pyth price account approved by a [`GatekeeperNetwork`] for pricing a token"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "add",
-            "type": {
-              "vec": {
-                "defined": "GatekeeperFees"
-              }
-            }
+            "name": "token",
+            "docs": [
+              "The token that is priced"
+            ],
+            "type": "publicKey"
           },
           {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
+            "name": "priceAccount",
+            "docs": [
+              "The Pyth price account giving the price of one token in the reference currency"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "maxSlotAge",
+            "docs": [
+              "The maximum number of slots since the price was published"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxConfidenceRatio",
+            "docs": [
+              "The maximum confidence interval, in basis points of the price"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "UpdateGatekeeperKeys",
+      "name": "SupportedToken",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "add",
-            "type": {
-              "vec": {
-                "defined": "GatekeeperAuthKey"
-              }
-            }
-          },
-          {
-            "name": "remove",
-            "type": {
-              "vec": "publicKey"
-            }
+            "name": "key",
+            "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "GatekeeperAuthKey",
+      "name": "NetworkFeesPercentage",
+      "docs": [
+        "Fees that a [`GatekeeperNetwork`] can charge"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "flags",
+            "name": "token",
             "docs": [
-              "The permissions this key has"
+              "The token for the fee, `None` means fee is invalid"
             ],
-            "type": "u32"
+            "type": "publicKey"
           },
           {
-            "name": "key",
+            "name": "issue",
             "docs": [
-              "The key"
+              "Percentage taken on issue. In Hundredths of a percent (0.01% or 0.0001)."
             ],
-            "type": "publicKey"
+            "type": "u16"
+          },
+          {
+            "name": "refresh",
+            "docs": [
+              "Percentage taken on refresh. In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "expire",
+            "docs": [
+              "Percentage taken on expire. In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "verify",
+            "docs": [
+              "Percentage taken on verify. In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "issueReferrer",
+            "docs": [
+              "Percentage of the issue fee paid to a referrer out of the gatekeeper's share.",
+              "In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "verifyReferrer",
+            "docs": [
+              "Percentage of the verify fee paid to a referrer out of the gatekeeper's share.",
+              "In Hundredths of a percent (0.01% or 0.0001)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "effectiveFrom",
+            "docs": [
+              "The time these fees take effect. Entries for the same token with different times",
+              "form a schedule, and the latest entry that has taken effect is charged."
+            ],
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "GatekeeperFees",
+      "name": "PassIssueFee",
       "docs": [
-        "The fees a gatekeeper/network can take"
+        "The shares of an issue fee paid for a [`Pass`], kept so they can be refunded"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "token",
+            "name": "funderTokenAccount",
             "docs": [
-              "The token for these fees. None value for this means native SOL price"
+              "The token account the fee was paid from, and the one it is refunded to"
             ],
             "type": "publicKey"
           },
           {
-            "name": "issue",
+            "name": "mint",
             "docs": [
-              "Fees taken at issuance of a new pass in token units or lamports for SOL."
+              "The mint the fee was paid in"
             ],
-            "type": "u64"
+            "type": "publicKey"
           },
           {
-            "name": "refresh",
+            "name": "networkFee",
             "docs": [
-              "Fees taken when a pass is refreshed in token units or lamports for SOL."
+              "The network's share of the fee"
             ],
             "type": "u64"
           },
           {
-            "name": "expire",
+            "name": "gatekeeperFee",
             "docs": [
-              "The fee taken when a pass is expired in token units or lamports for SOL.",
-              "This should only be used where pass value comes from one-time use."
+              "The gatekeeper's share of the fee"
             ],
             "type": "u64"
           },
           {
-            "name": "verify",
+            "name": "paidAt",
             "docs": [
-              "The fee taken when a pass is verified in token units or lamports for SOL.",
-              "This should only be used where pass value comes from proper use"
+              "The time the fee was paid"
             ],
-            "type": "u64"
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "SupportedToken",
+      "name": "AuthKey",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "flags",
+            "docs": [
+              "The permissions this key has"
+            ],
+            "type": "u16"
+          },
           {
             "name": "key",
+            "docs": [
+              "The key"
+            ],
             "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "NetworkFeesPercentage",
+      "name": "PassVoucher",
       "docs": [
-        "Fees that a [`GatekeeperNetwork`] can charge"
+        "The message a gatekeeper signs to let the subject redeem a pass without the gatekeeper online"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "token",
+            "name": "subject",
             "docs": [
-              "The token for the fee, `None` means fee is invalid"
+              "The subject the pass is issued to"
             ],
             "type": "publicKey"
           },
           {
-            "name": "issue",
+            "name": "network",
             "docs": [
-              "Percentage taken on issue. In Hundredths of a percent (0.01% or 0.0001)."
+              "The network the pass is issued on"
             ],
-            "type": "u16"
+            "type": "publicKey"
           },
           {
-            "name": "refresh",
+            "name": "gatekeeper",
             "docs": [
-              "Percentage taken on refresh. In Hundredths of a percent (0.01% or 0.0001)."
+              "The gatekeeper the pass is issued by, whose key signs the voucher"
             ],
-            "type": "u16"
+            "type": "publicKey"
           },
           {
-            "name": "expire",
+            "name": "passNumber",
             "docs": [
-              "Percentage taken on expire. In Hundredths of a percent (0.01% or 0.0001)."
+              "The number of the issued pass"
             ],
             "type": "u16"
           },
           {
-            "name": "verify",
+            "name": "expiry",
             "docs": [
-              "Percentage taken on verify. In Hundredths of a percent (0.01% or 0.0001)."
+              "The time after which the voucher can no longer be redeemed"
             ],
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "AuthKey",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "type": "i64"
+          },
           {
-            "name": "flags",
+            "name": "data",
             "docs": [
-              "The permissions this key has"
+              "Stored in [`crate::state::Pass::gatekeeper_data`]"
             ],
-            "type": "u16"
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "key",
+            "name": "nonce",
             "docs": [
-              "The key"
+              "Unique per gatekeeper, so each voucher can only be redeemed once"
             ],
-            "type": "publicKey"
+            "type": "u64"
           }
         ]
       }
@@ -2949,6 +8608,42 @@ export const IDL: SolanaAnchorGateway = {
           },
           {
             "name": "InvalidState"
+          },
+          {
+            "name": "InvalidDid"
+          },
+          {
+            "name": "InsufficientStake"
+          },
+          {
+            "name": "InsufficientStakeToUnstake"
+          },
+          {
+            "name": "UnbondingPeriodNotEnded"
+          },
+          {
+            "name": "StakeMintMismatch"
+          },
+          {
+            "name": "InsufficientStakeToSlash"
+          },
+          {
+            "name": "FeesOutOfBounds"
+          },
+          {
+            "name": "InvalidGatekeeperAddresses"
+          },
+          {
+            "name": "StakeOverflow"
+          },
+          {
+            "name": "InvalidSlashAmount"
+          },
+          {
+            "name": "InsufficientAccessPassData"
+          },
+          {
+            "name": "FeeNoticeTooShort"
           }
         ]
       }
@@ -2972,6 +8667,117 @@ export const IDL: SolanaAnchorGateway = {
           },
           {
             "name": "InvalidPass"
+          },
+          {
+            "name": "PassDataTooLarge"
+          },
+          {
+            "name": "NoRefundableFee"
+          },
+          {
+            "name": "RefundPeriodEnded"
+          },
+          {
+            "name": "InvalidRefundAccount"
+          },
+          {
+            "name": "InvalidPassRequest"
+          },
+          {
+            "name": "PassRequestNotPending"
+          },
+          {
+            "name": "PassRequestNotReclaimable"
+          },
+          {
+            "name": "InvalidDeadline"
+          },
+          {
+            "name": "InvalidVoucherSignature"
+          },
+          {
+            "name": "InvalidVoucherSigner"
+          },
+          {
+            "name": "VoucherExpired"
+          },
+          {
+            "name": "RefundRequired"
+          },
+          {
+            "name": "PassRequestExpired"
+          },
+          {
+            "name": "ConflictingFeePayers"
+          },
+          {
+            "name": "VoucherNotExpired"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SponsorshipErrors",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SponsorshipExpired"
+          },
+          {
+            "name": "NetworkNotSponsored"
+          },
+          {
+            "name": "GatekeeperNotSponsored"
+          },
+          {
+            "name": "SubjectCapExceeded"
+          },
+          {
+            "name": "InvalidSponsorship"
+          },
+          {
+            "name": "NoFeePayer"
+          },
+          {
+            "name": "UnauthorizedVerifier"
+          },
+          {
+            "name": "InvalidAmount"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeCreditErrors",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "InvalidFeeCredit"
+          },
+          {
+            "name": "InsufficientCredit"
+          },
+          {
+            "name": "ReferrerNotSupported"
+          },
+          {
+            "name": "InvalidAmount"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MigrationErrors",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "UnsupportedAccount"
+          },
+          {
+            "name": "AlreadyMigrated"
           }
         ]
       }
@@ -2996,6 +8802,23 @@ export const IDL: SolanaAnchorGateway = {
         ]
       }
     },
+    {
+      "name": "PassRequestState",
+      "docs": [
+        "The state of a [`PassRequest`]."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Fulfilled"
+          }
+        ]
+      }
+    },
     {
       "name": "PassState",
       "docs": [
@@ -3015,6 +8838,108 @@ export const IDL: SolanaAnchorGateway = {
           }
         ]
       }
+    },
+    {
+      "name": "PassDataSection",
+      "docs": [
+        "The owner of an extended data section on a [`Pass`]."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Network"
+          },
+          {
+            "name": "Gatekeeper"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeOperation",
+      "docs": [
+        "The pass operations a fee can be charged for"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Issue"
+          },
+          {
+            "name": "Refresh"
+          },
+          {
+            "name": "Expire"
+          },
+          {
+            "name": "Verify"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeePayer",
+      "docs": [
+        "The source of the tokens paying a pass fee"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Funder",
+            "fields": [
+              {
+                "name": "funder",
+                "type": {
+                  "defined": "Signer<'a>"
+                }
+              },
+              {
+                "name": "token_account",
+                "type": {
+                  "defined": "InterfaceAccount<'a,TokenAccount>"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Sponsorship",
+            "fields": [
+              {
+                "name": "sponsorship",
+                "type": {
+                  "defined": "Account<'a,Sponsorship>"
+                }
+              },
+              {
+                "name": "vault",
+                "type": {
+                  "defined": "InterfaceAccount<'a,TokenAccount>"
+                }
+              }
+            ]
+          },
+          {
+            "name": "PassRequest",
+            "fields": [
+              {
+                "name": "pass_request",
+                "type": {
+                  "defined": "Account<'a,PassRequest>"
+                }
+              },
+              {
+                "name": "escrow",
+                "type": {
+                  "defined": "InterfaceAccount<'a,TokenAccount>"
+                }
+              }
+            ]
+          }
+        ]
+      }
     }
   ],
   "errors": [
@@ -3092,6 +9017,91 @@ export const IDL: SolanaAnchorGateway = {
       "code": 6014,
       "name": "UnsupportedNetworkFeature",
       "msg": "A network feature is not enabled for this instruction"
+    },
+    {
+      "code": 6015,
+      "name": "InsufficientAccessStaking",
+      "msg": "Insufficient access to set staking"
+    },
+    {
+      "code": 6016,
+      "name": "InsufficientAccessSlash",
+      "msg": "Insufficient access to slash gatekeepers"
+    },
+    {
+      "code": 6017,
+      "name": "InsufficientAccessHaltGatekeeper",
+      "msg": "Insufficient access to halt gatekeepers"
+    },
+    {
+      "code": 6018,
+      "name": "InsufficientAccessPassDataSize",
+      "msg": "Insufficient access to set the maximum pass data size"
+    },
+    {
+      "code": 6019,
+      "name": "InsufficientAccessPassData",
+      "msg": "Insufficient access to set pass data"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidNetworkIndex",
+      "msg": "The network index is not the next index in the registry"
+    },
+    {
+      "code": 6021,
+      "name": "PriceFeedNotApproved",
+      "msg": "No price feed is approved for this token"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidPriceFeed",
+      "msg": "The price account is not the approved price feed"
+    },
+    {
+      "code": 6023,
+      "name": "PriceNotTrading",
+      "msg": "The price feed is not trading"
+    },
+    {
+      "code": 6024,
+      "name": "StalePrice",
+      "msg": "The price feed has not been updated recently enough"
+    },
+    {
+      "code": 6025,
+      "name": "PriceConfidenceTooWide",
+      "msg": "The price feed confidence interval is too wide"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidPrice",
+      "msg": "The price feed returned an unusable price"
+    },
+    {
+      "code": 6027,
+      "name": "RefundsRequireIndexedNetwork",
+      "msg": "Fee refunds can only be enabled on indexed networks"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidReferrer",
+      "msg": "The referrer can not be the funder or the subject of the pass"
+    },
+    {
+      "code": 6029,
+      "name": "FeeNoticeTooShort",
+      "msg": "Fee changes must be scheduled at least the network's fee notice period ahead"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidFeeNoticePeriod",
+      "msg": "The fee notice period can not be negative"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidFeeBounds",
+      "msg": "A fee bound minimum is above its maximum"
     }
   ]
 };
@@ -156,10 +156,6 @@ const createGatekeeperAccount = async (
     network
   );
 
-  const [stakingDataAccount] = await NetworkService.createStakingAddress(
-    authorityKeypair.publicKey
-  );
-
   const service = await NetworkService.buildFromAnchor(
     program,
     authority.publicKey,
@@ -173,15 +169,13 @@ const createGatekeeperAccount = async (
   const foundAccount = await service.getGatekeeperAccount();
 
   if (!foundAccount) {
-    console.log(
-      `Creating data account ${dataAccount.toBase58()} with staking account: ${stakingDataAccount}`
-    );
+    console.log(`Creating data account ${dataAccount.toBase58()}`);
 
     const kp = Keypair.generate();
     await airdrop(programProvider.connection, kp.publicKey, LAMPORTS_PER_SOL);
 
     await service
-      .createGatekeeper(network, stakingDataAccount, undefined, {
+      .createGatekeeper({
         tokenFees: [],
         authThreshold: 1,
         authKeys: [
@@ -193,7 +187,7 @@ const createGatekeeperAccount = async (
       })
       .rpc();
 
-    await setGatekeeperFlagsAndFees(stakingDataAccount, service, 65535, [
+    await setGatekeeperFlagsAndFees(service, 65535, [
       {
         token: TEST_MINT,
         issue: new anchor.BN(1),
@@ -24,7 +24,6 @@ describe('Gateway v2 Client', () => {
   let adminService: AdminService;
   let networkService: NetworkService;
   let gatekeeperDataAccount: PublicKey;
-  let stakingDataAccount: PublicKey;
 
   let feePayerAuthority: Keypair;
   let adminAuthority: Keypair;
@@ -41,9 +40,6 @@ describe('Gateway v2 Client', () => {
       gatekeeperAuthority.publicKey,
       networkAuthority.publicKey
     );
-    [stakingDataAccount] = await NetworkService.createStakingAddress(
-      networkAuthority.publicKey
-    );
 
     adminService = await AdminService.buildFromAnchor(
       program,
@@ -93,7 +89,7 @@ describe('Gateway v2 Client', () => {
       .rpc();
 
     await networkService
-      .createGatekeeper(stakingDataAccount)
+      .createGatekeeper()
       .withPartialSigners(networkAuthority)
       .rpc();
   });
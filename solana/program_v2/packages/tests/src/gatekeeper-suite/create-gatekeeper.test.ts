@@ -32,7 +32,6 @@ describe('Gateway v2 Client', () => {
   let adminService: AdminService;
   let networkService: NetworkService;
   let gatekeeperDataAccount: PublicKey;
-  let stakingDataAccount: PublicKey;
 
   let feePayerAuthority: Keypair;
   let adminAuthority: Keypair;
@@ -49,9 +48,6 @@ describe('Gateway v2 Client', () => {
       gatekeeperAuthority.publicKey,
       networkAuthority.publicKey
     );
-    [stakingDataAccount] = await NetworkService.createStakingAddress(
-      networkAuthority.publicKey
-    );
 
     adminService = await AdminService.buildFromAnchor(
       program,
@@ -98,7 +94,7 @@ describe('Gateway v2 Client', () => {
     it('Creates a gatekeeper w/ default data on an established network', async function () {
       // creates a gatekeeper with the admin's authority
       await networkService
-        .createGatekeeper(stakingDataAccount)
+        .createGatekeeper()
         .withPartialSigners(networkAuthority)
         .rpc();
 
@@ -116,7 +112,7 @@ describe('Gateway v2 Client', () => {
     it('Cannot create a gatekeeper with no auth keys', async function () {
       return expect(
         networkService
-          .createGatekeeper(stakingDataAccount, {
+          .createGatekeeper({
             tokenFees: [fees],
             authThreshold: 1,
             authKeys: [],
@@ -132,7 +128,7 @@ describe('Gateway v2 Client', () => {
 
       // Step 1: Create a gatekeeper
       await networkService
-        .createGatekeeper(stakingDataAccount, {
+        .createGatekeeper({
           tokenFees: [
             {
               token: tokenKey.publicKey,
@@ -168,7 +164,7 @@ describe('Gateway v2 Client', () => {
   it('Cannot create a gatekeeper with insufficient auth keys', async function () {
     return expect(
       networkService
-        .createGatekeeper(stakingDataAccount, {
+        .createGatekeeper({
           tokenFees: [
             {
               token: PublicKey.unique(),
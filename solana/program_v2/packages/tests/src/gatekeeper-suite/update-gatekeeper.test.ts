@@ -4,12 +4,16 @@ import {
 } from '@identity.com/gateway-solana-client';
 import { SolanaAnchorGateway } from '@identity.com/gateway-solana-idl';
 import * as anchor from '@coral-xyz/anchor';
-import { PublicKey } from '@solana/web3.js';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import * as chai from 'chai';
 import { expect } from 'chai';
+import chaiAsPromised from 'chai-as-promised';
 import { describe } from 'mocha';
 import { setUpAdminNetworkGatekeeper } from '../test-set-up';
 import { setGatekeeperFlagsAndFees } from '../util/lib';
 
+chai.use(chaiAsPromised);
+
 describe('Gateway v2 Client', () => {
   anchor.setProvider(anchor.AnchorProvider.env());
   const program = anchor.workspace
@@ -17,20 +21,18 @@ describe('Gateway v2 Client', () => {
   const programProvider = program.provider as anchor.AnchorProvider;
 
   let networkService: NetworkService;
-  let stakingPDA: PublicKey;
+  let gatekeeperAuthority: Keypair;
+  let gatekeeperPDA: PublicKey;
 
   before(async () => {
-    ({ networkService, stakingPDA } = await setUpAdminNetworkGatekeeper(
-      program,
-      programProvider
-    ));
+    ({ networkService, gatekeeperAuthority, gatekeeperPDA } =
+      await setUpAdminNetworkGatekeeper(program, programProvider));
   });
 
   describe('Update Gatekeeper', () => {
     it('Updates a gatekeeper on an established network', async function () {
       // updates gatekeeper with new data
       await setGatekeeperFlagsAndFees(
-        stakingPDA,
         networkService,
         GatekeeperKeyFlags.AUTH | GatekeeperKeyFlags.WITHDRAW
       );
@@ -46,5 +48,23 @@ describe('Gateway v2 Client', () => {
         ).length
       ).to.equal(1);
     }).timeout(10000);
+
+    it('Cannot update a gatekeeper with a different network', async function () {
+      const otherNetworkService = await NetworkService.buildFromAnchor(
+        program,
+        Keypair.generate().publicKey,
+        gatekeeperAuthority.publicKey,
+        gatekeeperPDA,
+        {
+          clusterType: 'localnet',
+          wallet: new anchor.Wallet(gatekeeperAuthority),
+        },
+        programProvider
+      );
+
+      return expect(
+        setGatekeeperFlagsAndFees(otherNetworkService, GatekeeperKeyFlags.AUTH)
+      ).to.eventually.be.rejectedWith(/ConstraintAddress/);
+    }).timeout(10000);
   });
 });
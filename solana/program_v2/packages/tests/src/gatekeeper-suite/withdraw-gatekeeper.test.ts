@@ -30,7 +30,6 @@ describe('withdraw gatekeeper', () => {
   let networkService: NetworkService;
 
   let gatekeeperPDA: PublicKey;
-  let stakingPDA: PublicKey;
 
   let adminAuthority: Keypair;
   let networkAuthority: Keypair;
@@ -50,7 +49,6 @@ describe('withdraw gatekeeper', () => {
       gatekeeperAuthority,
       mintAuthority,
       mintAccount,
-      stakingPDA,
     } = await setUpAdminNetworkGatekeeper(program, programProvider));
     ({ gatekeeperAta } = await makeAssociatedTokenAccountsForIssue(
       programProvider.connection,
@@ -80,7 +78,6 @@ describe('withdraw gatekeeper', () => {
     );
 
     await setGatekeeperFlagsAndFees(
-      stakingPDA,
       networkService,
       GatekeeperKeyFlags.AUTH | GatekeeperKeyFlags.WITHDRAW
     );
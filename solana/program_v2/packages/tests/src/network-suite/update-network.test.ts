@@ -338,7 +338,7 @@ describe('Gateway v2 Client', () => {
           passExpireTime: 400,
           fees: {
             add: [],
-            remove: [feeKeypair.publicKey],
+            remove: [{ token: feeKeypair.publicKey, effectiveFrom: 0 }],
           },
           authKeys: {
             add: [],
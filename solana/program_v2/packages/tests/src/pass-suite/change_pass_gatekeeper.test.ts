@@ -31,7 +31,6 @@ describe('Change pass gatekeeper', () => {
   let adminService: AdminService;
 
   let gatekeeperPDA: PublicKey;
-  let stakingPDA: PublicKey;
   let passAccount: PublicKey;
   let mint: PublicKey;
 
@@ -51,7 +50,6 @@ describe('Change pass gatekeeper', () => {
       networkService,
       gatekeeperService,
       gatekeeperPDA,
-      stakingPDA,
       passAccount,
       mint,
       adminAuthority,
@@ -91,7 +89,6 @@ describe('Change pass gatekeeper', () => {
     // Assemble
     const dataAcct = networkService.getGatekeeperAddress();
     await setGatekeeperFlagsAndFees(
-      stakingPDA,
       networkService,
       GatekeeperKeyFlags.AUTH | GatekeeperKeyFlags.CHANGE_PASS_GATEKEEPER
     );
@@ -110,7 +107,6 @@ describe('Change pass gatekeeper', () => {
     // Assemble
     const dataAcct = networkService.getGatekeeperAddress();
     await setGatekeeperFlagsAndFees(
-      stakingPDA,
       networkService,
       GatekeeperKeyFlags.AUTH | GatekeeperKeyFlags.CHANGE_PASS_GATEKEEPER
     );
@@ -130,7 +126,6 @@ describe('Change pass gatekeeper', () => {
     // Assemble
     const dataAcct = networkService.getGatekeeperAddress();
     await setGatekeeperFlagsAndFees(
-      stakingPDA,
       networkService,
       GatekeeperKeyFlags.AUTH | GatekeeperKeyFlags.CHANGE_PASS_GATEKEEPER
     );
@@ -161,7 +156,6 @@ describe('Change pass gatekeeper', () => {
 
     const dataAcct = networkService.getGatekeeperAddress();
     await setGatekeeperFlagsAndFees(
-      stakingPDA,
       networkService,
       GatekeeperKeyFlags.AUTH | GatekeeperKeyFlags.CHANGE_PASS_GATEKEEPER
     );
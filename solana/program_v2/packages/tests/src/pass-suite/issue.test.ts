@@ -28,7 +28,6 @@ describe('issue', () => {
   let networkService: NetworkService;
 
   let gatekeeperPDA: PublicKey;
-  let stakingPDA: PublicKey;
   let passAccount: PublicKey;
   let mint: PublicKey;
 
@@ -44,7 +43,6 @@ describe('issue', () => {
       gatekeeperService,
       networkService,
       gatekeeperPDA,
-      stakingPDA,
       passAccount,
       mint,
       adminAuthority,
@@ -163,7 +161,7 @@ describe('issue', () => {
         mintAccount.publicKey,
         gatekeeperPDA
       );
-    await setGatekeeperFlagsAndFees(stakingPDA, networkService, 65535, [
+    await setGatekeeperFlagsAndFees(networkService, 65535, [
       {
         token: mint,
         issue: 0,
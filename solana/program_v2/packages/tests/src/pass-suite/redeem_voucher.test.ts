@@ -0,0 +1,176 @@
+import { GatekeeperService } from '@identity.com/gateway-solana-client';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import {
+  Ed25519Program,
+  Keypair,
+  PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import { SolanaAnchorGateway } from '@identity.com/gateway-solana-idl';
+import { Account, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import {
+  makeAssociatedTokenAccountsForIssue,
+  setUpAdminNetworkGatekeeper,
+} from '../test-set-up';
+
+chai.use(chaiAsPromised);
+const expect = chai.expect;
+
+type PassVoucher = {
+  subject: PublicKey;
+  network: PublicKey;
+  gatekeeper: PublicKey;
+  passNumber: number;
+  expiry: anchor.BN;
+  data: number[];
+  nonce: anchor.BN;
+};
+
+describe('Redeem a pass voucher', () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace
+    .SolanaAnchorGateway as anchor.Program<SolanaAnchorGateway>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+
+  let gatekeeperPDA: PublicKey;
+  let mint: PublicKey;
+
+  let networkAuthority: Keypair;
+  let gatekeeperAuthority: Keypair;
+  let subject: Keypair;
+
+  let gatekeeperAta: Account;
+  let networkAta: Account;
+  let funderAta: Account;
+  let funderKeypair: Keypair;
+
+  const makeVoucher = (passNumber = 0, nonce = 1): PassVoucher => ({
+    subject: subject.publicKey,
+    network: networkAuthority.publicKey,
+    gatekeeper: gatekeeperPDA,
+    passNumber,
+    expiry: new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+    data: new Array(32).fill(7),
+    nonce: new anchor.BN(nonce),
+  });
+
+  const redeem = async (
+    voucher: PassVoucher,
+    signer: Keypair = gatekeeperAuthority
+  ): Promise<PublicKey> => {
+    const pass = await GatekeeperService.createPassAddress(
+      voucher.subject,
+      voucher.network,
+      voucher.passNumber
+    );
+    const [redeemedVoucher] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('gw-voucher'),
+        gatekeeperPDA.toBuffer(),
+        voucher.nonce.toArrayLike(Buffer, 'le', 8),
+      ],
+      program.programId
+    );
+
+    const signatureInstruction =
+      Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: signer.secretKey,
+        message: program.coder.types.encode('PassVoucher', voucher),
+      });
+    const redeemInstruction = await program.methods
+      .redeemPassVoucher(voucher)
+      .accounts({
+        pass,
+        redeemedVoucher,
+        network: networkAuthority.publicKey,
+        gatekeeper: gatekeeperPDA,
+        payer: programProvider.wallet.publicKey,
+        funder: funderKeypair.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        splTokenProgram: TOKEN_PROGRAM_ID,
+        mint,
+        funderTokenAccount: funderAta.address,
+        networkTokenAccount: networkAta.address,
+        gatekeeperTokenAccount: gatekeeperAta.address,
+        sponsorship: null,
+        sponsorshipVault: null,
+        sponsoredSubject: null,
+        priceAccount: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .instruction();
+
+    await programProvider.sendAndConfirm(
+      new Transaction().add(signatureInstruction, redeemInstruction),
+      [funderKeypair]
+    );
+
+    return pass;
+  };
+
+  beforeEach(async () => {
+    let adminAuthority: Keypair;
+    let mintAuthority: Keypair;
+    let mintAccount: Keypair;
+    ({
+      gatekeeperPDA,
+      mint,
+      adminAuthority,
+      networkAuthority,
+      gatekeeperAuthority,
+      mintAuthority,
+      subject,
+      mintAccount,
+    } = await setUpAdminNetworkGatekeeper(program, programProvider));
+
+    ({ gatekeeperAta, networkAta, funderAta, funderKeypair } =
+      await makeAssociatedTokenAccountsForIssue(
+        programProvider.connection,
+        adminAuthority,
+        mintAuthority,
+        networkAuthority.publicKey,
+        gatekeeperAuthority.publicKey,
+        mintAccount.publicKey,
+        gatekeeperPDA
+      ));
+  });
+
+  it('Issues the pass signed for in the voucher', async () => {
+    const voucher = makeVoucher(3);
+
+    const passAccount = await redeem(voucher);
+
+    const pass = await program.account.pass.fetch(passAccount);
+    expect(pass.subject.toBase58()).to.equal(subject.publicKey.toBase58());
+    expect(pass.gatekeeper.toBase58()).to.equal(gatekeeperPDA.toBase58());
+    expect(pass.passNumber).to.equal(3);
+    expect(pass.gatekeeperData).to.deep.equal(voucher.data);
+  }).timeout(10000);
+
+  it('Cannot redeem a voucher twice', async () => {
+    await redeem(makeVoucher(0));
+
+    // A different pass number changes the message but not the nonce
+    return expect(redeem(makeVoucher(1))).to.eventually.be.rejected;
+  }).timeout(10000);
+
+  it('Cannot redeem a voucher signed by a key without the issue flag', async () => {
+    return expect(
+      redeem(makeVoucher(), Keypair.generate())
+    ).to.eventually.be.rejectedWith(/InvalidVoucherSigner/);
+  }).timeout(10000);
+
+  it('Cannot redeem a voucher for another gatekeeper', async () => {
+    const voucher = {
+      ...makeVoucher(),
+      gatekeeper: Keypair.generate().publicKey,
+    };
+
+    return expect(redeem(voucher)).to.eventually.be.rejectedWith(
+      /InvalidGatekeeper/
+    );
+  }).timeout(10000);
+});
@@ -31,7 +31,6 @@ export const setUpAdminNetworkGatekeeper = async (
   networkService: NetworkService;
   gatekeeperService: GatekeeperService;
   gatekeeperPDA: PublicKey;
-  stakingPDA: PublicKey;
   passAccount: PublicKey;
   mint: PublicKey;
   adminAuthority: Keypair;
@@ -62,10 +61,6 @@ export const setUpAdminNetworkGatekeeper = async (
     networkAuthority.publicKey
   );
 
-  const [stakingPDA] = await NetworkService.createStakingAddress(
-    gatekeeperAuthority.publicKey
-  );
-
   const adminService = await AdminService.buildFromAnchor(
     program,
     networkAuthority.publicKey,
@@ -114,11 +109,11 @@ export const setUpAdminNetworkGatekeeper = async (
     .rpc();
 
   await networkService
-    .createGatekeeper(stakingPDA)
+    .createGatekeeper()
     .withPartialSigners(networkAuthority)
     .rpc();
 
-  await setGatekeeperFlagsAndFees(stakingPDA, networkService, 65535, [
+  await setGatekeeperFlagsAndFees(networkService, 65535, [
     {
       token: mint,
       issue: 1000,
@@ -151,7 +146,6 @@ export const setUpAdminNetworkGatekeeper = async (
     networkService,
     gatekeeperService,
     gatekeeperPDA,
-    stakingPDA,
     passAccount,
     mint,
     adminAuthority,
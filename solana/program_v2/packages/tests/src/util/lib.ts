@@ -1,4 +1,4 @@
-import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js';
 import {
   airdrop,
   FeeStructure,
@@ -34,30 +34,26 @@ export const loadPrivateKey = async (
 };
 
 export const setGatekeeperFlagsAndFees = async (
-  stakingAccount: PublicKey,
   service: NetworkService,
   flags: number,
   feesToAdd: FeeStructure[] = []
 ): Promise<void> => {
   await service
-    .updateGatekeeper(
-      {
-        authThreshold: 1,
-        tokenFees: {
-          remove: [],
-          add: feesToAdd,
-        },
-        authKeys: {
-          add: [
-            {
-              key: service.getWallet().publicKey,
-              flags: flags,
-            },
-          ],
-          remove: [],
-        },
+    .updateGatekeeper({
+      authThreshold: 1,
+      tokenFees: {
+        remove: [],
+        add: feesToAdd,
       },
-      stakingAccount
-    )
+      authKeys: {
+        add: [
+          {
+            key: service.getWallet().publicKey,
+            flags: flags,
+          },
+        ],
+        remove: [],
+      },
+    })
     .rpc();
 };
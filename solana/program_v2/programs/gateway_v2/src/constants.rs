@@ -1,3 +1,16 @@
 pub const GATEKEEPER_SEED: &[u8; 13] = b"gw-gatekeeper";
 pub const PASS_SEED: &[u8; 7] = b"gw-pass";
 pub const MAX_NETWORK_FEE: u16 = 10000;
+pub const GATEKEEPER_ADDRESSES_SEED: &[u8; 12] = b"gw-addresses";
+pub const GATEKEEPER_STAKE_SEED: &[u8; 8] = b"gw-stake";
+pub const SLASH_RECORD_SEED: &[u8; 8] = b"gw-slash";
+pub const NETWORK_SEED: &[u8; 10] = b"gw-network";
+pub const NETWORK_REGISTRY_SEED: &[u8; 11] = b"gw-registry";
+pub const SPONSORSHIP_SEED: &[u8; 14] = b"gw-sponsorship";
+pub const SPONSORSHIP_VAULT_SEED: &[u8; 20] = b"gw-sponsorship-vault";
+pub const SPONSORED_SUBJECT_SEED: &[u8; 20] = b"gw-sponsored-subject";
+pub const FEE_CREDIT_SEED: &[u8; 13] = b"gw-fee-credit";
+pub const FEE_CREDIT_VAULT_SEED: &[u8; 19] = b"gw-fee-credit-vault";
+pub const PASS_REQUEST_SEED: &[u8; 15] = b"gw-pass-request";
+pub const PASS_REQUEST_ESCROW_SEED: &[u8; 22] = b"gw-pass-request-escrow";
+pub const VOUCHER_SEED: &[u8; 10] = b"gw-voucher";
@@ -32,6 +32,40 @@ pub enum NetworkErrors {
     TokenNotSupported,
     #[msg("A network feature is not enabled for this instruction")]
     UnsupportedNetworkFeature,
+    #[msg("Insufficient access to set staking")]
+    InsufficientAccessStaking,
+    #[msg("Insufficient access to slash gatekeepers")]
+    InsufficientAccessSlash,
+    #[msg("Insufficient access to halt gatekeepers")]
+    InsufficientAccessHaltGatekeeper,
+    #[msg("Insufficient access to set the maximum pass data size")]
+    InsufficientAccessPassDataSize,
+    #[msg("Insufficient access to set pass data")]
+    InsufficientAccessPassData,
+    #[msg("The network index is not the next index in the registry")]
+    InvalidNetworkIndex,
+    #[msg("No price feed is approved for this token")]
+    PriceFeedNotApproved,
+    #[msg("The price account is not the approved price feed")]
+    InvalidPriceFeed,
+    #[msg("The price feed is not trading")]
+    PriceNotTrading,
+    #[msg("The price feed has not been updated recently enough")]
+    StalePrice,
+    #[msg("The price feed confidence interval is too wide")]
+    PriceConfidenceTooWide,
+    #[msg("The price feed returned an unusable price")]
+    InvalidPrice,
+    #[msg("Fee refunds can only be enabled on indexed networks")]
+    RefundsRequireIndexedNetwork,
+    #[msg("The referrer can not be the funder or the subject of the pass")]
+    InvalidReferrer,
+    #[msg("Fee changes must be scheduled at least the network's fee notice period ahead")]
+    FeeNoticeTooShort,
+    #[msg("The fee notice period can not be negative")]
+    InvalidFeeNoticePeriod,
+    #[msg("A fee bound minimum is above its maximum")]
+    InvalidFeeBounds,
 }
 
 #[error_code]
@@ -52,6 +86,30 @@ pub enum GatekeeperErrors {
     FeesNotProvided,
     #[msg("Invalid gatekeeper state for the operation")]
     InvalidState,
+    #[msg("The DID must be empty or start with 'did:'")]
+    InvalidDid,
+    #[msg("The gatekeeper does not meet the network's minimum stake")]
+    InsufficientStake,
+    #[msg("The gatekeeper has not staked enough to unstake this amount")]
+    InsufficientStakeToUnstake,
+    #[msg("The unbonding period has not ended")]
+    UnbondingPeriodNotEnded,
+    #[msg("The gatekeeper still has stake in a different mint")]
+    StakeMintMismatch,
+    #[msg("The gatekeeper does not have enough stake to slash this amount")]
+    InsufficientStakeToSlash,
+    #[msg("The fees are outside the network's bounds")]
+    FeesOutOfBounds,
+    #[msg("The gatekeeper addresses account does not belong to this gatekeeper")]
+    InvalidGatekeeperAddresses,
+    #[msg("The stake amount or unbonding time is out of range")]
+    StakeOverflow,
+    #[msg("The slash amount must be greater than zero")]
+    InvalidSlashAmount,
+    #[msg("Insufficient access to set pass data")]
+    InsufficientAccessPassData,
+    #[msg("Fee changes must be scheduled at least the network's fee notice period ahead")]
+    FeeNoticeTooShort,
 }
 
 #[error_code]
@@ -66,4 +124,74 @@ pub enum PassErrors {
     InvalidNetwork,
     #[msg("The pass is not active or has expired")]
     InvalidPass,
+    #[msg("The pass data exceeds the network's maximum size")]
+    PassDataTooLarge,
+    #[msg("The pass has no issue fee to refund")]
+    NoRefundableFee,
+    #[msg("The refund grace period has ended")]
+    RefundPeriodEnded,
+    #[msg("The refund accounts do not match the pass's issue fee")]
+    InvalidRefundAccount,
+    #[msg("The pass request does not match this pass")]
+    InvalidPassRequest,
+    #[msg("The pass request is not pending")]
+    PassRequestNotPending,
+    #[msg("The pass request is pending and its deadline has not passed")]
+    PassRequestNotReclaimable,
+    #[msg("The pass request deadline must be in the future")]
+    InvalidDeadline,
+    #[msg("The transaction does not contain a valid Ed25519 signature over the voucher")]
+    InvalidVoucherSignature,
+    #[msg("The voucher signer can not issue passes for this gatekeeper")]
+    InvalidVoucherSigner,
+    #[msg("The voucher has expired")]
+    VoucherExpired,
+    #[msg("The pass has a refundable issue fee and must be revoked with refund_pass_fee")]
+    RefundRequired,
+    #[msg("The pass request deadline has passed")]
+    PassRequestExpired,
+    #[msg("A pass request and a fee credit can not both pay the issue fee")]
+    ConflictingFeePayers,
+    #[msg("The voucher has not expired and could still be redeemed")]
+    VoucherNotExpired,
+}
+
+#[error_code]
+pub enum SponsorshipErrors {
+    #[msg("The sponsorship has expired")]
+    SponsorshipExpired,
+    #[msg("The sponsorship does not cover this network")]
+    NetworkNotSponsored,
+    #[msg("The sponsorship does not cover this gatekeeper")]
+    GatekeeperNotSponsored,
+    #[msg("The sponsorship's cap for this subject would be exceeded")]
+    SubjectCapExceeded,
+    #[msg("The sponsorship accounts do not match")]
+    InvalidSponsorship,
+    #[msg("Neither a funder nor a sponsorship was provided to pay the fee")]
+    NoFeePayer,
+    #[msg("Only the pass subject or the sponsor can verify a pass paid by the sponsorship")]
+    UnauthorizedVerifier,
+    #[msg("The amount must be greater than zero")]
+    InvalidAmount,
+}
+
+#[error_code]
+pub enum FeeCreditErrors {
+    #[msg("The fee credit does not belong to this funder, gatekeeper and mint")]
+    InvalidFeeCredit,
+    #[msg("The fee credit balance is too low to pay this fee")]
+    InsufficientCredit,
+    #[msg("Referrer fees can not be paid from a fee credit")]
+    ReferrerNotSupported,
+    #[msg("The amount must be greater than zero")]
+    InvalidAmount,
+}
+
+#[error_code]
+pub enum MigrationErrors {
+    #[msg("The account can not be migrated")]
+    UnsupportedAccount,
+    #[msg("The account has already been migrated to the current version")]
+    AlreadyMigrated,
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{NETWORK_REGISTRY_SEED, NETWORK_SEED};
+use crate::errors::NetworkErrors;
+use crate::instructions::admin::{initialize_network, CreateNetworkData};
+use crate::state::{GatekeeperNetwork, NetworkRegistry};
+use crate::util::OC_SIZE_DISCRIMINATOR;
+
+// Creates a network at a PDA derived from the authority and the next index in the registry
+pub fn create_indexed_network(
+    ctx: Context<CreateIndexedNetworkAccount>,
+    network_index: u16,
+    data: CreateNetworkData,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let network = &mut ctx.accounts.network;
+
+    require!(
+        registry.claim_index(network_index),
+        NetworkErrors::InvalidNetworkIndex
+    );
+    registry.bump = *ctx.bumps.get("registry").unwrap();
+
+    network.network_index = network_index;
+    network.signer_bump = *ctx.bumps.get("network").unwrap();
+
+    initialize_network(network, &ctx.accounts.authority, data)
+}
+
+#[derive(Accounts, Debug)]
+#[instruction(network_index: u16, data: CreateNetworkData)]
+pub struct CreateIndexedNetworkAccount<'info> {
+    #[account(
+    init_if_needed,
+    payer = payer,
+    space = OC_SIZE_DISCRIMINATOR + NetworkRegistry::INIT_SPACE,
+    seeds = [NETWORK_REGISTRY_SEED],
+    bump
+    )]
+    pub registry: Account<'info, NetworkRegistry>,
+    #[account(
+    init,
+    payer = payer,
+    space = GatekeeperNetwork::size(
+    data.fees.len(),
+    data.auth_keys.len(),
+    0,
+    data.supported_tokens.len(),
+    data.price_feeds.len(),
+    data.fee_bounds.len(),
+    ),
+    seeds = [NETWORK_SEED, authority.key().as_ref(), & network_index.to_le_bytes()],
+    bump,
+    constraint = data.check_auth_threshold() @ NetworkErrors::InsufficientAuthKeys,
+    constraint = crate::util::validate_fees_within_bounds(&data.fees) @ NetworkErrors::NetworkFeeOutOfBounds
+    )]
+    pub network: Account<'info, GatekeeperNetwork>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -2,21 +2,52 @@ use anchor_lang::prelude::*;
 
 use crate::errors::NetworkErrors;
 use crate::state::{
-    AuthKey, GatekeeperNetwork, NetworkFeesPercentage, NetworkKeyFlags, SupportedToken,
+    AuthKey, GatekeeperFeeBounds, GatekeeperNetwork, NetworkFeesPercentage, NetworkKeyFlags,
+    NetworkPriceFeed, NetworkStaking, SupportedToken,
 };
 
 pub fn create_network(ctx: Context<CreateNetworkAccount>, data: CreateNetworkData) -> Result<()> {
-    let network = &mut ctx.accounts.network;
-    let authority = &ctx.accounts.authority;
+    initialize_network(&mut ctx.accounts.network, &ctx.accounts.authority, data)
+}
 
+pub(crate) fn initialize_network(
+    network: &mut GatekeeperNetwork,
+    authority: &Signer,
+    data: CreateNetworkData,
+) -> Result<()> {
+    network.version = GatekeeperNetwork::VERSION;
     network.auth_threshold = data.auth_threshold;
     // TODO: Do we even need this dedicated authority if we implement the auth_keys system?
     network.authority = *authority.key;
     network.pass_expire_time = data.pass_expire_time;
     network.auth_keys = data.auth_keys;
     network.fees = data.fees;
+    network.price_feeds = data.price_feeds;
+    network.fee_bounds = data.fee_bounds;
     network.supported_tokens = data.supported_tokens;
     network.network_features = data.network_features;
+    network.staking = data.staking;
+    network.max_pass_data_size = data.max_pass_data_size;
+    network.refund_grace_period = data.refund_grace_period;
+    network.fee_notice_period = data.fee_notice_period;
+
+    require!(network.is_staking_valid(), NetworkErrors::TokenNotSupported);
+    require!(
+        network.fee_notice_period >= 0,
+        NetworkErrors::InvalidFeeNoticePeriod
+    );
+    require!(
+        network.are_price_feeds_supported(),
+        NetworkErrors::TokenNotSupported
+    );
+    require!(
+        network.are_fee_bounds_valid(),
+        NetworkErrors::InvalidFeeBounds
+    );
+    require!(
+        network.can_refund_fees(),
+        NetworkErrors::RefundsRequireIndexedNetwork
+    );
 
     Ok(())
 }
@@ -29,16 +60,28 @@ pub struct CreateNetworkData {
     pub pass_expire_time: i64,
     /// The [`GatekeeperNetwork::fees`].
     pub fees: Vec<NetworkFeesPercentage>,
+    /// The [`GatekeeperNetwork::price_feeds`].
+    pub price_feeds: Vec<NetworkPriceFeed>,
+    /// The [`GatekeeperNetwork::fee_bounds`].
+    pub fee_bounds: Vec<GatekeeperFeeBounds>,
     /// The [`GatekeeperNetwork::auth_keys`].
     pub auth_keys: Vec<AuthKey>,
     /// The [`GatekeeperNetwork::supported_tokens`].
     pub supported_tokens: Vec<SupportedToken>,
     /// The [`GatekeeperNetwork::network_features`].
     pub network_features: u32,
+    /// The [`GatekeeperNetwork::staking`].
+    pub staking: NetworkStaking,
+    /// The [`GatekeeperNetwork::max_pass_data_size`].
+    pub max_pass_data_size: u16,
+    /// The [`GatekeeperNetwork::refund_grace_period`].
+    pub refund_grace_period: i64,
+    /// The [`GatekeeperNetwork::fee_notice_period`].
+    pub fee_notice_period: i64,
 }
 
 impl CreateNetworkData {
-    fn check_auth_threshold(&self) -> bool {
+    pub(crate) fn check_auth_threshold(&self) -> bool {
         let auth_key_count = self
             .auth_keys
             .iter()
@@ -61,7 +104,9 @@ pub struct CreateNetworkAccount<'info> {
     data.fees.len(),
     data.auth_keys.len(),
     0,
-    data.supported_tokens.len()
+    data.supported_tokens.len(),
+    data.price_feeds.len(),
+    data.fee_bounds.len(),
     ),
     constraint = data.check_auth_threshold() @ NetworkErrors::InsufficientAuthKeys,
     constraint = crate::util::validate_fees_within_bounds(&data.fees) @ NetworkErrors::NetworkFeeOutOfBounds
@@ -84,9 +129,15 @@ mod tests {
             auth_threshold: 2,
             pass_expire_time: 0,
             fees: Vec::new(),
+            price_feeds: Vec::new(),
+            fee_bounds: Vec::new(),
             auth_keys: Vec::new(),
             supported_tokens: Vec::new(),
             network_features: 0,
+            staking: Default::default(),
+            max_pass_data_size: 0,
+            refund_grace_period: 0,
+            fee_notice_period: 0,
         };
 
         // Test case where there are fewer auth keys than the threshold
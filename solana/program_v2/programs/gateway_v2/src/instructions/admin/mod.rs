@@ -1,7 +1,9 @@
 mod close_network;
+mod create_indexed_network;
 mod create_network;
 mod update_network;
 
 pub use close_network::*;
+pub use create_indexed_network::*;
 pub use create_network::*;
 pub use update_network::*;
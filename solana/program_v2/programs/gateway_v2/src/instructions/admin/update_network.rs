@@ -2,12 +2,14 @@ use anchor_lang::prelude::*;
 
 use crate::errors::NetworkErrors;
 use crate::state::{
-    AuthKey, GatekeeperNetwork, NetworkFeesPercentage, NetworkKeyFlags, SupportedToken,
-    UpdateOperations,
+    has_fee_notice, prune_fee_schedule, AuthKey, FeeScheduleKey, GatekeeperFeeBounds,
+    GatekeeperNetwork, NetworkFeesPercentage, NetworkKeyFlags, NetworkPriceFeed, NetworkStaking,
+    SupportedToken, UpdateOperations,
 };
 
 pub fn update_network(ctx: Context<UpdateNetworkAccount>, data: UpdateNetworkData) -> Result<()> {
     let network = &mut ctx.accounts.network;
+    let now = Clock::get()?.unix_timestamp;
 
     // Only set the expire time if a new one is provided
     if let Some(pass_expire_time) = data.pass_expire_time {
@@ -15,10 +17,60 @@ pub fn update_network(ctx: Context<UpdateNetworkAccount>, data: UpdateNetworkDat
     }
 
     network.apply_update(data.auth_keys, &ctx.accounts.authority)?;
+    // Fee changes need the notice period in force before this update
+    require!(
+        has_fee_notice(
+            &network.fees,
+            &data.fees.add,
+            network.fee_notice_period,
+            now
+        ),
+        NetworkErrors::FeeNoticeTooShort
+    );
+    prune_fee_schedule(&mut network.fees, now);
     network.apply_update(data.fees, &ctx.accounts.authority)?;
+    prune_fee_schedule(&mut network.fees, now);
+    network.apply_update(data.price_feeds, &ctx.accounts.authority)?;
+    network.apply_update(data.fee_bounds, &ctx.accounts.authority)?;
     network.apply_update(data.supported_tokens, &ctx.accounts.authority)?;
     network.update_network_features(data.network_features)?;
 
+    if let Some(staking) = data.staking {
+        network.staking = staking;
+    }
+
+    if let Some(max_pass_data_size) = data.max_pass_data_size {
+        network.max_pass_data_size = max_pass_data_size;
+    }
+
+    if let Some(refund_grace_period) = data.refund_grace_period {
+        network.refund_grace_period = refund_grace_period;
+    }
+
+    if let Some(fee_notice_period) = data.fee_notice_period {
+        require!(
+            fee_notice_period >= 0,
+            NetworkErrors::InvalidFeeNoticePeriod
+        );
+        network.fee_notice_period = fee_notice_period;
+    }
+
+    require!(
+        network.are_fee_bounds_valid(),
+        NetworkErrors::InvalidFeeBounds
+    );
+    require!(
+        network.can_refund_fees(),
+        NetworkErrors::RefundsRequireIndexedNetwork
+    );
+
+    // Removing a supported token can also invalidate the staking mint and price feeds
+    require!(network.is_staking_valid(), NetworkErrors::TokenNotSupported);
+    require!(
+        network.are_price_feeds_supported(),
+        NetworkErrors::TokenNotSupported
+    );
+
     Ok(())
 }
 
@@ -30,12 +82,24 @@ pub struct UpdateNetworkData {
     pub pass_expire_time: Option<i64>,
     /// The [`GatekeeperNetwork::fees`].
     pub fees: UpdateFees,
+    /// The [`GatekeeperNetwork::price_feeds`].
+    pub price_feeds: UpdatePriceFeeds,
+    /// The [`GatekeeperNetwork::fee_bounds`]. Existing gatekeeper fees outside them can not be charged.
+    pub fee_bounds: UpdateFeeBounds,
     /// The [`GatekeeperNetwork::auth_keys`].
     pub auth_keys: UpdateKeys,
     /// The [`GatekeeperNetwork::network_features`].
     pub network_features: u32,
     /// The [`GatekeeperNetwork::supported_tokens`].
     pub supported_tokens: UpdateSupportedTokens,
+    /// The [`GatekeeperNetwork::staking`].
+    pub staking: Option<NetworkStaking>,
+    /// The [`GatekeeperNetwork::max_pass_data_size`].
+    pub max_pass_data_size: Option<u16>,
+    /// The [`GatekeeperNetwork::refund_grace_period`].
+    pub refund_grace_period: Option<i64>,
+    /// The [`GatekeeperNetwork::fee_notice_period`].
+    pub fee_notice_period: Option<i64>,
 }
 
 impl UpdateNetworkData {
@@ -49,6 +113,16 @@ impl UpdateNetworkData {
             || network.can_access(authority, NetworkKeyFlags::ADJUST_FEES)
     }
 
+    fn can_update_fee_bounds(&self, network: &GatekeeperNetwork, authority: &Signer) -> bool {
+        (self.fee_bounds.add.is_empty() && self.fee_bounds.remove.is_empty())
+            || network.can_access(authority, NetworkKeyFlags::ADJUST_FEES)
+    }
+
+    fn can_update_price_feeds(&self, network: &GatekeeperNetwork, authority: &Signer) -> bool {
+        (self.price_feeds.add.is_empty() && self.price_feeds.remove.is_empty())
+            || network.can_access(authority, NetworkKeyFlags::ADJUST_FEES)
+    }
+
     fn can_update_expiry(&self, network: &GatekeeperNetwork, authority: &Signer) -> bool {
         match self.pass_expire_time {
             None => false,
@@ -68,6 +142,54 @@ impl UpdateNetworkData {
         (self.supported_tokens.add.is_empty() && self.supported_tokens.remove.is_empty())
             || network.can_access(authority, NetworkKeyFlags::UPDATE_TOKENS)
     }
+
+    fn can_update_staking(&self, network: &GatekeeperNetwork, authority: &Signer) -> bool {
+        match self.staking {
+            None => true,
+            Some(staking) => {
+                network.staking == staking
+                    || network.can_access(authority, NetworkKeyFlags::SET_STAKING)
+            }
+        }
+    }
+
+    fn can_update_refund_grace_period(
+        &self,
+        network: &GatekeeperNetwork,
+        authority: &Signer,
+    ) -> bool {
+        match self.refund_grace_period {
+            None => true,
+            Some(period) => {
+                network.refund_grace_period == period
+                    || network.can_access(authority, NetworkKeyFlags::ADJUST_FEES)
+            }
+        }
+    }
+
+    fn can_update_fee_notice_period(
+        &self,
+        network: &GatekeeperNetwork,
+        authority: &Signer,
+    ) -> bool {
+        match self.fee_notice_period {
+            None => true,
+            Some(period) => {
+                network.fee_notice_period == period
+                    || network.can_access(authority, NetworkKeyFlags::ADJUST_FEES)
+            }
+        }
+    }
+
+    fn can_update_pass_data_size(&self, network: &GatekeeperNetwork, authority: &Signer) -> bool {
+        match self.max_pass_data_size {
+            None => true,
+            Some(size) => {
+                network.max_pass_data_size == size
+                    || network.can_access(authority, NetworkKeyFlags::SET_PASS_DATA_SIZE)
+            }
+        }
+    }
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize)]
@@ -85,6 +207,18 @@ pub struct UpdateGatekeepers {
 #[derive(Debug, AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateFees {
     pub add: Vec<NetworkFeesPercentage>,
+    pub remove: Vec<FeeScheduleKey>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateFeeBounds {
+    pub add: Vec<GatekeeperFeeBounds>,
+    pub remove: Vec<Pubkey>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePriceFeeds {
+    pub add: Vec<NetworkPriceFeed>,
     pub remove: Vec<Pubkey>,
 }
 
@@ -103,15 +237,23 @@ pub struct UpdateNetworkAccount<'info> {
     network.fees.len() + data.fees.add.len() - data.fees.remove.len(),
     network.auth_keys.len() + data.auth_keys.add.len() - data.auth_keys.remove.len(),
     network.gatekeepers.len(),
-    network.supported_tokens.len() + data.supported_tokens.add.len() - data.supported_tokens.remove.len()
+    network.supported_tokens.len() + data.supported_tokens.add.len() - data.supported_tokens.remove.len(),
+    network.price_feeds.len() + data.price_feeds.add.len() - data.price_feeds.remove.len(),
+    network.fee_bounds.len() + data.fee_bounds.add.len() - data.fee_bounds.remove.len(),
     ),
     realloc::payer = payer,
     realloc::zero = false,
     constraint = data.can_update_expiry(& network, & authority) @ NetworkErrors::InsufficientAccessExpiry,
     constraint = data.can_update_auth_keys(& network, & authority) @ NetworkErrors::InsufficientAccessAuthKeys,
     constraint = data.can_update_fees(& network, & authority) @ NetworkErrors::InsufficientAccessFees,
+    constraint = data.can_update_price_feeds(& network, & authority) @ NetworkErrors::InsufficientAccessFees,
+    constraint = data.can_update_fee_bounds(& network, & authority) @ NetworkErrors::InsufficientAccessFees,
     constraint = data.can_update_features(& network, & authority) @ NetworkErrors::InsufficientAccessFeatures,
     constraint = data.can_update_tokens(& network, & authority) @ NetworkErrors::InsufficientAccessTokens,
+    constraint = data.can_update_staking(& network, & authority) @ NetworkErrors::InsufficientAccessStaking,
+    constraint = data.can_update_pass_data_size(& network, & authority) @ NetworkErrors::InsufficientAccessPassDataSize,
+    constraint = data.can_update_refund_grace_period(& network, & authority) @ NetworkErrors::InsufficientAccessFees,
+    constraint = data.can_update_fee_notice_period(& network, & authority) @ NetworkErrors::InsufficientAccessFees,
     constraint = crate::util::validate_fees_within_bounds(& data.fees.add) @ NetworkErrors::NetworkFeeOutOfBounds,
     )]
     pub network: Account<'info, GatekeeperNetwork>,
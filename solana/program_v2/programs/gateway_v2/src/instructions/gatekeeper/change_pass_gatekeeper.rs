@@ -10,7 +10,7 @@ pub fn change_pass_gatekeeper(ctx: Context<PassChangeGatekeeper>) -> Result<()>
     let pass = &mut ctx.accounts.pass;
     let new_gatekeeper = &ctx.accounts.new_gatekeeper;
 
-    pass.gatekeeper = new_gatekeeper.key();
+    pass.change_gatekeeper(new_gatekeeper.key());
 
     Ok(())
 }
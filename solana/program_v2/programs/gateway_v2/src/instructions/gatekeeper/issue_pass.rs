@@ -3,62 +3,160 @@ use anchor_lang::Key;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::{GATEKEEPER_SEED, PASS_SEED};
-use crate::errors::{GatekeeperErrors, NetworkErrors};
+use crate::errors::{FeeCreditErrors, GatekeeperErrors, NetworkErrors, PassErrors};
 use crate::state::{
-    Gatekeeper, GatekeeperKeyFlags, GatekeeperNetwork, GatekeeperState, Pass, PassState,
+    FeeCredit, Gatekeeper, GatekeeperKeyFlags, GatekeeperNetwork, GatekeeperState, Pass,
+    PassIssueFee, PassRequest, PassRequestState, PassState, SponsoredSubject, Sponsorship,
 };
 use crate::util::{
-    calculate_network_and_gatekeeper_fee, create_and_invoke_transfer, get_gatekeeper_fees,
-    get_network_fees,
+    calculate_network_gatekeeper_and_referrer_fee, draw_sponsored_fee, get_gatekeeper_fee,
+    get_network_fees, FeeOperation, FeePayer,
 };
 
 pub fn issue_pass(ctx: Context<IssuePass>, subject: Pubkey, pass_number: u16) -> Result<()> {
     let pass = &mut ctx.accounts.pass;
     let network = &mut ctx.accounts.network;
     let gatekeeper = &mut ctx.accounts.gatekeeper;
-    let funder = &mut ctx.accounts.funder;
 
     let spl_token_program = &mut ctx.accounts.spl_token_program;
     let mint_account = &mut ctx.accounts.mint;
 
     let network_ata = &mut ctx.accounts.network_token_account;
     let gatekeeper_ata = &mut ctx.accounts.gatekeeper_token_account;
-    let funder_ata = &mut ctx.accounts.funder_token_account;
+    let referrer_ata = &mut ctx.accounts.referrer_token_account;
 
     let mint_address = mint_account.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        ctx.accounts.pass_request.is_none() || ctx.accounts.fee_credit.is_none(),
+        PassErrors::ConflictingFeePayers
+    );
 
     // TODO: Can we put the fee transfers into a trait and reuse dependent on the type of instruction?
-    let absolute_fee = get_gatekeeper_fees(&gatekeeper.token_fees, mint_address)?.issue;
-    let network_percentage = get_network_fees(&network.fees, mint_address)?.issue;
-    let (network_fee, gatekeeper_fee) =
-        calculate_network_and_gatekeeper_fee(absolute_fee, network_percentage);
-
-    create_and_invoke_transfer(
-        spl_token_program.to_owned(),
-        funder_ata.to_owned(),
-        gatekeeper_ata.to_owned(),
-        mint_account.to_owned(),
-        funder.to_owned(),
-        gatekeeper_fee,
-    )?;
-
-    create_and_invoke_transfer(
-        spl_token_program.to_owned(),
-        funder_ata.to_owned(),
-        network_ata.to_owned(),
-        mint_account.to_owned(),
-        funder.to_owned(),
-        network_fee,
-    )?;
+    let price_account = ctx
+        .accounts
+        .price_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let absolute_fee = match &ctx.accounts.pass_request {
+        // A subject's request pays the fee quoted when it was made
+        Some(pass_request) => {
+            require!(
+                pass_request.is_for(&subject, &network.key(), &gatekeeper.key(), &mint_address),
+                PassErrors::InvalidPassRequest
+            );
+            require!(
+                pass_request.state == PassRequestState::Pending,
+                PassErrors::PassRequestNotPending
+            );
+            require!(now <= pass_request.deadline, PassErrors::PassRequestExpired);
+
+            pass_request.amount
+        }
+        None => get_gatekeeper_fee(
+            gatekeeper,
+            network,
+            mint_account,
+            price_account.as_ref(),
+            FeeOperation::Issue,
+        )?,
+    };
+    let network_fees = get_network_fees(&network.fees, mint_address, now)?;
+    // The referrer's share stays with the gatekeeper when no referrer is provided
+    let referrer_percentage = referrer_ata
+        .as_ref()
+        .map_or(0, |_| network_fees.issue_referrer);
+    let (network_fee, gatekeeper_fee, referrer_fee) = calculate_network_gatekeeper_and_referrer_fee(
+        absolute_fee,
+        network_fees.issue,
+        referrer_percentage,
+    );
+
+    // Fees charged to a fee credit may already be settled, so only transferred fees are refundable,
+    // and only on networks that refund them
+    let issue_fee = if let Some(fee_credit) = &mut ctx.accounts.fee_credit {
+        // Prepaid fees are only moved when the fee credit is settled
+        require!(
+            referrer_ata.is_none(),
+            FeeCreditErrors::ReferrerNotSupported
+        );
+        let funder = ctx
+            .accounts
+            .funder
+            .as_ref()
+            .ok_or(FeeCreditErrors::InvalidFeeCredit)?;
+        fee_credit.charge(
+            &funder.key(),
+            &gatekeeper.key(),
+            &mint_address,
+            network_fee,
+            gatekeeper_fee,
+        )?;
+
+        None
+    } else {
+        let fee_payer = if let Some(pass_request) = &mut ctx.accounts.pass_request {
+            let escrow = ctx
+                .accounts
+                .pass_request_escrow
+                .as_ref()
+                .filter(|escrow| escrow.key() == pass_request.escrow)
+                .ok_or(PassErrors::InvalidPassRequest)?;
+            pass_request.state = PassRequestState::Fulfilled;
+
+            FeePayer::PassRequest {
+                pass_request: pass_request.to_owned(),
+                escrow: escrow.to_owned(),
+            }
+        } else {
+            draw_sponsored_fee(
+                &ctx.accounts.sponsorship,
+                &mut ctx.accounts.sponsored_subject,
+                &subject,
+                &network.key(),
+                &gatekeeper.key(),
+                absolute_fee,
+            )?;
+            FeePayer::new(
+                &ctx.accounts.funder,
+                &ctx.accounts.funder_token_account,
+                &ctx.accounts.sponsorship,
+                &ctx.accounts.sponsorship_vault,
+            )?
+        };
+
+        fee_payer.transfer(
+            spl_token_program,
+            gatekeeper_ata,
+            mint_account,
+            gatekeeper_fee,
+        )?;
+
+        fee_payer.transfer(spl_token_program, network_ata, mint_account, network_fee)?;
+
+        if let Some(referrer_ata) = referrer_ata {
+            fee_payer.transfer(spl_token_program, referrer_ata, mint_account, referrer_fee)?;
+        }
+
+        (network.refund_grace_period > 0).then(|| PassIssueFee {
+            funder_token_account: fee_payer.token_account(),
+            mint: mint_address,
+            network_fee,
+            gatekeeper_fee,
+            paid_at: now,
+        })
+    };
 
     pass.signer_bump = *ctx.bumps.get("pass").unwrap();
     pass.subject = subject;
-    pass.issue_time = Clock::get().unwrap().unix_timestamp;
+    pass.issue_time = now;
     pass.network = network.key();
     pass.gatekeeper = gatekeeper.key();
     pass.state = PassState::Active;
-    pass.version = 0;
+    pass.version = Pass::VERSION;
     pass.pass_number = pass_number;
+    pass.issue_fee = issue_fee;
 
     Ok(())
 }
@@ -69,7 +167,7 @@ pub struct IssuePass<'info> {
     #[account(
     init,
     payer = payer,
-    space = crate::util::OC_SIZE_DISCRIMINATOR + Pass::INIT_SPACE,
+    space = Pass::size(0, 0),
     seeds = [PASS_SEED, subject.as_ref(), network.key().as_ref(), & pass_number.to_le_bytes()],
     constraint = gatekeeper.can_access(& authority, GatekeeperKeyFlags::ISSUE),
     bump
@@ -82,19 +180,20 @@ pub struct IssuePass<'info> {
     #[account(
     seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), network.key().as_ref()],
     constraint = gatekeeper.gatekeeper_state == GatekeeperState::Active @ GatekeeperErrors::InvalidState,
+    constraint = gatekeeper.has_minimum_stake(& network.staking) @ GatekeeperErrors::InsufficientStake,
     bump = gatekeeper.gatekeeper_bump
     )]
     pub gatekeeper: Box<Account<'info, Gatekeeper>>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub authority: Signer<'info>,
-    pub funder: Signer<'info>,
+    pub funder: Option<Signer<'info>>,
     pub system_program: Program<'info, System>,
     pub spl_token_program: Interface<'info, TokenInterface>,
     #[account(constraint = network.is_token_supported(& mint.key()) @ NetworkErrors::TokenNotSupported)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
-    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub funder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     #[account(
     mut,
     constraint = network_token_account.owner == * network.to_account_info().key,
@@ -105,4 +204,26 @@ pub struct IssuePass<'info> {
     constraint = gatekeeper_token_account.owner == * gatekeeper.to_account_info().key,
     )]
     pub gatekeeper_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+    mut,
+    constraint = referrer_token_account.owner != subject @ NetworkErrors::InvalidReferrer,
+    constraint = funder.as_ref().map_or(true, | funder | referrer_token_account.owner != funder.key()) @ NetworkErrors::InvalidReferrer,
+    )]
+    pub referrer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Pays the fees in place of the funder
+    pub sponsorship: Option<Box<Account<'info, Sponsorship>>>,
+    #[account(mut)]
+    pub sponsorship_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub sponsored_subject: Option<Box<Account<'info, SponsoredSubject>>>,
+    /// Pays the fees from a subject's escrow, in place of the funder
+    #[account(mut)]
+    pub pass_request: Option<Box<Account<'info, PassRequest>>>,
+    #[account(mut)]
+    pub pass_request_escrow: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Pays the fees from prepaid credit, in place of the funder's token account
+    #[account(mut)]
+    pub fee_credit: Option<Box<Account<'info, FeeCredit>>>,
+    /// CHECK: Only read for reference fees, and must be the network's price feed for the mint
+    pub price_account: Option<UncheckedAccount<'info>>,
 }
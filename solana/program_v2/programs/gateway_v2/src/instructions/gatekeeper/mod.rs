@@ -3,11 +3,17 @@ pub use expire_pass::*;
 pub use issue_pass::*;
 pub use pass_set_state::*;
 pub use refresh_pass::*;
+pub use refund_pass_fee::*;
+pub use reject_pass_request::*;
 pub use set_pass_data::*;
+pub use set_pass_extended_data::*;
 
 mod change_pass_gatekeeper;
 mod expire_pass;
 mod issue_pass;
 mod pass_set_state;
 mod refresh_pass;
+mod refund_pass_fee;
+mod reject_pass_request;
 mod set_pass_data;
+mod set_pass_extended_data;
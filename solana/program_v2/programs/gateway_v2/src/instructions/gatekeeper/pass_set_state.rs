@@ -2,9 +2,26 @@ use anchor_lang::prelude::*;
 
 use crate::constants::{GATEKEEPER_SEED, PASS_SEED};
 use crate::errors::PassErrors;
-use crate::state::{Gatekeeper, GatekeeperKeyFlags, Pass, PassState};
+use crate::state::{Gatekeeper, GatekeeperKeyFlags, GatekeeperNetwork, Pass, PassState};
+
+pub fn pass_set_state(ctx: Context<PassSetState>, state: PassState) -> Result<()> {
+    let pass = &mut ctx.accounts.pass;
+
+    // Passes revoked within the network's grace period must refund their issue fee, which is done
+    // by `refund_pass_fee`
+    if state == PassState::Revoked && pass.issue_fee.is_some() {
+        let network = ctx
+            .accounts
+            .network
+            .as_ref()
+            .ok_or(PassErrors::InvalidNetwork)?;
+        require!(
+            !pass
+                .is_issue_fee_refundable(network.refund_grace_period, Clock::get()?.unix_timestamp),
+            PassErrors::RefundRequired
+        );
+    }
 
-pub fn pass_set_state(pass: &mut Account<Pass>, state: PassState) -> Result<()> {
     pass.state = state;
 
     Ok(())
@@ -33,4 +50,7 @@ pub struct PassSetState<'info> {
     bump = gatekeeper.gatekeeper_bump
     )]
     pub gatekeeper: Account<'info, Gatekeeper>,
+    /// Only needed to revoke a pass with an issue fee, to check the refund grace period
+    #[account(address = pass.network @ PassErrors::InvalidNetwork)]
+    pub network: Option<Account<'info, GatekeeperNetwork>>,
 }
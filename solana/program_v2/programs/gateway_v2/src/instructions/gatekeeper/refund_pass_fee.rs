@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{GATEKEEPER_SEED, PASS_SEED};
+use crate::errors::PassErrors;
+use crate::state::{Gatekeeper, GatekeeperKeyFlags, GatekeeperNetwork, Pass, PassState};
+use crate::util::{invoke_gatekeeper_transfer, invoke_network_transfer};
+
+// Revokes a pass, if it is not already revoked, and refunds its issue fee to the funder. Passes
+// with a refundable issue fee can only be revoked this way.
+pub fn refund_pass_fee(ctx: Context<RefundPassFee>) -> Result<()> {
+    let pass = &mut ctx.accounts.pass;
+    let network = &ctx.accounts.network;
+    let gatekeeper = &ctx.accounts.gatekeeper;
+
+    if pass.state != PassState::Revoked {
+        pass.state = PassState::Revoked;
+    }
+
+    require!(
+        ctx.accounts.network_token_account.owner == network.key()
+            && ctx.accounts.gatekeeper_token_account.owner == gatekeeper.key(),
+        PassErrors::InvalidRefundAccount
+    );
+
+    let issue_fee =
+        pass.take_issue_fee_refund(network.refund_grace_period, Clock::get()?.unix_timestamp)?;
+    require!(
+        issue_fee.funder_token_account == ctx.accounts.funder_token_account.key()
+            && issue_fee.mint == ctx.accounts.mint.key(),
+        PassErrors::InvalidRefundAccount
+    );
+
+    invoke_network_transfer(
+        network,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.network_token_account,
+        &ctx.accounts.funder_token_account,
+        &ctx.accounts.mint,
+        issue_fee.network_fee,
+    )?;
+
+    invoke_gatekeeper_transfer(
+        gatekeeper,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.gatekeeper_token_account,
+        &ctx.accounts.funder_token_account,
+        &ctx.accounts.mint,
+        issue_fee.gatekeeper_fee,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundPassFee<'info> {
+    #[account(
+    mut,
+    seeds = [PASS_SEED, pass.subject.as_ref(), pass.network.key().as_ref(), & pass.pass_number.to_le_bytes() ],
+    bump = pass.signer_bump,
+    constraint = gatekeeper.can_access(& authority, GatekeeperKeyFlags::REVOKE),
+    )]
+    pub pass: Account<'info, Pass>,
+    pub authority: Signer<'info>,
+    #[account(
+    constraint = pass.gatekeeper == gatekeeper.key(),
+    constraint = gatekeeper.can_set_pass_state(PassState::Revoked),
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), gatekeeper.gatekeeper_network.as_ref()],
+    bump = gatekeeper.gatekeeper_bump
+    )]
+    pub gatekeeper: Account<'info, Gatekeeper>,
+    #[account(address = pass.network)]
+    pub network: Account<'info, GatekeeperNetwork>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub network_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub gatekeeper_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::GATEKEEPER_SEED;
+use crate::errors::PassErrors;
+use crate::state::{Gatekeeper, GatekeeperKeyFlags, PassRequest, PassRequestState};
+use crate::util::close_pass_request_escrow;
+
+// Declines a pending pass request, refunding the escrowed fee to the subject
+pub fn reject_pass_request(ctx: Context<RejectPassRequestAccount>) -> Result<()> {
+    close_pass_request_escrow(
+        &ctx.accounts.pass_request,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.escrow,
+        &ctx.accounts.subject_token_account,
+        &ctx.accounts.subject.to_account_info(),
+        &ctx.accounts.mint,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RejectPassRequestAccount<'info> {
+    #[account(
+    mut,
+    close = subject,
+    constraint = pass_request.gatekeeper == gatekeeper.key() @ PassErrors::InvalidPassRequest,
+    constraint = pass_request.state == PassRequestState::Pending @ PassErrors::PassRequestNotPending,
+    )]
+    pub pass_request: Box<Account<'info, PassRequest>>,
+    #[account(
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), gatekeeper.gatekeeper_network.as_ref()],
+    bump = gatekeeper.gatekeeper_bump,
+    constraint = gatekeeper.can_access(& authority, GatekeeperKeyFlags::ISSUE),
+    )]
+    pub gatekeeper: Box<Account<'info, Gatekeeper>>,
+    pub authority: Signer<'info>,
+    /// CHECK: Receives the rent of the closed accounts, must be the requesting subject
+    #[account(mut, address = pass_request.subject @ PassErrors::InvalidPassRequest)]
+    pub subject: UncheckedAccount<'info>,
+    #[account(mut, address = pass_request.escrow @ PassErrors::InvalidPassRequest)]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = pass_request.subject_token_account @ PassErrors::InvalidPassRequest)]
+    pub subject_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = pass_request.mint @ PassErrors::InvalidPassRequest)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GATEKEEPER_SEED, PASS_SEED};
+use crate::errors::{GatekeeperErrors, NetworkErrors, PassErrors};
+use crate::state::{
+    Gatekeeper, GatekeeperKeyFlags, GatekeeperNetwork, GatekeeperState, NetworkKeyFlags, Pass,
+    PassDataSection,
+};
+use crate::util::resize_account;
+
+// Replaces the extended data in one section of a pass, resizing the pass to fit. Rent freed
+// by shrinking the pass goes to the network.
+pub fn set_pass_extended_data(
+    ctx: Context<PassSetExtendedData>,
+    section: PassDataSection,
+    data: Vec<u8>,
+) -> Result<()> {
+    let max_size = ctx.accounts.network.max_pass_data_size;
+    let new_size = ctx.accounts.pass.size_after_update(section, &data);
+
+    ctx.accounts
+        .pass
+        .set_extended_data(section, data, max_size)?;
+
+    resize_account(
+        &ctx.accounts.pass.to_account_info(),
+        new_size,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.network.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )
+}
+
+#[derive(Accounts, Debug)]
+#[instruction(section: PassDataSection, data: Vec<u8>)]
+pub struct PassSetExtendedData<'info> {
+    #[account(
+    mut,
+    seeds = [PASS_SEED, pass.subject.as_ref(), pass.network.key().as_ref(), & pass.pass_number.to_le_bytes() ],
+    bump = pass.signer_bump,
+    constraint = pass.network == network.key() @ PassErrors::InvalidNetwork,
+    constraint = pass.gatekeeper == gatekeeper.key() @ PassErrors::InvalidGatekeeper,
+    )]
+    pub pass: Account<'info, Pass>,
+    #[account(
+    mut,
+    constraint = section != PassDataSection::Network || network.can_access(& authority, NetworkKeyFlags::SET_PASS_DATA) @ NetworkErrors::InsufficientAccessPassData,
+    )]
+    pub network: Account<'info, GatekeeperNetwork>,
+    #[account(
+    constraint = section != PassDataSection::Gatekeeper || gatekeeper.can_access(& authority, GatekeeperKeyFlags::SET_PASS_DATA) @ GatekeeperErrors::InsufficientAccessPassData,
+    constraint = gatekeeper.gatekeeper_state != GatekeeperState::Halted @ GatekeeperErrors::InvalidState,
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), gatekeeper.gatekeeper_network.as_ref()],
+    bump = gatekeeper.gatekeeper_bump
+    )]
+    pub gatekeeper: Account<'info, Gatekeeper>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
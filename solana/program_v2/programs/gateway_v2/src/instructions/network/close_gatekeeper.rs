@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::GATEKEEPER_SEED;
+use crate::constants::{GATEKEEPER_ADDRESSES_SEED, GATEKEEPER_SEED};
 use crate::errors::*;
 use crate::state::*;
 
@@ -20,6 +20,12 @@ pub fn close_gatekeeper(ctx: Context<CloseGatekeeperAccount>) -> Result<()> {
         return Err(error!(GatekeeperErrors::InvalidGatekeeper));
     }
 
+    // The addresses account is closed alongside the gatekeeper so its rent is not stranded
+    require!(
+        ctx.accounts.addresses.is_some() == (gatekeeper.addresses != Pubkey::default()),
+        GatekeeperErrors::InvalidGatekeeperAddresses
+    );
+
     Ok(())
 }
 
@@ -35,11 +41,20 @@ pub struct CloseGatekeeperAccount<'info> {
     pub gatekeeper: Account<'info, Gatekeeper>,
     #[account(
     mut,
+    close = destination,
+    seeds = [GATEKEEPER_ADDRESSES_SEED, gatekeeper.key().as_ref()],
+    bump = addresses.bump,
+    )]
+    pub addresses: Option<Account<'info, GatekeeperAddresses>>,
+    #[account(
+    mut,
     realloc = GatekeeperNetwork::size(
     network.fees.len(),
     network.auth_keys.len(),
     network.gatekeepers.len() - 1,
     network.supported_tokens.len(),
+    network.price_feeds.len(),
+    network.fee_bounds.len(),
     ),
     realloc::payer = payer,
     realloc::zero = false,
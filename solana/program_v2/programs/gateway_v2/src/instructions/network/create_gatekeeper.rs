@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::constants::GATEKEEPER_SEED;
 use crate::errors::{GatekeeperErrors, NetworkErrors};
 use crate::state::gatekeeper::{Gatekeeper, GatekeeperFees, GatekeeperState};
-use crate::state::{GatekeeperAuthKey, GatekeeperNetwork, NetworkKeyFlags};
+use crate::state::{GatekeeperAuthKey, GatekeeperNetwork, NetworkKeyFlags, ReferenceFees};
 use crate::util::check_gatekeeper_auth_threshold;
 
 pub fn create_gatekeeper(
@@ -13,7 +13,6 @@ pub fn create_gatekeeper(
     let subject = &mut ctx.accounts.subject;
     let gatekeeper = &mut ctx.accounts.gatekeeper;
     let network = &mut ctx.accounts.network;
-    let staking_account = &ctx.accounts.staking_account;
     let auth_keys = data.auth_keys;
 
     require!(
@@ -21,11 +20,12 @@ pub fn create_gatekeeper(
         GatekeeperErrors::InsufficientAuthKeys
     );
 
+    gatekeeper.version = Gatekeeper::VERSION;
     gatekeeper.subject = *subject.key;
     gatekeeper.gatekeeper_bump = *ctx.bumps.get("gatekeeper").unwrap();
     gatekeeper.gatekeeper_network = network.key();
-    gatekeeper.staking_account = staking_account.key();
     gatekeeper.token_fees = data.token_fees;
+    gatekeeper.reference_fees = data.reference_fees;
     gatekeeper.auth_threshold = data.auth_threshold;
     gatekeeper.auth_keys = auth_keys;
     gatekeeper.gatekeeper_state = GatekeeperState::Active;
@@ -40,6 +40,8 @@ pub fn create_gatekeeper(
 pub struct CreateGatekeeperData {
     // Fees for the gatekeeper
     pub token_fees: Vec<GatekeeperFees>,
+    /// The [`Gatekeeper::reference_fees`].
+    pub reference_fees: Option<ReferenceFees>,
     pub auth_threshold: u8,
     pub auth_keys: Vec<GatekeeperAuthKey>,
 }
@@ -69,14 +71,14 @@ pub struct CreateGatekeeperAccount<'info> {
     network.auth_keys.len(),
     network.gatekeepers.len() + 1,
     network.supported_tokens.len(),
+    network.price_feeds.len(),
+    network.fee_bounds.len(),
     ),
     realloc::payer = payer,
     realloc::zero = false,
     constraint = network.can_access(& authority, NetworkKeyFlags::CREATE_GATEKEEPER) @ NetworkErrors::InsufficientAccessCreateGatekeeper,
+    constraint = network.are_gatekeeper_fees_within_bounds(& data.token_fees) @ GatekeeperErrors::FeesOutOfBounds,
     )]
     pub network: Account<'info, GatekeeperNetwork>,
-    #[account(mut)]
-    /// CHECK: Add Checking Later
-    pub staking_account: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GATEKEEPER_ADDRESSES_SEED, GATEKEEPER_SEED};
+use crate::errors::GatekeeperErrors;
+use crate::state::{Gatekeeper, GatekeeperAddresses, GatekeeperKeyFlags};
+
+// Creates the discoverability addresses for a gatekeeper and links them from the gatekeeper
+pub fn create_gatekeeper_addresses(
+    ctx: Context<CreateGatekeeperAddressesAccount>,
+    data: CreateGatekeeperAddressesData,
+) -> Result<()> {
+    let gatekeeper = &mut ctx.accounts.gatekeeper;
+    let addresses = &mut ctx.accounts.addresses;
+
+    addresses.version = 0;
+    addresses.gatekeeper = gatekeeper.key();
+    addresses.gatekeeper_network = gatekeeper.gatekeeper_network;
+    addresses.bump = *ctx.bumps.get("addresses").unwrap();
+    addresses.initialize(data)?;
+
+    gatekeeper.addresses = addresses.key();
+
+    Ok(())
+}
+
+/// Data for [`CreateGatekeeperAddressesAccount`]
+#[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct CreateGatekeeperAddressesData {
+    /// The [`GatekeeperAddresses::terms_of_service_hash`].
+    pub terms_of_service_hash: [u8; 32],
+    /// The [`GatekeeperAddresses::did`].
+    pub did: String,
+    /// The [`GatekeeperAddresses::service_endpoints`].
+    pub service_endpoints: Vec<String>,
+    /// The [`GatekeeperAddresses::supported_jurisdictions`].
+    pub supported_jurisdictions: Vec<[u8; 2]>,
+}
+
+#[derive(Accounts, Debug)]
+#[instruction(data: CreateGatekeeperAddressesData)]
+pub struct CreateGatekeeperAddressesAccount<'info> {
+    #[account(
+    init,
+    payer = payer,
+    space = GatekeeperAddresses::size(
+    &data.did,
+    &data.service_endpoints,
+    data.supported_jurisdictions.len(),
+    ),
+    seeds = [GATEKEEPER_ADDRESSES_SEED, gatekeeper.key().as_ref()],
+    bump
+    )]
+    pub addresses: Account<'info, GatekeeperAddresses>,
+    #[account(
+    mut,
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), gatekeeper.gatekeeper_network.as_ref()],
+    bump = gatekeeper.gatekeeper_bump,
+    constraint = gatekeeper.can_access(& authority, GatekeeperKeyFlags::SET_ADDRESSES) @ GatekeeperErrors::InsufficientAccessAuthKeys,
+    )]
+    pub gatekeeper: Account<'info, Gatekeeper>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{GATEKEEPER_SEED, GATEKEEPER_STAKE_SEED};
+use crate::errors::NetworkErrors;
+use crate::state::{Gatekeeper, GatekeeperNetwork};
+use crate::util::create_and_invoke_transfer;
+
+// Deposits tokens into the gatekeeper's stake vault
+pub fn gatekeeper_stake(ctx: Context<GatekeeperStakeAccount>, amount: u64) -> Result<()> {
+    let gatekeeper = &mut ctx.accounts.gatekeeper;
+    let stake_vault = &ctx.accounts.stake_vault;
+    let mint = &ctx.accounts.mint;
+
+    gatekeeper.add_stake(stake_vault.key(), mint.key(), amount)?;
+
+    create_and_invoke_transfer(
+        ctx.accounts.token_program.to_owned(),
+        ctx.accounts.funder_token_account.to_owned(),
+        stake_vault.to_owned(),
+        mint.to_owned(),
+        ctx.accounts.funder.to_owned(),
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct GatekeeperStakeAccount<'info> {
+    #[account(
+    mut,
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), network.key().as_ref()],
+    bump = gatekeeper.gatekeeper_bump,
+    )]
+    pub gatekeeper: Box<Account<'info, Gatekeeper>>,
+    #[account(constraint = gatekeeper.gatekeeper_network == network.key())]
+    pub network: Box<Account<'info, GatekeeperNetwork>>,
+    #[account(
+    init_if_needed,
+    payer = payer,
+    seeds = [GATEKEEPER_STAKE_SEED, gatekeeper.key().as_ref(), mint.key().as_ref()],
+    bump,
+    token::mint = mint,
+    token::authority = gatekeeper,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(constraint = mint.key() == network.staking.mint @ NetworkErrors::TokenNotSupported)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub funder: Signer<'info>,
+    #[account(mut)]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::GATEKEEPER_SEED;
+use crate::errors::GatekeeperErrors;
+use crate::state::{Gatekeeper, GatekeeperKeyFlags, GatekeeperNetwork};
+
+// Starts the unbonding period for part of the gatekeeper's stake
+pub fn gatekeeper_unstake(ctx: Context<GatekeeperUnstakeAccount>, amount: u64) -> Result<()> {
+    let gatekeeper = &mut ctx.accounts.gatekeeper;
+    let network = &ctx.accounts.network;
+
+    gatekeeper.unstake(
+        amount,
+        network.staking.unbonding_period,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct GatekeeperUnstakeAccount<'info> {
+    #[account(
+    mut,
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), network.key().as_ref()],
+    bump = gatekeeper.gatekeeper_bump,
+    constraint = gatekeeper.can_access(& authority, GatekeeperKeyFlags::UNSTAKE) @ GatekeeperErrors::InsufficientAccessAuthKeys,
+    )]
+    pub gatekeeper: Account<'info, Gatekeeper>,
+    #[account(constraint = gatekeeper.gatekeeper_network == network.key())]
+    pub network: Account<'info, GatekeeperNetwork>,
+    pub authority: Signer<'info>,
+}
@@ -62,7 +62,9 @@ pub struct GatekeeperWithdrawAccount<'info> {
     pub receiver_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
     mut,
-    constraint = gatekeeper_token_account.owner == * gatekeeper.to_account_info().key
+    constraint = gatekeeper_token_account.owner == * gatekeeper.to_account_info().key,
+    // Stake can only leave the vault through the unbonding process
+    constraint = gatekeeper_token_account.key() != gatekeeper.staking_account @ GatekeeperErrors::InvalidGatekeeper,
     )]
     pub gatekeeper_token_account: InterfaceAccount<'info, TokenAccount>,
     pub mint: InterfaceAccount<'info, Mint>,
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::GATEKEEPER_SEED;
+use crate::errors::GatekeeperErrors;
+use crate::state::{Gatekeeper, GatekeeperKeyFlags};
+use crate::util::invoke_gatekeeper_transfer;
+
+// Withdraws stake from the vault once its unbonding period has ended
+pub fn gatekeeper_withdraw_stake(ctx: Context<GatekeeperWithdrawStakeAccount>) -> Result<()> {
+    let gatekeeper = &mut ctx.accounts.gatekeeper;
+
+    let amount = gatekeeper.release_unbonded_stake(Clock::get()?.unix_timestamp)?;
+
+    invoke_gatekeeper_transfer(
+        gatekeeper,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.receiver_token_account,
+        &ctx.accounts.mint,
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GatekeeperWithdrawStakeAccount<'info> {
+    #[account(
+    mut,
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), gatekeeper.gatekeeper_network.as_ref()],
+    bump = gatekeeper.gatekeeper_bump,
+    constraint = gatekeeper.can_access(& authority, GatekeeperKeyFlags::UNSTAKE) @ GatekeeperErrors::InsufficientAccessAuthKeys,
+    )]
+    pub gatekeeper: Account<'info, Gatekeeper>,
+    pub authority: Signer<'info>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub receiver_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+    mut,
+    address = gatekeeper.staking_account @ GatekeeperErrors::InvalidGatekeeper,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = gatekeeper.stake.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+}
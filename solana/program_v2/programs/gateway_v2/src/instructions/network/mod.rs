@@ -1,11 +1,23 @@
 mod close_gatekeeper;
 mod create_gatekeeper;
+mod create_gatekeeper_addresses;
+mod gatekeeper_stake;
+mod gatekeeper_unstake;
 mod gatekeeper_withdraw;
+mod gatekeeper_withdraw_stake;
 mod set_gatekeeper_state;
+mod slash_gatekeeper;
 mod update_gatekeeper;
+mod update_gatekeeper_addresses;
 
 pub use close_gatekeeper::*;
 pub use create_gatekeeper::*;
+pub use create_gatekeeper_addresses::*;
+pub use gatekeeper_stake::*;
+pub use gatekeeper_unstake::*;
 pub use gatekeeper_withdraw::*;
+pub use gatekeeper_withdraw_stake::*;
 pub use set_gatekeeper_state::*;
+pub use slash_gatekeeper::*;
 pub use update_gatekeeper::*;
+pub use update_gatekeeper_addresses::*;
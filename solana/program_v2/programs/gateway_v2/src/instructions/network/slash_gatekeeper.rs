@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{GATEKEEPER_SEED, SLASH_RECORD_SEED};
+use crate::errors::{GatekeeperErrors, NetworkErrors};
+use crate::state::{Gatekeeper, GatekeeperNetwork, GatekeeperState, NetworkKeyFlags, SlashRecord};
+use crate::util::{invoke_gatekeeper_transfer, OC_SIZE_DISCRIMINATOR};
+
+// Allows a network to move part of a gatekeeper's stake into the network vault
+pub fn slash_gatekeeper(
+    ctx: Context<SlashGatekeeperAccount>,
+    data: SlashGatekeeperData,
+) -> Result<()> {
+    let gatekeeper = &mut ctx.accounts.gatekeeper;
+    let slash_record = &mut ctx.accounts.slash_record;
+
+    let amount = data.amount;
+    gatekeeper.slash(amount)?;
+
+    invoke_gatekeeper_transfer(
+        gatekeeper,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.network_token_account,
+        &ctx.accounts.mint,
+        amount,
+    )?;
+
+    if data.halt {
+        gatekeeper.set_gatekeeper_state(&GatekeeperState::Halted)?;
+    }
+
+    slash_record.version = 0;
+    slash_record.gatekeeper = gatekeeper.key();
+    slash_record.network = ctx.accounts.network.key();
+    slash_record.authority = ctx.accounts.authority.key();
+    slash_record.amount = amount;
+    slash_record.reason = data.reason;
+    slash_record.evidence_hash = data.evidence_hash;
+    slash_record.affected_passes_hash = data.affected_passes_hash;
+    slash_record.timestamp = Clock::get()?.unix_timestamp;
+    slash_record.bump = *ctx.bumps.get("slash_record").unwrap();
+
+    Ok(())
+}
+
+/// Data for [`SlashGatekeeperAccount`]
+#[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct SlashGatekeeperData {
+    /// The amount of stake to slash, taken from unbonding stake once the staked amount is exhausted
+    pub amount: u64,
+    /// The [`SlashRecord::reason`].
+    pub reason: u16,
+    /// The [`SlashRecord::evidence_hash`]. Also seeds the [`SlashRecord`] address.
+    pub evidence_hash: [u8; 32],
+    /// The [`SlashRecord::affected_passes_hash`].
+    pub affected_passes_hash: [u8; 32],
+    /// Whether to also set the gatekeeper to [`GatekeeperState::Halted`]
+    pub halt: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(data: SlashGatekeeperData)]
+pub struct SlashGatekeeperAccount<'info> {
+    #[account(
+    mut,
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), network.key().as_ref()],
+    bump = gatekeeper.gatekeeper_bump,
+    constraint = gatekeeper.gatekeeper_network == network.key(),
+    )]
+    pub gatekeeper: Box<Account<'info, Gatekeeper>>,
+    #[account(
+    constraint = network.can_access(& authority, NetworkKeyFlags::SLASH_GATEKEEPER) @ NetworkErrors::InsufficientAccessSlash,
+    constraint = ! data.halt || network.can_access(& authority, NetworkKeyFlags::HALT_GATEKEEPER) @ NetworkErrors::InsufficientAccessHaltGatekeeper,
+    )]
+    pub network: Box<Account<'info, GatekeeperNetwork>>,
+    pub authority: Signer<'info>,
+    #[account(
+    init,
+    payer = payer,
+    space = OC_SIZE_DISCRIMINATOR + SlashRecord::INIT_SPACE,
+    seeds = [SLASH_RECORD_SEED, gatekeeper.key().as_ref(), data.evidence_hash.as_ref()],
+    bump
+    )]
+    pub slash_record: Box<Account<'info, SlashRecord>>,
+    #[account(
+    mut,
+    address = gatekeeper.staking_account @ GatekeeperErrors::InvalidGatekeeper,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+    mut,
+    constraint = network_token_account.owner == network.key(),
+    )]
+    pub network_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = gatekeeper.stake.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
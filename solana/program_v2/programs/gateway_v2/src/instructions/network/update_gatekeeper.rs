@@ -3,7 +3,10 @@ use anchor_lang::prelude::*;
 use crate::constants::GATEKEEPER_SEED;
 use crate::errors::GatekeeperErrors;
 use crate::state::UpdateOperations;
-use crate::state::{Gatekeeper, GatekeeperAuthKey, GatekeeperFees, GatekeeperKeyFlags};
+use crate::state::{
+    has_fee_notice, prune_fee_schedule, FeeScheduleKey, Gatekeeper, GatekeeperAuthKey,
+    GatekeeperFees, GatekeeperKeyFlags, GatekeeperNetwork, ReferenceFees,
+};
 
 // Runs all the update methods on the passed-in gatekeeper
 pub fn update_gatekeeper(
@@ -12,11 +15,26 @@ pub fn update_gatekeeper(
 ) -> Result<()> {
     let gatekeeper = &mut ctx.accounts.gatekeeper;
     let authority = &mut ctx.accounts.authority;
-    let staking_account = &mut ctx.accounts.staking_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        has_fee_notice(
+            &gatekeeper.token_fees,
+            &data.token_fees.add,
+            ctx.accounts.network.fee_notice_period,
+            now
+        ),
+        GatekeeperErrors::FeeNoticeTooShort
+    );
 
     gatekeeper.apply_update(data.auth_keys, authority)?;
+    prune_fee_schedule(&mut gatekeeper.token_fees, now);
     gatekeeper.apply_update(data.token_fees, authority)?;
-    gatekeeper.set_staking_account(staking_account)?;
+    prune_fee_schedule(&mut gatekeeper.token_fees, now);
+
+    if let Some(reference_fees) = data.reference_fees {
+        gatekeeper.reference_fees = reference_fees;
+    }
 
     Ok(())
 }
@@ -35,8 +53,14 @@ impl UpdateGatekeeperData {
             )
     }
 
-    fn can_update_staking(&self, gatekeeper: &Gatekeeper, authority: &Signer) -> bool {
-        gatekeeper.can_access(authority, GatekeeperKeyFlags::AUTH)
+    fn can_update_reference_fees(&self, gatekeeper: &Gatekeeper, authority: &Signer) -> bool {
+        match self.reference_fees {
+            None => true,
+            Some(reference_fees) => {
+                gatekeeper.reference_fees == reference_fees
+                    || gatekeeper.can_access(authority, GatekeeperKeyFlags::ADJUST_FEES)
+            }
+        }
     }
 }
 
@@ -55,16 +79,18 @@ pub struct UpdateGatekeeperAccount<'info> {
     bump = gatekeeper.gatekeeper_bump,
     constraint = data.can_update_auth_keys(&gatekeeper, & authority) @ GatekeeperErrors::InsufficientAccessAuthKeys,
     constraint = data.can_update_fees(&gatekeeper, & authority) @ GatekeeperErrors::InsufficientAccessAuthKeys,
-    constraint = data.can_update_staking(&gatekeeper, & authority) @ GatekeeperErrors::InsufficientAccessAuthKeys,
+    constraint = data.can_update_reference_fees(&gatekeeper, & authority) @ GatekeeperErrors::InsufficientAccessAuthKeys,
     )]
     pub gatekeeper: Account<'info, Gatekeeper>,
+    #[account(
+    address = gatekeeper.gatekeeper_network,
+    constraint = network.are_gatekeeper_fees_within_bounds(& data.token_fees.add) @ GatekeeperErrors::FeesOutOfBounds,
+    )]
+    pub network: Box<Account<'info, GatekeeperNetwork>>,
     #[account(mut)]
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(mut)]
-    /// CHECK: Add Check Later
-    pub staking_account: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -72,6 +98,8 @@ pub struct UpdateGatekeeperAccount<'info> {
 pub struct UpdateGatekeeperData {
     /// The fees for this gatekeeper
     pub token_fees: UpdateGatekeeperFees,
+    /// The [`Gatekeeper::reference_fees`]. `Some(None)` removes the reference fees.
+    pub reference_fees: Option<Option<ReferenceFees>>,
     /// The [`Gatekeeper::auth_threshold`].
     pub auth_threshold: Option<u8>,
     /// The keys with permissions on this gatekeeper
@@ -81,7 +109,7 @@ pub struct UpdateGatekeeperData {
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct UpdateGatekeeperFees {
     pub add: Vec<GatekeeperFees>,
-    pub remove: Vec<Pubkey>,
+    pub remove: Vec<FeeScheduleKey>,
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GATEKEEPER_ADDRESSES_SEED, GATEKEEPER_SEED};
+use crate::errors::GatekeeperErrors;
+use crate::state::{Gatekeeper, GatekeeperAddresses, GatekeeperKeyFlags};
+
+// Replaces the discoverability addresses that are set in the update data
+pub fn update_gatekeeper_addresses(
+    ctx: Context<UpdateGatekeeperAddressesAccount>,
+    data: UpdateGatekeeperAddressesData,
+) -> Result<()> {
+    ctx.accounts.addresses.update(data)
+}
+
+/// Data for [`UpdateGatekeeperAddressesAccount`]. Fields that are `None` are left unchanged.
+#[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateGatekeeperAddressesData {
+    /// The [`GatekeeperAddresses::terms_of_service_hash`].
+    pub terms_of_service_hash: Option<[u8; 32]>,
+    /// The [`GatekeeperAddresses::did`].
+    pub did: Option<String>,
+    /// The [`GatekeeperAddresses::service_endpoints`].
+    pub service_endpoints: Option<Vec<String>>,
+    /// The [`GatekeeperAddresses::supported_jurisdictions`].
+    pub supported_jurisdictions: Option<Vec<[u8; 2]>>,
+}
+
+#[derive(Accounts, Debug)]
+#[instruction(data: UpdateGatekeeperAddressesData)]
+pub struct UpdateGatekeeperAddressesAccount<'info> {
+    #[account(
+    mut,
+    realloc = addresses.size_after_update(&data),
+    realloc::payer = payer,
+    realloc::zero = false,
+    seeds = [GATEKEEPER_ADDRESSES_SEED, gatekeeper.key().as_ref()],
+    bump = addresses.bump,
+    constraint = gatekeeper.addresses == addresses.key() @ GatekeeperErrors::InvalidGatekeeper,
+    )]
+    pub addresses: Account<'info, GatekeeperAddresses>,
+    #[account(
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), gatekeeper.gatekeeper_network.as_ref()],
+    bump = gatekeeper.gatekeeper_bump,
+    constraint = gatekeeper.can_access(& authority, GatekeeperKeyFlags::SET_ADDRESSES) @ GatekeeperErrors::InsufficientAccessAuthKeys,
+    )]
+    pub gatekeeper: Account<'info, Gatekeeper>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
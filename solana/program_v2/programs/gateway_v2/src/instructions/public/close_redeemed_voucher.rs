@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::PassErrors;
+use crate::state::RedeemedVoucher;
+
+// Returns the rent of a redeemed voucher's record to its payer. Anyone can close it once the
+// voucher has expired, as an expired voucher can not be redeemed again.
+pub fn close_redeemed_voucher(ctx: Context<CloseRedeemedVoucherAccount>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp > ctx.accounts.redeemed_voucher.expiry,
+        PassErrors::VoucherNotExpired
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts, Debug)]
+pub struct CloseRedeemedVoucherAccount<'info> {
+    #[account(
+    mut,
+    close = payer,
+    has_one = payer,
+    )]
+    pub redeemed_voucher: Account<'info, RedeemedVoucher>,
+    /// CHECK: The rent receiver recorded on the redeemed voucher
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
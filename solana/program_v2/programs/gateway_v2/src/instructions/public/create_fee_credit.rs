@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{FEE_CREDIT_SEED, FEE_CREDIT_VAULT_SEED};
+use crate::errors::NetworkErrors;
+use crate::state::{FeeCredit, Gatekeeper, GatekeeperNetwork};
+use crate::util::OC_SIZE_DISCRIMINATOR;
+
+pub fn create_fee_credit(ctx: Context<CreateFeeCreditAccount>) -> Result<()> {
+    let fee_credit = &mut ctx.accounts.fee_credit;
+
+    fee_credit.version = 0;
+    fee_credit.funder = ctx.accounts.funder.key();
+    fee_credit.gatekeeper = ctx.accounts.gatekeeper.key();
+    fee_credit.network = ctx.accounts.network.key();
+    fee_credit.mint = ctx.accounts.mint.key();
+    fee_credit.vault = ctx.accounts.vault.key();
+    fee_credit.balance = 0;
+    fee_credit.gatekeeper_owed = 0;
+    fee_credit.network_owed = 0;
+    fee_credit.bump = *ctx.bumps.get("fee_credit").unwrap();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateFeeCreditAccount<'info> {
+    #[account(
+    init,
+    payer = payer,
+    space = OC_SIZE_DISCRIMINATOR + FeeCredit::INIT_SPACE,
+    seeds = [FEE_CREDIT_SEED, funder.key().as_ref(), gatekeeper.key().as_ref(), mint.key().as_ref()],
+    bump
+    )]
+    pub fee_credit: Box<Account<'info, FeeCredit>>,
+    #[account(
+    init,
+    payer = payer,
+    seeds = [FEE_CREDIT_VAULT_SEED, fee_credit.key().as_ref()],
+    bump,
+    token::mint = mint,
+    token::authority = fee_credit,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub funder: Signer<'info>,
+    #[account(constraint = gatekeeper.gatekeeper_network == network.key())]
+    pub gatekeeper: Box<Account<'info, Gatekeeper>>,
+    pub network: Box<Account<'info, GatekeeperNetwork>>,
+    #[account(constraint = network.is_token_supported(& mint.key()) @ NetworkErrors::TokenNotSupported)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
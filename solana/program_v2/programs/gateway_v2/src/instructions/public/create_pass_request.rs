@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{GATEKEEPER_SEED, PASS_REQUEST_ESCROW_SEED, PASS_REQUEST_SEED};
+use crate::errors::{GatekeeperErrors, NetworkErrors, PassErrors};
+use crate::state::{Gatekeeper, GatekeeperNetwork, GatekeeperState, PassRequest, PassRequestState};
+use crate::util::{
+    create_and_invoke_transfer, get_gatekeeper_fee, FeeOperation, OC_SIZE_DISCRIMINATOR,
+};
+
+// Asks a gatekeeper for a pass, escrowing the gatekeeper's current issue fee
+pub fn create_pass_request(ctx: Context<CreatePassRequestAccount>, deadline: i64) -> Result<()> {
+    require!(
+        deadline > Clock::get()?.unix_timestamp,
+        PassErrors::InvalidDeadline
+    );
+
+    let price_account = ctx
+        .accounts
+        .price_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let amount = get_gatekeeper_fee(
+        &ctx.accounts.gatekeeper,
+        &ctx.accounts.network,
+        &ctx.accounts.mint,
+        price_account.as_ref(),
+        FeeOperation::Issue,
+    )?;
+
+    let pass_request = &mut ctx.accounts.pass_request;
+    pass_request.version = 0;
+    pass_request.subject = ctx.accounts.subject.key();
+    pass_request.network = ctx.accounts.network.key();
+    pass_request.gatekeeper = ctx.accounts.gatekeeper.key();
+    pass_request.mint = ctx.accounts.mint.key();
+    pass_request.escrow = ctx.accounts.escrow.key();
+    pass_request.subject_token_account = ctx.accounts.subject_token_account.key();
+    pass_request.amount = amount;
+    pass_request.deadline = deadline;
+    pass_request.state = PassRequestState::Pending;
+    pass_request.bump = *ctx.bumps.get("pass_request").unwrap();
+
+    create_and_invoke_transfer(
+        ctx.accounts.token_program.to_owned(),
+        ctx.accounts.subject_token_account.to_owned(),
+        ctx.accounts.escrow.to_owned(),
+        ctx.accounts.mint.to_owned(),
+        ctx.accounts.subject.to_owned(),
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(deadline: i64)]
+pub struct CreatePassRequestAccount<'info> {
+    #[account(
+    init,
+    payer = subject,
+    space = OC_SIZE_DISCRIMINATOR + PassRequest::INIT_SPACE,
+    seeds = [PASS_REQUEST_SEED, subject.key().as_ref(), gatekeeper.key().as_ref()],
+    bump
+    )]
+    pub pass_request: Box<Account<'info, PassRequest>>,
+    #[account(
+    init,
+    payer = subject,
+    seeds = [PASS_REQUEST_ESCROW_SEED, pass_request.key().as_ref()],
+    bump,
+    token::mint = mint,
+    token::authority = pass_request,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub subject: Signer<'info>,
+    #[account(mut)]
+    pub subject_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub network: Box<Account<'info, GatekeeperNetwork>>,
+    #[account(
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), network.key().as_ref()],
+    bump = gatekeeper.gatekeeper_bump,
+    constraint = gatekeeper.gatekeeper_network == network.key(),
+    constraint = gatekeeper.gatekeeper_state == GatekeeperState::Active @ GatekeeperErrors::InvalidState,
+    constraint = gatekeeper.has_minimum_stake(& network.staking) @ GatekeeperErrors::InsufficientStake,
+    )]
+    pub gatekeeper: Box<Account<'info, Gatekeeper>>,
+    #[account(constraint = network.is_token_supported(& mint.key()) @ NetworkErrors::TokenNotSupported)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Only read for reference fees, and must be the network's price feed for the mint
+    pub price_account: Option<UncheckedAccount<'info>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
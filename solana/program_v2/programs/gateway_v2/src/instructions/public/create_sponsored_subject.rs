@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SPONSORED_SUBJECT_SEED;
+use crate::errors::SponsorshipErrors;
+use crate::state::{SponsoredSubject, Sponsorship};
+use crate::util::OC_SIZE_DISCRIMINATOR;
+
+// Opens the account tracking a subject's spend against a sponsorship. Only subjects approved by
+// the sponsor this way can have their fees paid, though anyone may pay the rent.
+pub fn create_sponsored_subject(
+    ctx: Context<CreateSponsoredSubjectAccount>,
+    subject: Pubkey,
+) -> Result<()> {
+    let sponsored_subject = &mut ctx.accounts.sponsored_subject;
+
+    sponsored_subject.version = 0;
+    sponsored_subject.sponsorship = ctx.accounts.sponsorship.key();
+    sponsored_subject.subject = subject;
+    sponsored_subject.spent = 0;
+    sponsored_subject.bump = *ctx.bumps.get("sponsored_subject").unwrap();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(subject: Pubkey)]
+pub struct CreateSponsoredSubjectAccount<'info> {
+    #[account(
+    init,
+    payer = payer,
+    space = OC_SIZE_DISCRIMINATOR + SponsoredSubject::INIT_SPACE,
+    seeds = [SPONSORED_SUBJECT_SEED, sponsorship.key().as_ref(), subject.as_ref()],
+    bump
+    )]
+    pub sponsored_subject: Box<Account<'info, SponsoredSubject>>,
+    #[account(has_one = sponsor @ SponsorshipErrors::InvalidSponsorship)]
+    pub sponsorship: Box<Account<'info, Sponsorship>>,
+    pub sponsor: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{SPONSORSHIP_SEED, SPONSORSHIP_VAULT_SEED};
+use crate::state::Sponsorship;
+
+pub fn create_sponsorship(
+    ctx: Context<CreateSponsorshipAccount>,
+    index: u16,
+    data: CreateSponsorshipData,
+) -> Result<()> {
+    let sponsorship = &mut ctx.accounts.sponsorship;
+
+    sponsorship.version = 0;
+    sponsorship.sponsor = ctx.accounts.sponsor.key();
+    sponsorship.index = index;
+    sponsorship.mint = ctx.accounts.mint.key();
+    sponsorship.vault = ctx.accounts.vault.key();
+    sponsorship.networks = data.networks;
+    sponsorship.gatekeepers = data.gatekeepers;
+    sponsorship.per_subject_cap = data.per_subject_cap;
+    sponsorship.expires_at = data.expires_at;
+    sponsorship.bump = *ctx.bumps.get("sponsorship").unwrap();
+
+    Ok(())
+}
+
+/// Data for [`CreateSponsorshipAccount`]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSponsorshipData {
+    /// The [`Sponsorship::networks`].
+    pub networks: Vec<Pubkey>,
+    /// The [`Sponsorship::gatekeepers`].
+    pub gatekeepers: Vec<Pubkey>,
+    /// The [`Sponsorship::per_subject_cap`].
+    pub per_subject_cap: u64,
+    /// The [`Sponsorship::expires_at`].
+    pub expires_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(index: u16, data: CreateSponsorshipData)]
+pub struct CreateSponsorshipAccount<'info> {
+    #[account(
+    init,
+    payer = payer,
+    space = Sponsorship::size(data.networks.len(), data.gatekeepers.len()),
+    seeds = [SPONSORSHIP_SEED, sponsor.key().as_ref(), & index.to_le_bytes()],
+    bump
+    )]
+    pub sponsorship: Box<Account<'info, Sponsorship>>,
+    #[account(
+    init,
+    payer = payer,
+    seeds = [SPONSORSHIP_VAULT_SEED, sponsorship.key().as_ref()],
+    bump,
+    token::mint = mint,
+    token::authority = sponsorship,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub sponsor: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::SponsorshipErrors;
+use crate::state::Sponsorship;
+use crate::util::create_and_invoke_transfer;
+
+// Deposits tokens into the sponsorship's vault. Anyone may top up a sponsorship.
+pub fn fund_sponsorship(ctx: Context<FundSponsorshipAccount>, amount: u64) -> Result<()> {
+    create_and_invoke_transfer(
+        ctx.accounts.spl_token_program.to_owned(),
+        ctx.accounts.funder_token_account.to_owned(),
+        ctx.accounts.vault.to_owned(),
+        ctx.accounts.mint.to_owned(),
+        ctx.accounts.funder.to_owned(),
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct FundSponsorshipAccount<'info> {
+    pub sponsorship: Box<Account<'info, Sponsorship>>,
+    #[account(
+    mut,
+    address = sponsorship.vault @ SponsorshipErrors::InvalidSponsorship,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = sponsorship.mint @ SponsorshipErrors::InvalidSponsorship)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub funder: Signer<'info>,
+    #[account(mut)]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+}
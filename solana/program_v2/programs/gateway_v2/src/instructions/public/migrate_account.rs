@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::MigrationErrors;
+use crate::state::{
+    Gatekeeper, GatekeeperNetwork, GatekeeperNetworkV0, GatekeeperV0, Pass, PassV0,
+};
+use crate::util::{resize_account, OC_SIZE_DISCRIMINATOR};
+
+// Rewrites a version 0 pass, network or gatekeeper in the current layout, growing it to fit.
+// Anyone can migrate an account, the payer covers any extra rent.
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+
+    let (migrated, size) = {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() > OC_SIZE_DISCRIMINATOR,
+            MigrationErrors::UnsupportedAccount
+        );
+        require!(
+            data[OC_SIZE_DISCRIMINATOR] == 0,
+            MigrationErrors::AlreadyMigrated
+        );
+
+        let (discriminator, mut state) = data.split_at(OC_SIZE_DISCRIMINATOR);
+        let mut migrated = vec![];
+        let size = if discriminator == Pass::discriminator() {
+            let pass = Pass::from(PassV0::deserialize(&mut state)?);
+            pass.try_serialize(&mut migrated)?;
+            Pass::size(0, 0)
+        } else if discriminator == GatekeeperNetwork::discriminator() {
+            let network = GatekeeperNetwork::from(GatekeeperNetworkV0::deserialize(&mut state)?);
+            network.try_serialize(&mut migrated)?;
+            GatekeeperNetwork::size(
+                network.fees.len(),
+                network.auth_keys.len(),
+                network.gatekeepers.len(),
+                network.supported_tokens.len(),
+                0,
+                0,
+            )
+        } else if discriminator == Gatekeeper::discriminator() {
+            let gatekeeper = Gatekeeper::from(GatekeeperV0::deserialize(&mut state)?);
+            gatekeeper.try_serialize(&mut migrated)?;
+            Gatekeeper::size(gatekeeper.token_fees.len(), gatekeeper.auth_keys.len())
+        } else {
+            return err!(MigrationErrors::UnsupportedAccount);
+        };
+
+        // Never shrink the account, so the payer can not collect rent it did not pay
+        (migrated, size.max(data.len()))
+    };
+
+    resize_account(
+        &account,
+        size,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    data[..migrated.len()].copy_from_slice(&migrated);
+    data[migrated.len()..].fill(0);
+
+    Ok(())
+}
+
+#[derive(Accounts, Debug)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: The layout is checked against the discriminator and version before migrating
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
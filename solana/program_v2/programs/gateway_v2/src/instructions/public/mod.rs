@@ -1,3 +1,29 @@
+pub use close_redeemed_voucher::*;
+pub use create_fee_credit::*;
+pub use create_pass_request::*;
+pub use create_sponsored_subject::*;
+pub use create_sponsorship::*;
+pub use fund_sponsorship::*;
+pub use migrate_account::*;
+pub use reclaim_pass_request::*;
+pub use redeem_pass_voucher::*;
+pub use settle_fee_credit::*;
+pub use top_up_fee_credit::*;
 pub use verify_pass::*;
+pub use withdraw_fee_credit::*;
+pub use withdraw_sponsorship::*;
 
+pub mod close_redeemed_voucher;
+pub mod create_fee_credit;
+pub mod create_pass_request;
+pub mod create_sponsored_subject;
+pub mod create_sponsorship;
+pub mod fund_sponsorship;
+pub mod migrate_account;
+pub mod reclaim_pass_request;
+pub mod redeem_pass_voucher;
+pub mod settle_fee_credit;
+pub mod top_up_fee_credit;
 pub mod verify_pass;
+pub mod withdraw_fee_credit;
+pub mod withdraw_sponsorship;
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::PASS_REQUEST_SEED;
+use crate::errors::PassErrors;
+use crate::state::PassRequest;
+use crate::util::close_pass_request_escrow;
+
+// Closes a fulfilled request, or one the gatekeeper did not act on before the deadline,
+// returning any escrowed fee to the subject
+pub fn reclaim_pass_request(ctx: Context<ReclaimPassRequestAccount>) -> Result<()> {
+    require!(
+        ctx.accounts
+            .pass_request
+            .can_reclaim(Clock::get()?.unix_timestamp),
+        PassErrors::PassRequestNotReclaimable
+    );
+
+    close_pass_request_escrow(
+        &ctx.accounts.pass_request,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.escrow,
+        &ctx.accounts.subject_token_account,
+        &ctx.accounts.subject.to_account_info(),
+        &ctx.accounts.mint,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReclaimPassRequestAccount<'info> {
+    #[account(
+    mut,
+    close = subject,
+    seeds = [PASS_REQUEST_SEED, subject.key().as_ref(), pass_request.gatekeeper.as_ref()],
+    bump = pass_request.bump,
+    )]
+    pub pass_request: Box<Account<'info, PassRequest>>,
+    #[account(mut)]
+    pub subject: Signer<'info>,
+    #[account(mut, address = pass_request.escrow @ PassErrors::InvalidPassRequest)]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = pass_request.subject_token_account @ PassErrors::InvalidPassRequest)]
+    pub subject_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = pass_request.mint @ PassErrors::InvalidPassRequest)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+}
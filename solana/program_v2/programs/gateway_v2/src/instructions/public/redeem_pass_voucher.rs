@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use solana_program::sysvar;
+
+use crate::constants::{GATEKEEPER_SEED, PASS_SEED, VOUCHER_SEED};
+use crate::errors::{GatekeeperErrors, NetworkErrors, PassErrors};
+use crate::state::{
+    Gatekeeper, GatekeeperKeyFlags, GatekeeperNetwork, GatekeeperState, Pass, PassIssueFee,
+    PassState, RedeemedVoucher, SponsoredSubject, Sponsorship,
+};
+use crate::util::{
+    calculate_network_and_gatekeeper_fee, draw_sponsored_fee, get_gatekeeper_fee, get_network_fees,
+    FeeOperation, FeePayer, OC_SIZE_DISCRIMINATOR,
+};
+use crate::voucher::PassVoucher;
+
+// Issues a pass from a voucher signed off-chain by one of the gatekeeper's ISSUE keys
+pub fn redeem_pass_voucher(ctx: Context<RedeemPassVoucher>, voucher: PassVoucher) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(now <= voucher.expiry, PassErrors::VoucherExpired);
+
+    let network = &ctx.accounts.network;
+    let gatekeeper = &ctx.accounts.gatekeeper;
+
+    let voucher_signer = voucher.load_signer(&ctx.accounts.instructions)?;
+    require!(
+        gatekeeper.key_has_flag(&voucher_signer, GatekeeperKeyFlags::ISSUE),
+        PassErrors::InvalidVoucherSigner
+    );
+
+    let spl_token_program = &ctx.accounts.spl_token_program;
+    let mint_account = &ctx.accounts.mint;
+    let mint_address = mint_account.key();
+
+    let price_account = ctx
+        .accounts
+        .price_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let absolute_fee = get_gatekeeper_fee(
+        gatekeeper,
+        network,
+        mint_account,
+        price_account.as_ref(),
+        FeeOperation::Issue,
+    )?;
+    let network_percentage = get_network_fees(&network.fees, mint_address, now)?.issue;
+    let (network_fee, gatekeeper_fee) =
+        calculate_network_and_gatekeeper_fee(absolute_fee, network_percentage);
+
+    draw_sponsored_fee(
+        &ctx.accounts.sponsorship,
+        &mut ctx.accounts.sponsored_subject,
+        &voucher.subject,
+        &network.key(),
+        &gatekeeper.key(),
+        absolute_fee,
+    )?;
+    let fee_payer = FeePayer::new(
+        &ctx.accounts.funder,
+        &ctx.accounts.funder_token_account,
+        &ctx.accounts.sponsorship,
+        &ctx.accounts.sponsorship_vault,
+    )?;
+
+    fee_payer.transfer(
+        spl_token_program,
+        &ctx.accounts.network_token_account,
+        mint_account,
+        network_fee,
+    )?;
+
+    fee_payer.transfer(
+        spl_token_program,
+        &ctx.accounts.gatekeeper_token_account,
+        mint_account,
+        gatekeeper_fee,
+    )?;
+
+    let pass = &mut ctx.accounts.pass;
+    pass.version = Pass::VERSION;
+    pass.signer_bump = *ctx.bumps.get("pass").unwrap();
+    pass.subject = voucher.subject;
+    pass.issue_time = now;
+    pass.network = network.key();
+    pass.gatekeeper = gatekeeper.key();
+    pass.state = PassState::Active;
+    pass.pass_number = voucher.pass_number;
+    pass.gatekeeper_data = voucher.data;
+    pass.issue_fee = (network.refund_grace_period > 0).then(|| PassIssueFee {
+        funder_token_account: fee_payer.token_account(),
+        mint: mint_address,
+        network_fee,
+        gatekeeper_fee,
+        paid_at: now,
+    });
+
+    let redeemed_voucher = &mut ctx.accounts.redeemed_voucher;
+    redeemed_voucher.version = 0;
+    redeemed_voucher.pass = pass.key();
+    redeemed_voucher.payer = ctx.accounts.payer.key();
+    redeemed_voucher.expiry = voucher.expiry;
+    redeemed_voucher.bump = *ctx.bumps.get("redeemed_voucher").unwrap();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(voucher: PassVoucher)]
+pub struct RedeemPassVoucher<'info> {
+    #[account(
+    init,
+    payer = payer,
+    space = Pass::size(0, 0),
+    seeds = [PASS_SEED, voucher.subject.as_ref(), network.key().as_ref(), & voucher.pass_number.to_le_bytes()],
+    bump
+    )]
+    pub pass: Box<Account<'info, Pass>>,
+    #[account(
+    init,
+    payer = payer,
+    space = OC_SIZE_DISCRIMINATOR + RedeemedVoucher::INIT_SPACE,
+    seeds = [VOUCHER_SEED, gatekeeper.key().as_ref(), & voucher.nonce.to_le_bytes()],
+    bump
+    )]
+    pub redeemed_voucher: Box<Account<'info, RedeemedVoucher>>,
+    #[account(
+    address = voucher.network @ PassErrors::InvalidNetwork,
+    constraint = gatekeeper.gatekeeper_network == network.key()
+    )]
+    pub network: Box<Account<'info, GatekeeperNetwork>>,
+    #[account(
+    address = voucher.gatekeeper @ PassErrors::InvalidGatekeeper,
+    seeds = [GATEKEEPER_SEED, gatekeeper.subject.as_ref(), network.key().as_ref()],
+    constraint = gatekeeper.gatekeeper_state == GatekeeperState::Active @ GatekeeperErrors::InvalidState,
+    constraint = gatekeeper.has_minimum_stake(& network.staking) @ GatekeeperErrors::InsufficientStake,
+    bump = gatekeeper.gatekeeper_bump
+    )]
+    pub gatekeeper: Box<Account<'info, Gatekeeper>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub funder: Option<Signer<'info>>,
+    pub system_program: Program<'info, System>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+    #[account(constraint = network.is_token_supported(& mint.key()) @ NetworkErrors::TokenNotSupported)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub funder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+    mut,
+    constraint = network_token_account.owner == * network.to_account_info().key,
+    )]
+    pub network_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+    mut,
+    constraint = gatekeeper_token_account.owner == * gatekeeper.to_account_info().key,
+    )]
+    pub gatekeeper_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Pays the fees in place of the funder
+    pub sponsorship: Option<Box<Account<'info, Sponsorship>>>,
+    #[account(mut)]
+    pub sponsorship_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub sponsored_subject: Option<Box<Account<'info, SponsoredSubject>>>,
+    /// CHECK: Only read for reference fees, and must be the network's price feed for the mint
+    pub price_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: The instructions sysvar, read to find the Ed25519 signature over the voucher
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
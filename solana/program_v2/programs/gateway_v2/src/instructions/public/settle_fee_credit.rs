@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::FeeCreditErrors;
+use crate::state::FeeCredit;
+use crate::util::invoke_fee_credit_transfer;
+
+// Pays out the fees charged against a fee credit since it was last settled. Anyone may settle.
+pub fn settle_fee_credit(ctx: Context<SettleFeeCreditAccount>) -> Result<()> {
+    let fee_credit = &mut ctx.accounts.fee_credit;
+    let (network_fee, gatekeeper_fee) = fee_credit.settle();
+
+    invoke_fee_credit_transfer(
+        fee_credit,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.network_token_account,
+        &ctx.accounts.mint,
+        network_fee,
+    )?;
+
+    invoke_fee_credit_transfer(
+        fee_credit,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.gatekeeper_token_account,
+        &ctx.accounts.mint,
+        gatekeeper_fee,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleFeeCreditAccount<'info> {
+    #[account(mut)]
+    pub fee_credit: Box<Account<'info, FeeCredit>>,
+    #[account(
+    mut,
+    address = fee_credit.vault @ FeeCreditErrors::InvalidFeeCredit,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = fee_credit.mint @ FeeCreditErrors::InvalidFeeCredit)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+    mut,
+    constraint = network_token_account.owner == fee_credit.network @ FeeCreditErrors::InvalidFeeCredit,
+    )]
+    pub network_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+    mut,
+    constraint = gatekeeper_token_account.owner == fee_credit.gatekeeper @ FeeCreditErrors::InvalidFeeCredit,
+    )]
+    pub gatekeeper_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+}
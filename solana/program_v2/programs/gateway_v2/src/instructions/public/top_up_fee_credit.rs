@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::FeeCreditErrors;
+use crate::state::FeeCredit;
+use crate::util::create_and_invoke_transfer;
+
+// Deposits tokens into the fee credit's vault and adds them to the funder's balance
+pub fn top_up_fee_credit(ctx: Context<TopUpFeeCreditAccount>, amount: u64) -> Result<()> {
+    let fee_credit = &mut ctx.accounts.fee_credit;
+
+    fee_credit.balance = fee_credit
+        .balance
+        .checked_add(amount)
+        .ok_or(FeeCreditErrors::InsufficientCredit)?;
+
+    create_and_invoke_transfer(
+        ctx.accounts.spl_token_program.to_owned(),
+        ctx.accounts.funder_token_account.to_owned(),
+        ctx.accounts.vault.to_owned(),
+        ctx.accounts.mint.to_owned(),
+        ctx.accounts.funder.to_owned(),
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct TopUpFeeCreditAccount<'info> {
+    #[account(mut)]
+    pub fee_credit: Box<Account<'info, FeeCredit>>,
+    #[account(
+    mut,
+    address = fee_credit.vault @ FeeCreditErrors::InvalidFeeCredit,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = fee_credit.mint @ FeeCreditErrors::InvalidFeeCredit)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub funder: Signer<'info>,
+    #[account(mut)]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+}
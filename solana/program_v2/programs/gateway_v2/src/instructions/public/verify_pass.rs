@@ -2,50 +2,99 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::{GATEKEEPER_SEED, PASS_SEED};
-use crate::errors::{GatekeeperErrors, NetworkErrors};
-use crate::state::{Gatekeeper, GatekeeperNetwork, GatekeeperState, Pass};
+use crate::errors::{FeeCreditErrors, GatekeeperErrors, NetworkErrors, SponsorshipErrors};
+use crate::state::{
+    FeeCredit, Gatekeeper, GatekeeperNetwork, GatekeeperState, Pass, SponsoredSubject, Sponsorship,
+};
 use crate::util::{
-    calculate_network_and_gatekeeper_fee, create_and_invoke_transfer, get_gatekeeper_fees,
-    get_network_fees,
+    calculate_network_gatekeeper_and_referrer_fee, draw_sponsored_fee, get_gatekeeper_fee,
+    get_network_fees, FeeOperation, FeePayer,
 };
 
 pub fn verify_pass(ctx: Context<PassVerify>) -> Result<()> {
     let pass = &mut ctx.accounts.pass;
     let network = &mut ctx.accounts.network;
     let gatekeeper = &mut ctx.accounts.gatekeeper;
-    let funder = &mut ctx.accounts.funder;
 
     let spl_token_program = &mut ctx.accounts.spl_token_program;
     let mint_account = &mut ctx.accounts.mint;
     let network_ata = &mut ctx.accounts.network_token_account;
     let gatekeeper_ata = &mut ctx.accounts.gatekeeper_token_account;
-    let funder_ata = &mut ctx.accounts.funder_token_account;
+    let referrer_ata = &mut ctx.accounts.referrer_token_account;
 
     let mint_address = mint_account.key();
 
     // TODO: Can we put the fee transfers into a trait and reuse dependent on the type of instruction?
-    let absolute_fee = get_gatekeeper_fees(&gatekeeper.token_fees, mint_address)?.verify;
-    let network_percentage = get_network_fees(&network.fees, mint_address)?.verify;
-    let (network_fee, gatekeeper_fee) =
-        calculate_network_and_gatekeeper_fee(absolute_fee, network_percentage);
-
-    create_and_invoke_transfer(
-        spl_token_program.to_owned(),
-        funder_ata.to_owned(),
-        network_ata.to_owned(),
-        mint_account.to_owned(),
-        funder.to_owned(),
-        network_fee,
+    let price_account = ctx
+        .accounts
+        .price_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let absolute_fee = get_gatekeeper_fee(
+        gatekeeper,
+        network,
+        mint_account,
+        price_account.as_ref(),
+        FeeOperation::Verify,
     )?;
+    let network_fees = get_network_fees(&network.fees, mint_address, Clock::get()?.unix_timestamp)?;
+    // The referrer's share stays with the gatekeeper when no referrer is provided
+    let referrer_percentage = referrer_ata
+        .as_ref()
+        .map_or(0, |_| network_fees.verify_referrer);
+    let (network_fee, gatekeeper_fee, referrer_fee) = calculate_network_gatekeeper_and_referrer_fee(
+        absolute_fee,
+        network_fees.verify,
+        referrer_percentage,
+    );
 
-    create_and_invoke_transfer(
-        spl_token_program.to_owned(),
-        funder_ata.to_owned(),
-        gatekeeper_ata.to_owned(),
-        mint_account.to_owned(),
-        funder.to_owned(),
-        gatekeeper_fee,
-    )?;
+    if let Some(fee_credit) = &mut ctx.accounts.fee_credit {
+        // Prepaid fees are only moved when the fee credit is settled
+        require!(
+            referrer_ata.is_none(),
+            FeeCreditErrors::ReferrerNotSupported
+        );
+        let funder = ctx
+            .accounts
+            .funder
+            .as_ref()
+            .ok_or(FeeCreditErrors::InvalidFeeCredit)?;
+        fee_credit.charge(
+            &funder.key(),
+            &gatekeeper.key(),
+            &mint_address,
+            network_fee,
+            gatekeeper_fee,
+        )?;
+    } else {
+        draw_sponsored_fee(
+            &ctx.accounts.sponsorship,
+            &mut ctx.accounts.sponsored_subject,
+            &pass.subject,
+            &network.key(),
+            &gatekeeper.key(),
+            absolute_fee,
+        )?;
+        let fee_payer = FeePayer::new(
+            &ctx.accounts.funder,
+            &ctx.accounts.funder_token_account,
+            &ctx.accounts.sponsorship,
+            &ctx.accounts.sponsorship_vault,
+        )?;
+
+        fee_payer.transfer(spl_token_program, network_ata, mint_account, network_fee)?;
+
+        fee_payer.transfer(
+            spl_token_program,
+            gatekeeper_ata,
+            mint_account,
+            gatekeeper_fee,
+        )?;
+
+        if let Some(referrer_ata) = referrer_ata {
+            fee_payer.transfer(spl_token_program, referrer_ata, mint_account, referrer_fee)?;
+        }
+    }
 
     pass.verify()
 }
@@ -72,13 +121,13 @@ pub struct PassVerify<'info> {
     pub gatekeeper: Box<Account<'info, Gatekeeper>>,
     #[account(mut)]
     pub payer: Signer<'info>,
-    pub funder: Signer<'info>,
+    pub funder: Option<Signer<'info>>,
     pub authority: Signer<'info>,
     pub spl_token_program: Interface<'info, TokenInterface>,
     #[account(constraint = network.is_token_supported(& mint.key()) @ NetworkErrors::TokenNotSupported)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
-    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub funder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     #[account(
     mut,
     constraint = network_token_account.owner == * network.to_account_info().key,
@@ -89,4 +138,24 @@ pub struct PassVerify<'info> {
     constraint = gatekeeper_token_account.owner == * gatekeeper.to_account_info().key,
     )]
     pub gatekeeper_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+    mut,
+    constraint = referrer_token_account.owner != pass.subject @ NetworkErrors::InvalidReferrer,
+    constraint = funder.as_ref().map_or(true, | funder | referrer_token_account.owner != funder.key()) @ NetworkErrors::InvalidReferrer,
+    )]
+    pub referrer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Pays the fees in place of the funder, if the pass subject or the sponsor verifies the pass
+    #[account(
+    constraint = authority.key() == pass.subject || authority.key() == sponsorship.sponsor @ SponsorshipErrors::UnauthorizedVerifier,
+    )]
+    pub sponsorship: Option<Box<Account<'info, Sponsorship>>>,
+    #[account(mut)]
+    pub sponsorship_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub sponsored_subject: Option<Box<Account<'info, SponsoredSubject>>>,
+    /// Pays the fees from prepaid credit, in place of the funder's token account
+    #[account(mut)]
+    pub fee_credit: Option<Box<Account<'info, FeeCredit>>>,
+    /// CHECK: Only read for reference fees, and must be the network's price feed for the mint
+    pub price_account: Option<UncheckedAccount<'info>>,
 }
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::FEE_CREDIT_SEED;
+use crate::errors::FeeCreditErrors;
+use crate::state::FeeCredit;
+use crate::util::invoke_fee_credit_transfer;
+
+// Returns unspent credit to the funder. Fees already charged stay in the vault until settled.
+pub fn withdraw_fee_credit(ctx: Context<WithdrawFeeCreditAccount>, amount: u64) -> Result<()> {
+    require!(amount > 0, FeeCreditErrors::InvalidAmount);
+
+    let fee_credit = &mut ctx.accounts.fee_credit;
+
+    fee_credit.balance = fee_credit
+        .balance
+        .checked_sub(amount)
+        .ok_or(FeeCreditErrors::InsufficientCredit)?;
+
+    invoke_fee_credit_transfer(
+        fee_credit,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.receiver_token_account,
+        &ctx.accounts.mint,
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawFeeCreditAccount<'info> {
+    #[account(
+    mut,
+    seeds = [FEE_CREDIT_SEED, funder.key().as_ref(), fee_credit.gatekeeper.as_ref(), fee_credit.mint.as_ref()],
+    bump = fee_credit.bump,
+    )]
+    pub fee_credit: Box<Account<'info, FeeCredit>>,
+    pub funder: Signer<'info>,
+    #[account(
+    mut,
+    address = fee_credit.vault @ FeeCreditErrors::InvalidFeeCredit,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = fee_credit.mint @ FeeCreditErrors::InvalidFeeCredit)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub receiver_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+}
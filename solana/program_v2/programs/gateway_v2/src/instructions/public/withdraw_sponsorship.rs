@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::SPONSORSHIP_SEED;
+use crate::errors::SponsorshipErrors;
+use crate::state::Sponsorship;
+use crate::util::invoke_sponsorship_transfer;
+
+// Returns unspent tokens from the sponsorship's vault to the sponsor
+pub fn withdraw_sponsorship(ctx: Context<WithdrawSponsorshipAccount>, amount: u64) -> Result<()> {
+    require!(amount > 0, SponsorshipErrors::InvalidAmount);
+
+    invoke_sponsorship_transfer(
+        &ctx.accounts.sponsorship,
+        &ctx.accounts.spl_token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.receiver_token_account,
+        &ctx.accounts.mint,
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawSponsorshipAccount<'info> {
+    #[account(
+    seeds = [SPONSORSHIP_SEED, sponsor.key().as_ref(), & sponsorship.index.to_le_bytes()],
+    bump = sponsorship.bump,
+    )]
+    pub sponsorship: Box<Account<'info, Sponsorship>>,
+    pub sponsor: Signer<'info>,
+    #[account(
+    mut,
+    address = sponsorship.vault @ SponsorshipErrors::InvalidSponsorship,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = sponsorship.mint @ SponsorshipErrors::InvalidSponsorship)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub receiver_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub spl_token_program: Interface<'info, TokenInterface>,
+}
@@ -8,13 +8,16 @@ use crate::instructions::admin::*;
 use crate::instructions::gatekeeper::*;
 use crate::instructions::network::*;
 use crate::instructions::public::*;
-use crate::state::{GatekeeperState, PassState};
+use crate::state::{GatekeeperState, PassDataSection, PassState};
+use crate::voucher::PassVoucher;
 
 pub mod constants;
 pub mod errors;
 mod instructions;
+pub mod oracle;
 pub mod state;
 pub mod util;
+pub mod voucher;
 
 declare_id!("gate2TBGydKNyMNUqz64s8bz4uaWS9PNreMbmAjb1Ft");
 
@@ -29,6 +32,14 @@ pub mod solana_anchor_gateway {
         instructions::admin::create_network(ctx, data)
     }
 
+    pub fn create_indexed_network(
+        ctx: Context<CreateIndexedNetworkAccount>,
+        network_index: u16,
+        data: CreateNetworkData,
+    ) -> Result<()> {
+        instructions::admin::create_indexed_network(ctx, network_index, data)
+    }
+
     pub fn update_network(
         ctx: Context<UpdateNetworkAccount>,
         data: UpdateNetworkData,
@@ -69,12 +80,53 @@ pub mod solana_anchor_gateway {
         instructions::network::gatekeeper_withdraw(ctx, amount)
     }
 
+    pub fn create_gatekeeper_addresses(
+        ctx: Context<CreateGatekeeperAddressesAccount>,
+        data: CreateGatekeeperAddressesData,
+    ) -> Result<()> {
+        instructions::network::create_gatekeeper_addresses(ctx, data)
+    }
+
+    pub fn update_gatekeeper_addresses(
+        ctx: Context<UpdateGatekeeperAddressesAccount>,
+        data: UpdateGatekeeperAddressesData,
+    ) -> Result<()> {
+        instructions::network::update_gatekeeper_addresses(ctx, data)
+    }
+
+    pub fn gatekeeper_stake(ctx: Context<GatekeeperStakeAccount>, amount: u64) -> Result<()> {
+        instructions::network::gatekeeper_stake(ctx, amount)
+    }
+
+    pub fn gatekeeper_unstake(ctx: Context<GatekeeperUnstakeAccount>, amount: u64) -> Result<()> {
+        instructions::network::gatekeeper_unstake(ctx, amount)
+    }
+
+    pub fn gatekeeper_withdraw_stake(ctx: Context<GatekeeperWithdrawStakeAccount>) -> Result<()> {
+        instructions::network::gatekeeper_withdraw_stake(ctx)
+    }
+
+    pub fn slash_gatekeeper(
+        ctx: Context<SlashGatekeeperAccount>,
+        data: SlashGatekeeperData,
+    ) -> Result<()> {
+        instructions::network::slash_gatekeeper(ctx, data)
+    }
+
     pub fn issue_pass(ctx: Context<IssuePass>, subject: Pubkey, pass_number: u16) -> Result<()> {
         instructions::gatekeeper::issue_pass(ctx, subject, pass_number)
     }
 
     pub fn set_pass_state(ctx: Context<PassSetState>, state: PassState) -> Result<()> {
-        instructions::gatekeeper::pass_set_state(&mut ctx.accounts.pass, state)
+        instructions::gatekeeper::pass_set_state(ctx, state)
+    }
+
+    pub fn refund_pass_fee(ctx: Context<RefundPassFee>) -> Result<()> {
+        instructions::gatekeeper::refund_pass_fee(ctx)
+    }
+
+    pub fn reject_pass_request(ctx: Context<RejectPassRequestAccount>) -> Result<()> {
+        instructions::gatekeeper::reject_pass_request(ctx)
     }
 
     pub fn refresh_pass(ctx: Context<PassRefresh>) -> Result<()> {
@@ -93,6 +145,14 @@ pub mod solana_anchor_gateway {
         instructions::gatekeeper::set_pass_data(ctx, gatekeeper_data, network_data)
     }
 
+    pub fn set_pass_extended_data(
+        ctx: Context<PassSetExtendedData>,
+        section: PassDataSection,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::gatekeeper::set_pass_extended_data(ctx, section, data)
+    }
+
     pub fn expire_pass(ctx: Context<PassExpire>) -> Result<()> {
         instructions::gatekeeper::expire_pass(ctx)
     }
@@ -100,4 +160,72 @@ pub mod solana_anchor_gateway {
     pub fn verify_pass(ctx: Context<PassVerify>) -> Result<()> {
         instructions::public::verify_pass(ctx)
     }
+
+    pub fn create_sponsorship(
+        ctx: Context<CreateSponsorshipAccount>,
+        index: u16,
+        data: CreateSponsorshipData,
+    ) -> Result<()> {
+        instructions::public::create_sponsorship(ctx, index, data)
+    }
+
+    pub fn fund_sponsorship(ctx: Context<FundSponsorshipAccount>, amount: u64) -> Result<()> {
+        instructions::public::fund_sponsorship(ctx, amount)
+    }
+
+    pub fn withdraw_sponsorship(
+        ctx: Context<WithdrawSponsorshipAccount>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::public::withdraw_sponsorship(ctx, amount)
+    }
+
+    pub fn create_sponsored_subject(
+        ctx: Context<CreateSponsoredSubjectAccount>,
+        subject: Pubkey,
+    ) -> Result<()> {
+        instructions::public::create_sponsored_subject(ctx, subject)
+    }
+
+    pub fn create_fee_credit(ctx: Context<CreateFeeCreditAccount>) -> Result<()> {
+        instructions::public::create_fee_credit(ctx)
+    }
+
+    pub fn top_up_fee_credit(ctx: Context<TopUpFeeCreditAccount>, amount: u64) -> Result<()> {
+        instructions::public::top_up_fee_credit(ctx, amount)
+    }
+
+    pub fn withdraw_fee_credit(ctx: Context<WithdrawFeeCreditAccount>, amount: u64) -> Result<()> {
+        instructions::public::withdraw_fee_credit(ctx, amount)
+    }
+
+    pub fn settle_fee_credit(ctx: Context<SettleFeeCreditAccount>) -> Result<()> {
+        instructions::public::settle_fee_credit(ctx)
+    }
+
+    pub fn create_pass_request(
+        ctx: Context<CreatePassRequestAccount>,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::public::create_pass_request(ctx, deadline)
+    }
+
+    pub fn reclaim_pass_request(ctx: Context<ReclaimPassRequestAccount>) -> Result<()> {
+        instructions::public::reclaim_pass_request(ctx)
+    }
+
+    pub fn redeem_pass_voucher(
+        ctx: Context<RedeemPassVoucher>,
+        voucher: PassVoucher,
+    ) -> Result<()> {
+        instructions::public::redeem_pass_voucher(ctx, voucher)
+    }
+
+    pub fn close_redeemed_voucher(ctx: Context<CloseRedeemedVoucherAccount>) -> Result<()> {
+        instructions::public::close_redeemed_voucher(ctx)
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::public::migrate_account(ctx)
+    }
 }
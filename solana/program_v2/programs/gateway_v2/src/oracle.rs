@@ -0,0 +1,107 @@
+//! Validated reads of Pyth price accounts, used to convert reference currency fees.
+use anchor_lang::prelude::*;
+use pyth_price::OracleError;
+pub use pyth_price::{OracleConfig, OraclePrice};
+
+use crate::errors::NetworkErrors;
+use crate::state::NetworkPriceFeed;
+
+/// The number of decimals used by amounts in [`crate::state::ReferenceFees`], e.g. micro-USD
+pub const REFERENCE_CURRENCY_DECIMALS: u32 = 6;
+
+impl From<&NetworkPriceFeed> for OracleConfig {
+    fn from(feed: &NetworkPriceFeed) -> Self {
+        OracleConfig {
+            max_slot_age: feed.max_slot_age,
+            max_confidence_ratio: feed.max_confidence_ratio,
+        }
+    }
+}
+
+/// Reads the aggregate price from Pyth price account data, rejecting prices that are not
+/// trading, older than the feed's `max_slot_age` or wider than its `max_confidence_ratio`
+pub fn load_price(data: &[u8], feed: &NetworkPriceFeed, current_slot: u64) -> Result<OraclePrice> {
+    OraclePrice::parse(data, &feed.into(), current_slot).map_err(|error| match error {
+        OracleError::InvalidProductAccount | OracleError::InvalidPriceAccount => {
+            error!(NetworkErrors::InvalidPriceFeed)
+        }
+        OracleError::PriceNotTrading => error!(NetworkErrors::PriceNotTrading),
+        OracleError::StalePrice => error!(NetworkErrors::StalePrice),
+        OracleError::ConfidenceTooWide => error!(NetworkErrors::PriceConfidenceTooWide),
+        OracleError::InvalidPrice => error!(NetworkErrors::InvalidPrice),
+    })
+}
+
+/// Converts an amount of reference currency into base units of a mint at `price`, rounding up
+pub fn convert_reference_amount(
+    price: &OraclePrice,
+    amount: u64,
+    mint_decimals: u8,
+) -> Result<u64> {
+    let pow10 = |exp: u32| 10u128.checked_pow(exp);
+
+    let (numerator, denominator) = if price.exponent <= 0 {
+        (
+            pow10(mint_decimals as u32 + price.exponent.unsigned_abs())
+                .and_then(|scale| scale.checked_mul(amount as u128)),
+            pow10(REFERENCE_CURRENCY_DECIMALS)
+                .and_then(|scale| scale.checked_mul(price.price as u128)),
+        )
+    } else {
+        (
+            pow10(mint_decimals as u32).and_then(|scale| scale.checked_mul(amount as u128)),
+            pow10(REFERENCE_CURRENCY_DECIMALS + price.exponent as u32)
+                .and_then(|scale| scale.checked_mul(price.price as u128)),
+        )
+    };
+
+    let (numerator, denominator) = numerator
+        .zip(denominator)
+        .ok_or(NetworkErrors::InvalidPrice)?;
+
+    u64::try_from((numerator + denominator - 1) / denominator)
+        .map_err(|_| error!(NetworkErrors::InvalidPrice))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_price_maps_errors() {
+        let feed = make_feed();
+
+        assert_eq!(
+            load_price(&[0; 64], &feed, 100),
+            Err(error!(NetworkErrors::InvalidPriceFeed))
+        );
+    }
+
+    #[test]
+    fn test_convert_reference_amount() {
+        // 20.00 reference units per token with 8 decimal exponent
+        let price = OraclePrice {
+            price: 2_000_000_000,
+            confidence: 0,
+            exponent: -8,
+            publish_slot: 0,
+        };
+
+        // 5.00 reference units buys 0.25 tokens
+        assert_eq!(
+            convert_reference_amount(&price, 5_000_000, 9),
+            Ok(250_000_000)
+        );
+        // Rounds up to the next base unit
+        assert_eq!(convert_reference_amount(&price, 1, 0), Ok(1));
+    }
+
+    fn make_feed() -> NetworkPriceFeed {
+        NetworkPriceFeed {
+            token: Pubkey::new_unique(),
+            price_account: Pubkey::new_unique(),
+            max_slot_age: 25,
+            max_confidence_ratio: 100,
+        }
+    }
+}
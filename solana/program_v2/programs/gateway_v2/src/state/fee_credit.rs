@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::FeeCreditErrors;
+
+/// Tokens prepaid by a funder for a single gatekeeper's fees. Operations debit the balance
+/// without a token transfer, and the gatekeeper's and network's shares are paid out in bulk
+/// by `settle_fee_credit`.
+#[derive(Debug, InitSpace)]
+#[account]
+pub struct FeeCredit {
+    /// The version of this struct, should be 0 until a new version is released
+    pub version: u8,
+    /// The funder that prepaid the credit, and the only key that can spend or withdraw it
+    pub funder: Pubkey,
+    /// The gatekeeper whose fees the credit pays
+    pub gatekeeper: Pubkey,
+    /// The network of the gatekeeper
+    pub network: Pubkey,
+    /// The mint of the prepaid tokens
+    pub mint: Pubkey,
+    /// The token account holding the prepaid tokens, owned by this account
+    pub vault: Pubkey,
+    /// The tokens still available to the funder
+    pub balance: u64,
+    /// Fees owed to the gatekeeper that have not yet been settled
+    pub gatekeeper_owed: u64,
+    /// Fees owed to the network that have not yet been settled
+    pub network_owed: u64,
+    /// The bump for the signer of this account
+    pub bump: u8,
+}
+
+impl FeeCredit {
+    /// Moves a fee out of the funder's balance and into the amounts owed
+    pub fn charge(
+        &mut self,
+        funder: &Pubkey,
+        gatekeeper: &Pubkey,
+        mint: &Pubkey,
+        network_fee: u64,
+        gatekeeper_fee: u64,
+    ) -> Result<()> {
+        require!(
+            self.funder == *funder && self.gatekeeper == *gatekeeper && self.mint == *mint,
+            FeeCreditErrors::InvalidFeeCredit
+        );
+
+        let total = network_fee
+            .checked_add(gatekeeper_fee)
+            .ok_or(FeeCreditErrors::InsufficientCredit)?;
+        self.balance = self
+            .balance
+            .checked_sub(total)
+            .ok_or(FeeCreditErrors::InsufficientCredit)?;
+        self.network_owed = self
+            .network_owed
+            .checked_add(network_fee)
+            .ok_or(FeeCreditErrors::InsufficientCredit)?;
+        self.gatekeeper_owed = self
+            .gatekeeper_owed
+            .checked_add(gatekeeper_fee)
+            .ok_or(FeeCreditErrors::InsufficientCredit)?;
+
+        Ok(())
+    }
+
+    /// Clears the amounts owed, returning the network's and gatekeeper's shares
+    pub fn settle(&mut self) -> (u64, u64) {
+        let owed = (self.network_owed, self.gatekeeper_owed);
+        self.network_owed = 0;
+        self.gatekeeper_owed = 0;
+
+        owed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_fee_credit(balance: u64) -> FeeCredit {
+        FeeCredit {
+            version: 0,
+            funder: Pubkey::new_unique(),
+            gatekeeper: Pubkey::new_unique(),
+            network: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            balance,
+            gatekeeper_owed: 0,
+            network_owed: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_charge_and_settle() {
+        let mut fee_credit = make_fee_credit(100);
+        let (funder, gatekeeper, mint) =
+            (fee_credit.funder, fee_credit.gatekeeper, fee_credit.mint);
+
+        fee_credit
+            .charge(&funder, &gatekeeper, &mint, 10, 40)
+            .unwrap();
+        fee_credit
+            .charge(&funder, &gatekeeper, &mint, 10, 40)
+            .unwrap();
+        assert_eq!(fee_credit.balance, 0);
+
+        assert_eq!(
+            fee_credit.charge(&funder, &gatekeeper, &mint, 0, 1),
+            Err(error!(FeeCreditErrors::InsufficientCredit))
+        );
+
+        assert_eq!(fee_credit.settle(), (20, 80));
+        assert_eq!(fee_credit.settle(), (0, 0));
+    }
+
+    #[test]
+    fn test_charge_checks_accounts() {
+        let mut fee_credit = make_fee_credit(100);
+        let (funder, gatekeeper, mint) =
+            (fee_credit.funder, fee_credit.gatekeeper, fee_credit.mint);
+        let other = Pubkey::new_unique();
+
+        assert!(fee_credit.charge(&other, &gatekeeper, &mint, 1, 1).is_err());
+        assert!(fee_credit.charge(&funder, &other, &mint, 1, 1).is_err());
+        assert!(fee_credit
+            .charge(&funder, &gatekeeper, &other, 1, 1)
+            .is_err());
+        assert_eq!(fee_credit.balance, 100);
+    }
+}
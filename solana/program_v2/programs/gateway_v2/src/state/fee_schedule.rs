@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{GatekeeperFees, NetworkFeesPercentage};
+
+/// Identifies a single entry in a fee schedule
+#[derive(Clone, Copy, Debug, Eq, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct FeeScheduleKey {
+    /// The token of the entry
+    pub token: Pubkey,
+    /// The time the entry takes effect
+    pub effective_from: i64,
+}
+
+/// A fee entry that takes effect at a time, see [`GatekeeperFees::effective_from`]
+pub trait ScheduledFee {
+    fn schedule_key(&self) -> FeeScheduleKey;
+}
+
+impl ScheduledFee for GatekeeperFees {
+    fn schedule_key(&self) -> FeeScheduleKey {
+        FeeScheduleKey {
+            token: self.token,
+            effective_from: self.effective_from,
+        }
+    }
+}
+
+impl ScheduledFee for NetworkFeesPercentage {
+    fn schedule_key(&self) -> FeeScheduleKey {
+        FeeScheduleKey {
+            token: self.token,
+            effective_from: self.effective_from,
+        }
+    }
+}
+
+/// Checks that fees added for a token that is already charged are announced at least
+/// `notice_period` seconds ahead of taking effect. Fees for new tokens can take effect at once.
+pub fn has_fee_notice<T: ScheduledFee>(
+    existing: &[T],
+    added: &[T],
+    notice_period: i64,
+    now: i64,
+) -> bool {
+    let earliest = now.saturating_add(notice_period);
+
+    added.iter().all(|fee| {
+        let key = fee.schedule_key();
+        key.effective_from >= earliest
+            || !existing
+                .iter()
+                .any(|existing| existing.schedule_key().token == key.token)
+    })
+}
+
+/// Removes the entries that can no longer be charged, as a later entry for the same token has
+/// taken effect by `now`
+pub fn prune_fee_schedule<T: ScheduledFee>(fees: &mut Vec<T>, now: i64) {
+    let current: Vec<FeeScheduleKey> = fees
+        .iter()
+        .map(ScheduledFee::schedule_key)
+        .filter(|key| key.effective_from <= now)
+        .collect();
+
+    fees.retain(|fee| {
+        let key = fee.schedule_key();
+        !current
+            .iter()
+            .any(|other| other.token == key.token && other.effective_from > key.effective_from)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_fees(token: Pubkey, effective_from: i64) -> GatekeeperFees {
+        GatekeeperFees {
+            token,
+            issue: 100,
+            refresh: 100,
+            expire: 100,
+            verify: 100,
+            effective_from,
+        }
+    }
+
+    #[test]
+    fn test_has_fee_notice() {
+        let token = Pubkey::new_unique();
+        let existing = [make_fees(token, 0)];
+
+        assert!(has_fee_notice(&existing, &[make_fees(token, 160)], 60, 100));
+        assert!(!has_fee_notice(
+            &existing,
+            &[make_fees(token, 159)],
+            60,
+            100
+        ));
+        // A token without fees can be priced at once
+        assert!(has_fee_notice(
+            &existing,
+            &[make_fees(Pubkey::new_unique(), 0)],
+            60,
+            100
+        ));
+    }
+
+    #[test]
+    fn test_prune_fee_schedule() {
+        let token = Pubkey::new_unique();
+        let other_token = Pubkey::new_unique();
+        let mut fees = vec![
+            make_fees(token, 0),
+            make_fees(token, 50),
+            make_fees(token, 200),
+            make_fees(other_token, 0),
+        ];
+
+        prune_fee_schedule(&mut fees, 100);
+
+        assert_eq!(
+            fees,
+            vec![
+                make_fees(token, 50),
+                make_fees(token, 200),
+                make_fees(other_token, 0),
+            ]
+        );
+    }
+}
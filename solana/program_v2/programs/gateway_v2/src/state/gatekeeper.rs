@@ -7,14 +7,14 @@ use crate::instructions::network::{
     UpdateGatekeeperData, UpdateGatekeeperFees, UpdateGatekeeperKeys,
 };
 use crate::state::operations::UpdateOperations;
-use crate::state::{PassState, UpdateOperands};
+use crate::state::{FeeScheduleKey, NetworkStaking, PassState, ScheduledFee, UpdateOperands};
 use crate::util::*;
 
 /// A gatekeeper on a [`GatekeeperNetwork`] that can issue passes
 #[derive(Debug)]
 #[account]
 pub struct Gatekeeper {
-    /// The version of this struct, should be 0 until a new version is released
+    /// The version of this struct. Older accounts must be migrated to [`Self::VERSION`].
     pub version: u8,
     /// the authority for this gatekeeper
     pub subject: Pubkey,
@@ -22,7 +22,7 @@ pub struct Gatekeeper {
     pub gatekeeper_bump: u8,
     /// The [`GatekeeperNetwork`] this gatekeeper is on
     pub gatekeeper_network: Pubkey,
-    /// The staking account of this gatekeeper
+    /// The vault holding this gatekeeper's stake, default until tokens are first staked
     pub staking_account: Pubkey,
     /// The state of this gatekeeper
     pub gatekeeper_state: GatekeeperState,
@@ -32,6 +32,13 @@ pub struct Gatekeeper {
     pub auth_threshold: u8,
     /// The keys with permissions on this gatekeeper
     pub auth_keys: Vec<GatekeeperAuthKey>,
+    /// The [`GatekeeperAddresses`] account used for discoverability, default if not set
+    pub addresses: Pubkey,
+    /// The tokens this gatekeeper has staked in the `staking_account`
+    pub stake: GatekeeperStake,
+    /// Fees in the network's reference currency, charged for tokens without an entry in
+    /// `token_fees` using the network's price feed for the token
+    pub reference_fees: Option<ReferenceFees>,
 }
 
 #[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize, Copy, PartialEq, InitSpace)]
@@ -43,6 +50,9 @@ pub struct GatekeeperAuthKey {
 }
 
 impl Gatekeeper {
+    /// The current version of the account layout
+    pub const VERSION: u8 = 1;
+
     pub fn size(token_fees_count: usize, auth_keys_count: usize) -> usize {
         OC_SIZE_DISCRIMINATOR
             + OC_SIZE_U8 // version
@@ -51,19 +61,26 @@ impl Gatekeeper {
             + OC_SIZE_PUBKEY // gatekeeper_network
             + OC_SIZE_PUBKEY // staking account
             + GatekeeperState::INIT_SPACE // gatekeeper state
+            + OC_SIZE_U8 + ReferenceFees::INIT_SPACE // reference_fees
             + OC_SIZE_VEC_PREFIX + GatekeeperFees::INIT_SPACE * token_fees_count // fees
             + OC_SIZE_U8 // auth_threshold
-            + OC_SIZE_VEC_PREFIX + GatekeeperAuthKey::INIT_SPACE * auth_keys_count
-        // auth keys
+            + OC_SIZE_VEC_PREFIX + GatekeeperAuthKey::INIT_SPACE * auth_keys_count // auth keys
+            + OC_SIZE_PUBKEY // addresses
+            + GatekeeperStake::INIT_SPACE // stake
     }
 
     // Checks if an authkey has enough authority for an action
     pub fn can_access(&self, authority: &Signer, flag: GatekeeperKeyFlags) -> bool {
+        self.key_has_flag(authority.key, flag)
+    }
+
+    /// Checks for a key with `flag` that did not sign the transaction, such as a voucher signer
+    pub fn key_has_flag(&self, authority: &Pubkey, flag: GatekeeperKeyFlags) -> bool {
         self.auth_keys
             .iter()
             .filter(|key| {
                 GatekeeperKeyFlags::from_bits_truncate(key.flags).contains(flag)
-                    && *authority.key == key.key
+                    && *authority == key.key
             })
             .count()
             > 0
@@ -101,11 +118,91 @@ impl Gatekeeper {
         }
     }
 
-    // sets the staking account for the gatekeeper
-    pub fn set_staking_account(&mut self, staking_account: &mut UncheckedAccount) -> Result<()> {
-        if staking_account.key() != self.staking_account {
-            self.staking_account = staking_account.key();
+    /// Checks if this gatekeeper has staked enough to meet the network's requirements
+    pub fn has_minimum_stake(&self, staking: &NetworkStaking) -> bool {
+        staking.minimum_stake == 0
+            || (self.stake.mint == staking.mint && self.stake.amount >= staking.minimum_stake)
+    }
+
+    // Records tokens that have been transferred into the stake vault
+    pub fn add_stake(&mut self, stake_vault: Pubkey, mint: Pubkey, amount: u64) -> Result<()> {
+        if self.staking_account != stake_vault {
+            // Stake can only be held in one mint at a time
+            require!(
+                self.stake.amount == 0 && self.stake.unbonding_amount == 0,
+                GatekeeperErrors::StakeMintMismatch
+            );
+
+            self.staking_account = stake_vault;
+            self.stake.mint = mint;
         }
+
+        self.stake.amount = self
+            .stake
+            .amount
+            .checked_add(amount)
+            .ok_or(GatekeeperErrors::StakeOverflow)?;
+
+        Ok(())
+    }
+
+    // Moves stake into unbonding. Unbonding stake is held as a single tranche, so this restarts
+    // the unbonding period for the whole `unbonding_amount`, including tokens unstaked earlier.
+    // Gatekeepers that want earlier tokens back first should withdraw them before unstaking more.
+    pub fn unstake(&mut self, amount: u64, unbonding_period: i64, now: i64) -> Result<()> {
+        require!(
+            amount <= self.stake.amount,
+            GatekeeperErrors::InsufficientStakeToUnstake
+        );
+
+        let unbonding_amount = self
+            .stake
+            .unbonding_amount
+            .checked_add(amount)
+            .ok_or(GatekeeperErrors::StakeOverflow)?;
+        let unbonding_end_time = now
+            .checked_add(unbonding_period)
+            .ok_or(GatekeeperErrors::StakeOverflow)?;
+
+        self.stake.amount -= amount;
+        self.stake.unbonding_amount = unbonding_amount;
+        self.stake.unbonding_end_time = unbonding_end_time;
+
+        Ok(())
+    }
+
+    // Releases all unbonded stake, returning the amount that can be withdrawn from the vault
+    pub fn release_unbonded_stake(&mut self, now: i64) -> Result<u64> {
+        require!(
+            now >= self.stake.unbonding_end_time,
+            GatekeeperErrors::UnbondingPeriodNotEnded
+        );
+
+        let amount = self.stake.unbonding_amount;
+        self.stake.unbonding_amount = 0;
+
+        Ok(amount)
+    }
+
+    // Removes slashed tokens from the stake, taking from unbonding stake once the staked amount
+    // is exhausted
+    pub fn slash(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, GatekeeperErrors::InvalidSlashAmount);
+
+        let total_stake = self
+            .stake
+            .amount
+            .checked_add(self.stake.unbonding_amount)
+            .ok_or(GatekeeperErrors::StakeOverflow)?;
+        require!(
+            amount <= total_stake,
+            GatekeeperErrors::InsufficientStakeToSlash
+        );
+
+        let from_stake = amount.min(self.stake.amount);
+        self.stake.amount -= from_stake;
+        self.stake.unbonding_amount -= amount - from_stake;
+
         Ok(())
     }
 
@@ -168,23 +265,23 @@ impl UpdateOperations<UpdateGatekeeperKeys, GatekeeperAuthKey> for Gatekeeper {
     }
 }
 
-impl UpdateOperations<UpdateGatekeeperFees, GatekeeperFees> for Gatekeeper {
+impl UpdateOperations<UpdateGatekeeperFees, GatekeeperFees, FeeScheduleKey> for Gatekeeper {
     fn operands(
         this: &mut Self,
         operation: UpdateGatekeeperFees,
-    ) -> UpdateOperands<GatekeeperFees> {
+    ) -> UpdateOperands<GatekeeperFees, FeeScheduleKey> {
         UpdateOperands::new(&mut this.token_fees, operation.remove, operation.add)
     }
 
-    fn extract_key(container: &GatekeeperFees) -> Pubkey {
-        container.token
+    fn extract_key(container: &GatekeeperFees) -> FeeScheduleKey {
+        container.schedule_key()
     }
 
     fn missing_key_error() -> Error {
         error!(GatekeeperErrors::InsufficientAuthKeys)
     }
 
-    fn pre_remove_validation(_: &Pubkey, _: &Signer) -> Result<()> {
+    fn pre_remove_validation(_: &FeeScheduleKey, _: &Signer) -> Result<()> {
         Ok(())
     }
 
@@ -193,6 +290,21 @@ impl UpdateOperations<UpdateGatekeeperFees, GatekeeperFees> for Gatekeeper {
     }
 }
 
+/// Tokens a [`Gatekeeper`] has staked on its [`GatekeeperNetwork`]
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, Copy, AnchorSerialize, AnchorDeserialize, InitSpace,
+)]
+pub struct GatekeeperStake {
+    /// The mint of the staked tokens
+    pub mint: Pubkey,
+    /// The staked amount that counts towards the network's minimum stake
+    pub amount: u64,
+    /// Unstaked tokens that are still held in the vault until `unbonding_end_time`
+    pub unbonding_amount: u64,
+    /// The time after which the `unbonding_amount` can be withdrawn, restarted by every unstake
+    pub unbonding_end_time: i64,
+}
+
 /// The state of a [`Gatekeeper`]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
 pub enum GatekeeperState {
@@ -237,6 +349,47 @@ pub struct GatekeeperFees {
     /// The fee taken when a pass is verified in token units or lamports for SOL.
     /// This should only be used where pass value comes from proper use
     pub verify: u64,
+    /// The time these fees take effect. Entries for the same token with different times
+    /// form a schedule, and the latest entry that has taken effect is charged.
+    pub effective_from: i64,
+}
+
+impl GatekeeperFees {
+    pub fn fee(&self, operation: FeeOperation) -> u64 {
+        match operation {
+            FeeOperation::Issue => self.issue,
+            FeeOperation::Refresh => self.refresh,
+            FeeOperation::Expire => self.expire,
+            FeeOperation::Verify => self.verify,
+        }
+    }
+}
+
+/// Gatekeeper fees denominated in the reference currency of the network's price feeds,
+/// with [`crate::oracle::REFERENCE_CURRENCY_DECIMALS`] decimals
+#[derive(
+    Debug, Default, Clone, Eq, PartialEq, AnchorSerialize, AnchorDeserialize, Copy, InitSpace,
+)]
+pub struct ReferenceFees {
+    /// Fees taken at issuance of a new pass
+    pub issue: u64,
+    /// Fees taken when a pass is refreshed
+    pub refresh: u64,
+    /// The fee taken when a pass is expired
+    pub expire: u64,
+    /// The fee taken when a pass is verified
+    pub verify: u64,
+}
+
+impl ReferenceFees {
+    pub fn fee(&self, operation: FeeOperation) -> u64 {
+        match operation {
+            FeeOperation::Issue => self.issue,
+            FeeOperation::Refresh => self.refresh,
+            FeeOperation::Expire => self.expire,
+            FeeOperation::Verify => self.verify,
+        }
+    }
 }
 
 bitflags! {
@@ -277,6 +430,8 @@ bitflags! {
          const EXPIRE_PASS = 1 << 15;
          /// Key can withdraw fees from the gatekeeper
          const WITHDRAW = 1 << 16;
+         /// Key can unstake and withdraw the gatekeeper's stake
+         const UNSTAKE = 1 << 17;
      }
 }
 
@@ -422,6 +577,7 @@ mod tests {
                 refresh: 0,
                 expire: 0,
                 verify: 0,
+                effective_from: 0,
             };
             let update_fees = UpdateGatekeeperFees {
                 add: vec![expected_fees],
@@ -434,7 +590,7 @@ mod tests {
 
             let update_fees = UpdateGatekeeperFees {
                 add: vec![],
-                remove: vec![token],
+                remove: vec![expected_fees.schedule_key()],
             };
 
             // Assert
@@ -444,25 +600,153 @@ mod tests {
     }
 
     #[test]
-    fn test_set_staking_account() {
+    fn test_scheduled_fees_are_kept_alongside_current_fees() {
         with_signer(|authority| {
-            // Assemble
             let mut gatekeeper = make_gatekeeper(
                 Some(authority.clone()),
                 Some(authority.key()),
                 GatekeeperKeyFlags::AUTH,
             );
-            let account_info = authority.to_account_info();
-            let staking_account = &mut UncheckedAccount::try_from(account_info);
+            let token = Pubkey::new_unique();
+            let current_fees = GatekeeperFees {
+                token,
+                issue: 100,
+                refresh: 0,
+                expire: 0,
+                verify: 0,
+                effective_from: 0,
+            };
+            let scheduled_fees = GatekeeperFees {
+                issue: 200,
+                effective_from: 1000,
+                ..current_fees
+            };
+            let rescheduled_fees = GatekeeperFees {
+                issue: 150,
+                ..scheduled_fees
+            };
 
-            // Act
-            gatekeeper.set_staking_account(staking_account).unwrap();
+            let update_fees = UpdateGatekeeperFees {
+                add: vec![current_fees, scheduled_fees, rescheduled_fees],
+                remove: vec![],
+            };
+            assert_eq!(gatekeeper.apply_update(update_fees, &authority), Ok(()));
+            assert_eq!(gatekeeper.token_fees, vec![current_fees, rescheduled_fees]);
 
-            // Assert
-            assert_eq!(gatekeeper.staking_account, *staking_account.key);
+            // Removing the scheduled entry keeps the current fees
+            let update_fees = UpdateGatekeeperFees {
+                add: vec![],
+                remove: vec![rescheduled_fees.schedule_key()],
+            };
+            assert_eq!(gatekeeper.apply_update(update_fees, &authority), Ok(()));
+            assert_eq!(gatekeeper.token_fees, vec![current_fees]);
         });
     }
 
+    #[test]
+    fn test_has_minimum_stake() {
+        let mut gatekeeper = make_gatekeeper(None, None, GatekeeperKeyFlags::AUTH);
+        let mint = Pubkey::new_unique();
+        let staking = NetworkStaking {
+            mint,
+            minimum_stake: 100,
+            unbonding_period: 0,
+        };
+
+        assert!(gatekeeper.has_minimum_stake(&NetworkStaking::default()));
+        assert!(!gatekeeper.has_minimum_stake(&staking));
+
+        gatekeeper
+            .add_stake(Pubkey::new_unique(), mint, 100)
+            .unwrap();
+        assert!(gatekeeper.has_minimum_stake(&staking));
+
+        // Stake in a different mint does not count
+        assert!(!gatekeeper.has_minimum_stake(&NetworkStaking {
+            mint: Pubkey::new_unique(),
+            ..staking
+        }));
+    }
+
+    #[test]
+    fn test_add_stake_to_different_vault() {
+        let mut gatekeeper = make_gatekeeper(None, None, GatekeeperKeyFlags::AUTH);
+        gatekeeper
+            .add_stake(Pubkey::new_unique(), Pubkey::new_unique(), 100)
+            .unwrap();
+
+        let result = gatekeeper.add_stake(Pubkey::new_unique(), Pubkey::new_unique(), 100);
+
+        assert_eq!(result, Err(error!(GatekeeperErrors::StakeMintMismatch)));
+    }
+
+    #[test]
+    fn test_unstake_and_release() {
+        let mut gatekeeper = make_gatekeeper(None, None, GatekeeperKeyFlags::AUTH);
+        gatekeeper
+            .add_stake(Pubkey::new_unique(), Pubkey::new_unique(), 100)
+            .unwrap();
+
+        assert_eq!(
+            gatekeeper.unstake(101, 60, 1000),
+            Err(error!(GatekeeperErrors::InsufficientStakeToUnstake))
+        );
+
+        gatekeeper.unstake(40, 60, 1000).unwrap();
+        assert_eq!(gatekeeper.stake.amount, 60);
+        assert_eq!(gatekeeper.stake.unbonding_amount, 40);
+
+        assert_eq!(
+            gatekeeper.release_unbonded_stake(1059),
+            Err(error!(GatekeeperErrors::UnbondingPeriodNotEnded))
+        );
+        assert_eq!(gatekeeper.release_unbonded_stake(1060), Ok(40));
+        assert_eq!(gatekeeper.stake.unbonding_amount, 0);
+    }
+
+    #[test]
+    fn test_stake_overflow() {
+        let mut gatekeeper = make_gatekeeper(None, None, GatekeeperKeyFlags::AUTH);
+        let vault = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        gatekeeper.add_stake(vault, mint, u64::MAX).unwrap();
+
+        assert_eq!(
+            gatekeeper.add_stake(vault, mint, 1),
+            Err(error!(GatekeeperErrors::StakeOverflow))
+        );
+        assert_eq!(
+            gatekeeper.unstake(1, i64::MAX, 1),
+            Err(error!(GatekeeperErrors::StakeOverflow))
+        );
+        assert_eq!(gatekeeper.stake.amount, u64::MAX);
+    }
+
+    #[test]
+    fn test_slash_takes_unbonding_stake_last() {
+        let mut gatekeeper = make_gatekeeper(None, None, GatekeeperKeyFlags::AUTH);
+        gatekeeper
+            .add_stake(Pubkey::new_unique(), Pubkey::new_unique(), 100)
+            .unwrap();
+        gatekeeper.unstake(40, 60, 1000).unwrap();
+
+        assert_eq!(
+            gatekeeper.slash(0),
+            Err(error!(GatekeeperErrors::InvalidSlashAmount))
+        );
+        assert_eq!(gatekeeper.slash(70), Ok(()));
+        assert_eq!(gatekeeper.stake.amount, 0);
+        assert_eq!(gatekeeper.stake.unbonding_amount, 30);
+
+        assert_eq!(
+            gatekeeper.slash(31),
+            Err(error!(GatekeeperErrors::InsufficientStakeToSlash))
+        );
+        assert_eq!(gatekeeper.slash(30), Ok(()));
+        assert_eq!(gatekeeper.stake.amount, 0);
+        assert_eq!(gatekeeper.stake.unbonding_amount, 0);
+    }
+
     /// Test function to make a gatekeeper
     fn make_gatekeeper(
         authority: Option<Signer>,
@@ -494,6 +778,9 @@ mod tests {
             token_fees: vec![],
             auth_threshold: 0,
             auth_keys: vec![auth_key],
+            addresses: Pubkey::default(),
+            stake: Default::default(),
+            reference_fees: None,
         }
     }
 
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GatekeeperErrors;
+use crate::instructions::network::{CreateGatekeeperAddressesData, UpdateGatekeeperAddressesData};
+use crate::util::*;
+
+/// Discoverability information for a [`crate::state::Gatekeeper`], linked from
+/// [`crate::state::Gatekeeper::addresses`].
+///
+/// The fixed-size fields come first so clients can filter these accounts by gatekeeper network.
+#[derive(Debug)]
+#[account]
+pub struct GatekeeperAddresses {
+    /// The version of this struct, should be 0 until a new version is released
+    pub version: u8,
+    /// The [`crate::state::Gatekeeper`] these addresses belong to
+    pub gatekeeper: Pubkey,
+    /// The [`crate::state::GatekeeperNetwork`] the gatekeeper is on
+    pub gatekeeper_network: Pubkey,
+    /// The bump for the signer of this account
+    pub bump: u8,
+    /// A hash of the terms of service a subject agrees to when requesting a pass
+    pub terms_of_service_hash: [u8; 32],
+    /// The DID of the gatekeeper, empty if not set
+    pub did: String,
+    /// Endpoints (e.g. URLs) where the gatekeeper's services can be reached
+    pub service_endpoints: Vec<String>,
+    /// The jurisdictions the gatekeeper can issue passes in, as ISO 3166-1 alpha-2 codes
+    pub supported_jurisdictions: Vec<[u8; 2]>,
+}
+
+impl GatekeeperAddresses {
+    pub fn size(did: &str, service_endpoints: &[String], jurisdictions_count: usize) -> usize {
+        OC_SIZE_DISCRIMINATOR
+            + OC_SIZE_U8 // version
+            + OC_SIZE_PUBKEY // gatekeeper
+            + OC_SIZE_PUBKEY // gatekeeper_network
+            + OC_SIZE_U8 // bump
+            + 32 // terms_of_service_hash
+            + OC_SIZE_STRING_PREFIX + did.len() // did
+            + OC_SIZE_VEC_PREFIX
+            + service_endpoints
+                .iter()
+                .map(|endpoint| OC_SIZE_STRING_PREFIX + endpoint.len())
+                .sum::<usize>() // service_endpoints
+            + OC_SIZE_VEC_PREFIX + 2 * jurisdictions_count // supported_jurisdictions
+    }
+
+    /// The size of the account once `data` has been applied to it
+    pub fn size_after_update(&self, data: &UpdateGatekeeperAddressesData) -> usize {
+        Self::size(
+            data.did.as_ref().unwrap_or(&self.did),
+            data.service_endpoints
+                .as_ref()
+                .unwrap_or(&self.service_endpoints),
+            data.supported_jurisdictions
+                .as_ref()
+                .map_or(self.supported_jurisdictions.len(), |j| j.len()),
+        )
+    }
+
+    pub fn initialize(&mut self, data: CreateGatekeeperAddressesData) -> Result<()> {
+        require!(is_valid_did(&data.did), GatekeeperErrors::InvalidDid);
+
+        self.terms_of_service_hash = data.terms_of_service_hash;
+        self.did = data.did;
+        self.service_endpoints = data.service_endpoints;
+        self.supported_jurisdictions = data.supported_jurisdictions;
+
+        Ok(())
+    }
+
+    /// Replaces every field that is set in `data`
+    pub fn update(&mut self, data: UpdateGatekeeperAddressesData) -> Result<()> {
+        if let Some(did) = data.did {
+            require!(is_valid_did(&did), GatekeeperErrors::InvalidDid);
+            self.did = did;
+        }
+
+        if let Some(terms_of_service_hash) = data.terms_of_service_hash {
+            self.terms_of_service_hash = terms_of_service_hash;
+        }
+
+        if let Some(service_endpoints) = data.service_endpoints {
+            self.service_endpoints = service_endpoints;
+        }
+
+        if let Some(supported_jurisdictions) = data.supported_jurisdictions {
+            self.supported_jurisdictions = supported_jurisdictions;
+        }
+
+        Ok(())
+    }
+
+    pub fn supports_jurisdiction(&self, jurisdiction: &[u8; 2]) -> bool {
+        self.supported_jurisdictions.contains(jurisdiction)
+    }
+}
+
+// An empty DID means that the gatekeeper has not published one
+fn is_valid_did(did: &str) -> bool {
+    did.is_empty() || did.starts_with("did:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_matches_serialized_length() {
+        let mut addresses = make_addresses();
+        addresses
+            .initialize(CreateGatekeeperAddressesData {
+                terms_of_service_hash: [1; 32],
+                did: "did:sol:gatekeeper".to_string(),
+                service_endpoints: vec!["https://example.com/pass".to_string()],
+                supported_jurisdictions: vec![*b"US", *b"DE"],
+            })
+            .unwrap();
+
+        let serialized = addresses.try_to_vec().unwrap();
+
+        assert_eq!(
+            GatekeeperAddresses::size(
+                &addresses.did,
+                &addresses.service_endpoints,
+                addresses.supported_jurisdictions.len()
+            ),
+            OC_SIZE_DISCRIMINATOR + serialized.len()
+        );
+    }
+
+    #[test]
+    fn test_update_only_replaces_provided_fields() {
+        let mut addresses = make_addresses();
+        addresses.did = "did:sol:gatekeeper".to_string();
+        addresses.supported_jurisdictions = vec![*b"US"];
+
+        let data = UpdateGatekeeperAddressesData {
+            terms_of_service_hash: None,
+            did: None,
+            service_endpoints: Some(vec!["https://example.com".to_string()]),
+            supported_jurisdictions: None,
+        };
+        let expected_size = addresses.size_after_update(&data);
+
+        addresses.update(data).unwrap();
+
+        assert_eq!(addresses.did, "did:sol:gatekeeper");
+        assert_eq!(addresses.service_endpoints, vec!["https://example.com"]);
+        assert!(addresses.supports_jurisdiction(b"US"));
+        assert_eq!(
+            expected_size,
+            OC_SIZE_DISCRIMINATOR + addresses.try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_update_rejects_invalid_did() {
+        let mut addresses = make_addresses();
+
+        let result = addresses.update(UpdateGatekeeperAddressesData {
+            terms_of_service_hash: None,
+            did: Some("not-a-did".to_string()),
+            service_endpoints: None,
+            supported_jurisdictions: None,
+        });
+
+        assert_eq!(result, Err(error!(GatekeeperErrors::InvalidDid)));
+    }
+
+    fn make_addresses() -> GatekeeperAddresses {
+        GatekeeperAddresses {
+            version: 0,
+            gatekeeper: Pubkey::new_unique(),
+            gatekeeper_network: Pubkey::new_unique(),
+            bump: 0,
+            terms_of_service_hash: [0; 32],
+            did: String::new(),
+            service_endpoints: vec![],
+            supported_jurisdictions: vec![],
+        }
+    }
+}
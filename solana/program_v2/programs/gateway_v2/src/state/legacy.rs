@@ -0,0 +1,201 @@
+//! Account layouts from before the current account versions, used to migrate existing accounts.
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    AuthKey, Gatekeeper, GatekeeperAuthKey, GatekeeperFees, GatekeeperNetwork, GatekeeperState,
+    NetworkFeesPercentage, Pass, PassState, SupportedToken,
+};
+
+/// Version 0 of [`Pass`], before extended data was added
+#[derive(Debug, AnchorDeserialize)]
+pub struct PassV0 {
+    pub version: u8,
+    pub subject: Pubkey,
+    pub network: Pubkey,
+    pub pass_number: u16,
+    pub signer_bump: u8,
+    pub gatekeeper: Pubkey,
+    pub issue_time: i64,
+    pub state: PassState,
+    pub network_data: [u8; 32],
+    pub gatekeeper_data: [u8; 32],
+}
+
+impl From<PassV0> for Pass {
+    fn from(pass: PassV0) -> Self {
+        Pass {
+            version: Pass::VERSION,
+            subject: pass.subject,
+            network: pass.network,
+            pass_number: pass.pass_number,
+            signer_bump: pass.signer_bump,
+            gatekeeper: pass.gatekeeper,
+            issue_time: pass.issue_time,
+            state: pass.state,
+            network_data: pass.network_data,
+            gatekeeper_data: pass.gatekeeper_data,
+            extended_network_data: vec![],
+            extended_gatekeeper_data: vec![],
+            issue_fee: None,
+        }
+    }
+}
+
+/// Version 0 of [`NetworkFeesPercentage`], before referrer fees and fee schedules were added
+#[derive(Debug, AnchorDeserialize)]
+pub struct NetworkFeesPercentageV0 {
+    pub token: Pubkey,
+    pub issue: u16,
+    pub refresh: u16,
+    pub expire: u16,
+    pub verify: u16,
+}
+
+impl From<NetworkFeesPercentageV0> for NetworkFeesPercentage {
+    fn from(fees: NetworkFeesPercentageV0) -> Self {
+        NetworkFeesPercentage {
+            token: fees.token,
+            issue: fees.issue,
+            refresh: fees.refresh,
+            expire: fees.expire,
+            verify: fees.verify,
+            issue_referrer: 0,
+            verify_referrer: 0,
+            effective_from: 0,
+        }
+    }
+}
+
+/// Version 0 of [`GatekeeperNetwork`]
+#[derive(Debug, AnchorDeserialize)]
+pub struct GatekeeperNetworkV0 {
+    pub version: u8,
+    pub authority: Pubkey,
+    pub network_index: u16,
+    pub pass_expire_time: i64,
+    pub network_features: u32,
+    pub fees: Vec<NetworkFeesPercentageV0>,
+    pub supported_tokens: Vec<SupportedToken>,
+    pub gatekeepers: Vec<Pubkey>,
+    pub auth_threshold: u8,
+    pub auth_keys: Vec<AuthKey>,
+}
+
+impl From<GatekeeperNetworkV0> for GatekeeperNetwork {
+    fn from(network: GatekeeperNetworkV0) -> Self {
+        GatekeeperNetwork {
+            version: GatekeeperNetwork::VERSION,
+            authority: network.authority,
+            network_index: network.network_index,
+            pass_expire_time: network.pass_expire_time,
+            network_features: network.network_features,
+            fees: network.fees.into_iter().map(Into::into).collect(),
+            supported_tokens: network.supported_tokens,
+            gatekeepers: network.gatekeepers,
+            auth_threshold: network.auth_threshold,
+            auth_keys: network.auth_keys,
+            staking: Default::default(),
+            max_pass_data_size: 0,
+            signer_bump: 0,
+            price_feeds: vec![],
+            fee_bounds: vec![],
+            fee_notice_period: 0,
+            refund_grace_period: 0,
+        }
+    }
+}
+
+/// Version 0 of [`GatekeeperFees`], before fee schedules were added
+#[derive(Debug, AnchorDeserialize)]
+pub struct GatekeeperFeesV0 {
+    pub token: Pubkey,
+    pub issue: u64,
+    pub refresh: u64,
+    pub expire: u64,
+    pub verify: u64,
+}
+
+impl From<GatekeeperFeesV0> for GatekeeperFees {
+    fn from(fees: GatekeeperFeesV0) -> Self {
+        GatekeeperFees {
+            token: fees.token,
+            issue: fees.issue,
+            refresh: fees.refresh,
+            expire: fees.expire,
+            verify: fees.verify,
+            effective_from: 0,
+        }
+    }
+}
+
+/// Version 0 of [`Gatekeeper`]
+#[derive(Debug, AnchorDeserialize)]
+pub struct GatekeeperV0 {
+    pub version: u8,
+    pub subject: Pubkey,
+    pub gatekeeper_bump: u8,
+    pub gatekeeper_network: Pubkey,
+    pub staking_account: Pubkey,
+    pub gatekeeper_state: GatekeeperState,
+    pub token_fees: Vec<GatekeeperFeesV0>,
+    pub auth_threshold: u8,
+    pub auth_keys: Vec<GatekeeperAuthKey>,
+}
+
+impl From<GatekeeperV0> for Gatekeeper {
+    fn from(gatekeeper: GatekeeperV0) -> Self {
+        Gatekeeper {
+            version: Gatekeeper::VERSION,
+            subject: gatekeeper.subject,
+            gatekeeper_bump: gatekeeper.gatekeeper_bump,
+            gatekeeper_network: gatekeeper.gatekeeper_network,
+            // Version 0 never held stake in this account
+            staking_account: Pubkey::default(),
+            gatekeeper_state: gatekeeper.gatekeeper_state,
+            token_fees: gatekeeper.token_fees.into_iter().map(Into::into).collect(),
+            auth_threshold: gatekeeper.auth_threshold,
+            auth_keys: gatekeeper.auth_keys,
+            addresses: Pubkey::default(),
+            stake: Default::default(),
+            reference_fees: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::OC_SIZE_DISCRIMINATOR;
+
+    #[test]
+    fn test_migrate_pass_v0() {
+        let subject = Pubkey::new_unique();
+        let network = Pubkey::new_unique();
+        let gatekeeper = Pubkey::new_unique();
+        let v0_data = (
+            0u8,
+            subject,
+            network,
+            7u16,
+            255u8,
+            gatekeeper,
+            100i64,
+            PassState::Active,
+            [1u8; 32],
+            [2u8; 32],
+        )
+            .try_to_vec()
+            .unwrap();
+
+        let pass = Pass::from(PassV0::try_from_slice(&v0_data).unwrap());
+
+        assert_eq!(pass.version, Pass::VERSION);
+        assert_eq!(pass.subject, subject);
+        assert_eq!(pass.network, network);
+        assert_eq!(pass.pass_number, 7);
+        assert_eq!(pass.gatekeeper, gatekeeper);
+        assert_eq!(pass.gatekeeper_data, [2; 32]);
+        assert_eq!(pass.issue_fee, None);
+        assert!(OC_SIZE_DISCRIMINATOR + pass.try_to_vec().unwrap().len() <= Pass::size(0, 0));
+    }
+}
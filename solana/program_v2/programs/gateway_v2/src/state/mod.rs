@@ -1,11 +1,29 @@
+pub use fee_credit::*;
+pub use fee_schedule::*;
 pub use gatekeeper::*;
+pub use gatekeeper_addresses::*;
+pub use legacy::*;
 pub use network::*;
+pub use network_registry::*;
 pub use operations::*;
 pub use pass::*;
+pub use pass_request::*;
+pub use redeemed_voucher::*;
 pub use shared::*;
+pub use slash_record::*;
+pub use sponsorship::*;
 
+pub mod fee_credit;
+pub mod fee_schedule;
 pub mod gatekeeper;
+pub mod gatekeeper_addresses;
+pub mod legacy;
 pub mod network;
+pub mod network_registry;
 pub mod operations;
 pub mod pass;
+pub mod pass_request;
+pub mod redeemed_voucher;
 pub mod shared;
+pub mod slash_record;
+pub mod sponsorship;
@@ -2,20 +2,22 @@ use anchor_lang::prelude::*;
 use anchor_lang::{AnchorDeserialize, AnchorSerialize};
 use bitflags::bitflags;
 
-use crate::errors::NetworkErrors;
+use crate::errors::{GatekeeperErrors, NetworkErrors};
 use crate::instructions::admin::*;
-use crate::state::{AuthKey, UpdateOperands, UpdateOperations};
+use crate::state::{
+    AuthKey, FeeScheduleKey, GatekeeperFees, ScheduledFee, UpdateOperands, UpdateOperations,
+};
 use crate::util::*;
 
 /// A gatekeeper network which manages many [`Gatekeeper`]s.
 #[account]
 #[derive(Debug)]
 pub struct GatekeeperNetwork {
-    /// The version of this struct, should be 0 until a new version is released
+    /// The version of this struct. Older accounts must be migrated to [`Self::VERSION`].
     pub version: u8,
     /// The initial authority key
     pub authority: Pubkey,
-    /// the index of the network
+    /// The index the network address is derived from, `0` if the network is not a PDA
     pub network_index: u16,
     /// The length of time a pass lasts in seconds. `0` means does not expire.
     pub pass_expire_time: i64,
@@ -31,30 +33,139 @@ pub struct GatekeeperNetwork {
     pub auth_threshold: u8,
     /// Keys with permissions on the network
     pub auth_keys: Vec<AuthKey>,
+    /// The stake each gatekeeper must hold to issue passes on this network
+    pub staking: NetworkStaking,
+    /// The maximum size in bytes of each extended data section on a [`crate::state::Pass`]
+    pub max_pass_data_size: u16,
+    /// The bump for the signer, if the network is a PDA
+    pub signer_bump: u8,
+    /// The price feeds used to convert [`crate::state::ReferenceFees`] into each token
+    pub price_feeds: Vec<NetworkPriceFeed>,
+    /// The limits on the [`crate::state::GatekeeperFees`] gatekeepers can set for each token
+    pub fee_bounds: Vec<GatekeeperFeeBounds>,
+    /// How far in seconds a change to the fees of a token that is already charged has to be
+    /// scheduled ahead, see [`crate::state::GatekeeperFees::effective_from`]
+    pub fee_notice_period: i64,
+    /// How long after a pass is issued its issue fee is refunded on revoking it, `0` disables
+    /// refunds. Only indexed networks can sign for refunds out of their token accounts.
+    pub refund_grace_period: i64,
     // possible data for network features
     // pub network_features_data: Vec<u8>
 }
 
+/// The staking requirements a [`GatekeeperNetwork`] places on its [`Gatekeeper`]s
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, Copy, AnchorDeserialize, AnchorSerialize, InitSpace,
+)]
+pub struct NetworkStaking {
+    /// The mint gatekeepers stake in. Must be a supported token if `minimum_stake` is set.
+    pub mint: Pubkey,
+    /// The amount a gatekeeper needs staked to issue passes. `0` means staking is not required.
+    pub minimum_stake: u64,
+    /// The length of time in seconds between unstaking and being able to withdraw the stake
+    pub unbonding_period: i64,
+}
+
+/// The lowest and highest fees a gatekeeper on a [`GatekeeperNetwork`] can charge in a token
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, Copy, AnchorDeserialize, AnchorSerialize, InitSpace,
+)]
+pub struct GatekeeperFeeBounds {
+    /// The token the bounds apply to
+    pub token: Pubkey,
+    /// The minimum [`GatekeeperFees::issue`]
+    pub issue_min: u64,
+    /// The maximum [`GatekeeperFees::issue`]
+    pub issue_max: u64,
+    /// The minimum [`GatekeeperFees::refresh`]
+    pub refresh_min: u64,
+    /// The maximum [`GatekeeperFees::refresh`]
+    pub refresh_max: u64,
+    /// The minimum [`GatekeeperFees::expire`]
+    pub expire_min: u64,
+    /// The maximum [`GatekeeperFees::expire`]
+    pub expire_max: u64,
+    /// The minimum [`GatekeeperFees::verify`]
+    pub verify_min: u64,
+    /// The maximum [`GatekeeperFees::verify`]
+    pub verify_max: u64,
+}
+
+impl GatekeeperFeeBounds {
+    pub fn contains(&self, fees: &GatekeeperFees) -> bool {
+        (self.issue_min..=self.issue_max).contains(&fees.issue)
+            && (self.refresh_min..=self.refresh_max).contains(&fees.refresh)
+            && (self.expire_min..=self.expire_max).contains(&fees.expire)
+            && (self.verify_min..=self.verify_max).contains(&fees.verify)
+    }
+
+    /// Checks that a fee for an operation is within the bounds
+    pub fn contains_fee(&self, operation: FeeOperation, fee: u64) -> bool {
+        let (min, max) = match operation {
+            FeeOperation::Issue => (self.issue_min, self.issue_max),
+            FeeOperation::Refresh => (self.refresh_min, self.refresh_max),
+            FeeOperation::Expire => (self.expire_min, self.expire_max),
+            FeeOperation::Verify => (self.verify_min, self.verify_max),
+        };
+
+        (min..=max).contains(&fee)
+    }
+
+    /// Checks that no minimum is above its maximum, which would reject every fee
+    pub fn is_valid(&self) -> bool {
+        self.issue_min <= self.issue_max
+            && self.refresh_min <= self.refresh_max
+            && self.expire_min <= self.expire_max
+            && self.verify_min <= self.verify_max
+    }
+}
+
+/// A Pyth price account approved by a [`GatekeeperNetwork`] for pricing a token
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, Copy, AnchorDeserialize, AnchorSerialize, InitSpace,
+)]
+pub struct NetworkPriceFeed {
+    /// The token that is priced
+    pub token: Pubkey,
+    /// The Pyth price account giving the price of one token in the reference currency
+    pub price_account: Pubkey,
+    /// The maximum number of slots since the price was published
+    pub max_slot_age: u64,
+    /// The maximum confidence interval, in basis points of the price
+    pub max_confidence_ratio: u64,
+}
+
 #[derive(Debug, Default, Clone, Copy, AnchorDeserialize, AnchorSerialize, PartialEq, InitSpace)]
 pub struct SupportedToken {
     key: Pubkey,
 }
 
 impl GatekeeperNetwork {
+    /// The current version of the account layout
+    pub const VERSION: u8 = 1;
+
     pub fn size(
         fees_count: usize,
         auth_keys: usize,
         gatekeepers: usize,
         supported_tokens: usize,
+        price_feeds: usize,
+        fee_bounds: usize,
     ) -> usize {
         OC_SIZE_DISCRIMINATOR
             + OC_SIZE_U8 // version
             + OC_SIZE_PUBKEY // initial_authority
             + OC_SIZE_U32 // network_features
+            + NetworkStaking::INIT_SPACE // staking
+            + OC_SIZE_U16 // max_pass_data_size
+            + OC_SIZE_U64 // fee_notice_period
+            + OC_SIZE_U64 // refund_grace_period
             + OC_SIZE_U8 // auth_threshold
             + OC_SIZE_U64 // pass_expire_time
             + OC_SIZE_U8 // signer_bump
             + OC_SIZE_VEC_PREFIX + NetworkFeesPercentage::INIT_SPACE * fees_count // fees
+            + OC_SIZE_VEC_PREFIX + NetworkPriceFeed::INIT_SPACE * price_feeds // price_feeds
+            + OC_SIZE_VEC_PREFIX + GatekeeperFeeBounds::INIT_SPACE * fee_bounds // fee_bounds
             + OC_SIZE_VEC_PREFIX + AuthKey::INIT_SPACE * auth_keys // auth_keys
             + OC_SIZE_VEC_PREFIX + (OC_SIZE_PUBKEY * gatekeepers) // gatekeeper list
             + OC_SIZE_U16 // network_index
@@ -92,6 +203,48 @@ impl GatekeeperNetwork {
             .any(|token| token.key == *mint_account)
     }
 
+    /// Checks that each of the fees is within the [`GatekeeperNetwork::fee_bounds`] for its token.
+    /// Tokens without bounds are unrestricted.
+    pub fn are_gatekeeper_fees_within_bounds(&self, fees: &[GatekeeperFees]) -> bool {
+        fees.iter().all(|fee| {
+            self.fee_bounds
+                .iter()
+                .find(|bounds| bounds.token == fee.token)
+                .map_or(true, |bounds| bounds.contains(fee))
+        })
+    }
+
+    /// Checks a gatekeeper fee against the [`GatekeeperNetwork::fee_bounds`] for its token when it
+    /// is charged. Fees set before the bounds changed, and fees converted from reference fees, are
+    /// not checked when they are set, so the gatekeeper has to update them to charge again.
+    pub fn check_gatekeeper_fee(
+        &self,
+        mint: &Pubkey,
+        operation: FeeOperation,
+        fee: u64,
+    ) -> Result<u64> {
+        let within_bounds = self
+            .fee_bounds
+            .iter()
+            .find(|bounds| bounds.token == *mint)
+            .map_or(true, |bounds| bounds.contains_fee(operation, fee));
+        require!(within_bounds, GatekeeperErrors::FeesOutOfBounds);
+
+        Ok(fee)
+    }
+
+    /// Checks that none of the [`GatekeeperNetwork::fee_bounds`] has a minimum above its maximum
+    pub fn are_fee_bounds_valid(&self) -> bool {
+        self.fee_bounds.iter().all(GatekeeperFeeBounds::is_valid)
+    }
+
+    pub fn get_price_feed(&self, mint: &Pubkey) -> Result<&NetworkPriceFeed> {
+        self.price_feeds
+            .iter()
+            .find(|feed| feed.token == *mint)
+            .ok_or_else(|| error!(NetworkErrors::PriceFeedNotApproved))
+    }
+
     pub fn set_expire_time(&mut self, pass_expire_time: i64) -> Result<()> {
         self.pass_expire_time = pass_expire_time;
 
@@ -104,6 +257,24 @@ impl GatekeeperNetwork {
         Ok(())
     }
 
+    /// Checks that gatekeepers are able to stake in the [`GatekeeperNetwork::staking`] mint
+    pub fn is_staking_valid(&self) -> bool {
+        self.staking.minimum_stake == 0 || self.is_token_supported(&self.staking.mint)
+    }
+
+    /// Checks that every [`GatekeeperNetwork::price_feeds`] entry prices a supported token
+    pub fn are_price_feeds_supported(&self) -> bool {
+        self.price_feeds
+            .iter()
+            .all(|feed| self.is_token_supported(&feed.token))
+    }
+
+    /// Refunds move the network's share out of its token account, which needs the network to be
+    /// a PDA the program can sign for
+    pub fn can_refund_fees(&self) -> bool {
+        self.refund_grace_period == 0 || self.network_index != 0
+    }
+
     pub fn is_closeable(&self) -> bool {
         self.gatekeepers.is_empty()
     }
@@ -144,20 +315,23 @@ impl UpdateOperations<UpdateKeys, AuthKey> for GatekeeperNetwork {
     }
 }
 
-impl UpdateOperations<UpdateFees, NetworkFeesPercentage> for GatekeeperNetwork {
-    fn operands(this: &mut Self, operation: UpdateFees) -> UpdateOperands<NetworkFeesPercentage> {
+impl UpdateOperations<UpdateFees, NetworkFeesPercentage, FeeScheduleKey> for GatekeeperNetwork {
+    fn operands(
+        this: &mut Self,
+        operation: UpdateFees,
+    ) -> UpdateOperands<NetworkFeesPercentage, FeeScheduleKey> {
         UpdateOperands::new(&mut this.fees, operation.remove, operation.add)
     }
 
-    fn extract_key(container: &NetworkFeesPercentage) -> Pubkey {
-        container.token
+    fn extract_key(container: &NetworkFeesPercentage) -> FeeScheduleKey {
+        container.schedule_key()
     }
 
     fn missing_key_error() -> Error {
         error!(NetworkErrors::InsufficientAccessAuthKeys)
     }
 
-    fn pre_remove_validation(_: &Pubkey, _: &Signer) -> Result<()> {
+    fn pre_remove_validation(_: &FeeScheduleKey, _: &Signer) -> Result<()> {
         Ok(())
     }
 
@@ -166,6 +340,53 @@ impl UpdateOperations<UpdateFees, NetworkFeesPercentage> for GatekeeperNetwork {
     }
 }
 
+impl UpdateOperations<UpdateFeeBounds, GatekeeperFeeBounds> for GatekeeperNetwork {
+    fn operands(
+        this: &mut Self,
+        operation: UpdateFeeBounds,
+    ) -> UpdateOperands<GatekeeperFeeBounds> {
+        UpdateOperands::new(&mut this.fee_bounds, operation.remove, operation.add)
+    }
+
+    fn extract_key(container: &GatekeeperFeeBounds) -> Pubkey {
+        container.token
+    }
+
+    fn missing_key_error() -> Error {
+        error!(NetworkErrors::FeesNotProvided)
+    }
+
+    fn pre_remove_validation(_: &Pubkey, _: &Signer) -> Result<()> {
+        Ok(())
+    }
+
+    fn pre_add_validation(_: &GatekeeperFeeBounds, _: &Signer) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl UpdateOperations<UpdatePriceFeeds, NetworkPriceFeed> for GatekeeperNetwork {
+    fn operands(this: &mut Self, operation: UpdatePriceFeeds) -> UpdateOperands<NetworkPriceFeed> {
+        UpdateOperands::new(&mut this.price_feeds, operation.remove, operation.add)
+    }
+
+    fn extract_key(container: &NetworkPriceFeed) -> Pubkey {
+        container.token
+    }
+
+    fn missing_key_error() -> Error {
+        error!(NetworkErrors::PriceFeedNotApproved)
+    }
+
+    fn pre_remove_validation(_: &Pubkey, _: &Signer) -> Result<()> {
+        Ok(())
+    }
+
+    fn pre_add_validation(_: &NetworkPriceFeed, _: &Signer) -> Result<()> {
+        Ok(())
+    }
+}
+
 impl UpdateOperations<UpdateSupportedTokens, SupportedToken> for GatekeeperNetwork {
     fn operands(
         this: &mut Self,
@@ -206,6 +427,15 @@ pub struct NetworkFeesPercentage {
     pub expire: u16,
     /// Percentage taken on verify. In Hundredths of a percent (0.01% or 0.0001).
     pub verify: u16,
+    /// Percentage of the issue fee paid to a referrer out of the gatekeeper's share.
+    /// In Hundredths of a percent (0.01% or 0.0001).
+    pub issue_referrer: u16,
+    /// Percentage of the verify fee paid to a referrer out of the gatekeeper's share.
+    /// In Hundredths of a percent (0.01% or 0.0001).
+    pub verify_referrer: u16,
+    /// The time these fees take effect. Entries for the same token with different times
+    /// form a schedule, and the latest entry that has taken effect is charged.
+    pub effective_from: i64,
 }
 
 bitflags! {
@@ -228,7 +458,8 @@ bitflags! {
         const UNHALT_GATEKEEPER = 1 << 6;
         /// Key can un-revoke passes with gatekeepers
         const UNREVOKE_PASS = 1 << 7;
-        /// Key can adjust fees in [`GatekeeperNetwork::fees`]
+        /// Key can adjust fees in [`GatekeeperNetwork::fees`], [`GatekeeperNetwork::price_feeds`],
+        /// [`GatekeeperNetwork::fee_bounds`] and [`GatekeeperNetwork::refund_grace_period`]
         const ADJUST_FEES = 1 << 8;
         /// Key can access the network's vault
         const ACCESS_VAULT = 1 << 9;
@@ -236,6 +467,14 @@ bitflags! {
         const SET_EXPIRE_TIME = 1 << 10;
         /// Key can set [`GatekeeperNetwork::pass_expire_time`]
         const UPDATE_TOKENS = 1 << 11;
+        /// Key can set [`GatekeeperNetwork::staking`]
+        const SET_STAKING = 1 << 12;
+        /// Key can slash the stake of gatekeepers
+        const SLASH_GATEKEEPER = 1 << 13;
+        /// Key can set [`GatekeeperNetwork::max_pass_data_size`]
+        const SET_PASS_DATA_SIZE = 1 << 14;
+        /// Key can set the network extended data on passes
+        const SET_PASS_DATA = 1 << 15;
     }
 
      /// The flags for network features
@@ -254,12 +493,13 @@ mod tests {
     use solana_program::clock::Epoch;
     use solana_program::pubkey::Pubkey;
 
-    use crate::errors::NetworkErrors;
+    use crate::errors::{GatekeeperErrors, NetworkErrors};
     use crate::instructions::admin::{UpdateFees, UpdateKeys, UpdateSupportedTokens};
     use crate::state::{
-        AuthKey, GatekeeperNetwork, NetworkFeesPercentage, NetworkKeyFlags, SupportedToken,
-        UpdateOperations,
+        AuthKey, GatekeeperFeeBounds, GatekeeperFees, GatekeeperNetwork, NetworkFeesPercentage,
+        NetworkKeyFlags, NetworkPriceFeed, ScheduledFee, SupportedToken, UpdateOperations,
     };
+    use crate::util::FeeOperation;
 
     #[test]
     fn test_can_access_auth_key_with_same_authority() {
@@ -487,6 +727,9 @@ mod tests {
                 refresh: 0,
                 expire: 0,
                 verify: 0,
+                issue_referrer: 0,
+                verify_referrer: 0,
+                effective_from: 0,
             };
             let update_fees = UpdateFees {
                 add: vec![expected_fee],
@@ -497,7 +740,7 @@ mod tests {
 
             let update_fees = UpdateFees {
                 add: vec![],
-                remove: vec![expected_fee.token],
+                remove: vec![expected_fee.schedule_key()],
             };
 
             network.apply_update(update_fees, &authority).unwrap();
@@ -598,6 +841,106 @@ mod tests {
         assert!(!network.is_closeable());
     }
 
+    #[test]
+    fn test_gatekeeper_fees_within_bounds() {
+        let mut network = make_network(None, None, vec![], NetworkKeyFlags::AUTH);
+        let bounded_token = Pubkey::new_unique();
+        network.fee_bounds = vec![GatekeeperFeeBounds {
+            token: bounded_token,
+            issue_max: 100,
+            refresh_max: 100,
+            expire_max: 100,
+            verify_min: 10,
+            verify_max: 100,
+            ..Default::default()
+        }];
+        let mut fees = GatekeeperFees {
+            token: bounded_token,
+            issue: 100,
+            effective_from: 0,
+            refresh: 0,
+            expire: 0,
+            verify: 10,
+        };
+
+        assert!(network.are_gatekeeper_fees_within_bounds(&[fees]));
+
+        fees.issue = 101;
+        assert!(!network.are_gatekeeper_fees_within_bounds(&[fees]));
+
+        fees.token = Pubkey::new_unique();
+        assert!(network.are_gatekeeper_fees_within_bounds(&[fees]));
+    }
+
+    #[test]
+    fn test_check_gatekeeper_fee() {
+        let mut network = make_network(None, None, vec![], NetworkKeyFlags::AUTH);
+        let bounded_token = Pubkey::new_unique();
+        network.fee_bounds = vec![GatekeeperFeeBounds {
+            token: bounded_token,
+            issue_max: 100,
+            verify_min: 10,
+            verify_max: 100,
+            ..Default::default()
+        }];
+
+        assert_eq!(
+            network.check_gatekeeper_fee(&bounded_token, FeeOperation::Issue, 150),
+            Err(error!(GatekeeperErrors::FeesOutOfBounds))
+        );
+        assert_eq!(
+            network.check_gatekeeper_fee(&bounded_token, FeeOperation::Verify, 5),
+            Err(error!(GatekeeperErrors::FeesOutOfBounds))
+        );
+        assert_eq!(
+            network
+                .check_gatekeeper_fee(&bounded_token, FeeOperation::Verify, 50)
+                .unwrap(),
+            50
+        );
+        assert_eq!(
+            network
+                .check_gatekeeper_fee(&Pubkey::new_unique(), FeeOperation::Issue, 150)
+                .unwrap(),
+            150
+        );
+    }
+
+    #[test]
+    fn test_are_fee_bounds_valid() {
+        let mut network = make_network(None, None, vec![], NetworkKeyFlags::AUTH);
+        network.fee_bounds = vec![GatekeeperFeeBounds {
+            token: Pubkey::new_unique(),
+            issue_min: 10,
+            issue_max: 10,
+            ..Default::default()
+        }];
+        assert!(network.are_fee_bounds_valid());
+
+        network.fee_bounds[0].refresh_min = 1;
+        assert!(!network.are_fee_bounds_valid());
+    }
+
+    #[test]
+    fn test_are_price_feeds_supported() {
+        let token = Pubkey::new_unique();
+        let mut network = make_network(
+            None,
+            None,
+            vec![SupportedToken { key: token }],
+            NetworkKeyFlags::AUTH,
+        );
+        network.price_feeds = vec![NetworkPriceFeed {
+            token,
+            ..Default::default()
+        }];
+
+        assert!(network.are_price_feeds_supported());
+
+        network.price_feeds[0].token = Pubkey::new_unique();
+        assert!(!network.are_price_feeds_supported());
+    }
+
     fn make_network(
         authority: Option<Signer>,
         auth_key: Option<Pubkey>,
@@ -630,6 +973,13 @@ mod tests {
             gatekeepers: vec![],
             auth_threshold: 0,
             auth_keys: vec![auth_key],
+            staking: Default::default(),
+            max_pass_data_size: 0,
+            signer_bump: 0,
+            price_feeds: vec![],
+            fee_bounds: vec![],
+            fee_notice_period: 0,
+            refund_grace_period: 0,
         }
     }
 
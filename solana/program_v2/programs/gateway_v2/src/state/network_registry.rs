@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+/// The global counter that keeps [`crate::state::GatekeeperNetwork::network_index`] unique
+#[derive(Debug, InitSpace)]
+#[account]
+pub struct NetworkRegistry {
+    /// The version of this struct, should be 0 until a new version is released
+    pub version: u8,
+    /// The number of networks created from the registry, also the last index handed out
+    pub network_count: u16,
+    /// The bump for the signer of this account
+    pub bump: u8,
+}
+
+impl NetworkRegistry {
+    /// Indexes start at `1` so that `0` can mean a network was not created from the registry
+    pub fn is_next_index(&self, network_index: u16) -> bool {
+        self.network_count.checked_add(1) == Some(network_index)
+    }
+
+    pub fn claim_index(&mut self, network_index: u16) -> bool {
+        if !self.is_next_index(network_index) {
+            return false;
+        }
+
+        self.network_count = network_index;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_index() {
+        let mut registry = NetworkRegistry {
+            version: 0,
+            network_count: 0,
+            bump: 0,
+        };
+
+        assert!(!registry.claim_index(0));
+        assert!(registry.claim_index(1));
+        assert!(!registry.claim_index(1));
+        assert!(registry.claim_index(2));
+        assert_eq!(registry.network_count, 2);
+
+        registry.network_count = u16::MAX;
+        assert!(!registry.is_next_index(0));
+    }
+}
@@ -6,18 +6,18 @@ use anchor_lang::prelude::*;
 /// The `receiving_container` will have matched `remove_keys` taken out of it, while
 /// `added_containers` will be appended. The actual implementation of the transfer
 /// can be found in [UpdateOperations::apply_update].
-pub struct UpdateOperands<'a, Container> {
+pub struct UpdateOperands<'a, Container, Key = Pubkey> {
     receiving_container: &'a mut Vec<Container>,
-    remove_keys: Vec<Pubkey>,
+    remove_keys: Vec<Key>,
     added_containers: Vec<Container>,
 }
 
-impl<'a, Container> UpdateOperands<'a, Container> {
+impl<'a, Container, Key> UpdateOperands<'a, Container, Key> {
     pub fn new(
         receiving_container: &'a mut Vec<Container>,
-        remove_keys: Vec<Pubkey>,
+        remove_keys: Vec<Key>,
         added_containers: Vec<Container>,
-    ) -> UpdateOperands<'a, Container> {
+    ) -> UpdateOperands<'a, Container, Key> {
         UpdateOperands {
             receiving_container,
             remove_keys,
@@ -34,7 +34,8 @@ impl<'a, Container> UpdateOperands<'a, Container> {
 ///
 /// * `Operation` - The type of the struct containing the add and remove fields. For example: [crate::UpdateGatekeeperKeys]
 /// * `Container` - The type being added during the update operation. For example: [crate::state::GatekeeperAuthKey]
-pub trait UpdateOperations<Operation, Container> {
+/// * `Key` - The type identifying a container. For example: [Pubkey]
+pub trait UpdateOperations<Operation, Container, Key: PartialEq = Pubkey> {
     fn apply_update(&mut self, operation: Operation, authority: &Signer) -> Result<()> {
         let UpdateOperands {
             receiving_container,
@@ -73,18 +74,18 @@ pub trait UpdateOperations<Operation, Container> {
 
     /// Helper function to partition which members of struct implementing this trait
     /// are to receive mutations. Documentation on the return type can be found in [UpdateOperands].
-    fn operands(this: &mut Self, operation: Operation) -> UpdateOperands<Container>;
+    fn operands(this: &mut Self, operation: Operation) -> UpdateOperands<Container, Key>;
 
-    /// Helper function to extract a [Pubkey] type from a [Container] type.
-    fn extract_key(container: &Container) -> Pubkey;
+    /// Helper function to extract a [Key] type from a [Container] type.
+    fn extract_key(container: &Container) -> Key;
 
-    /// If a [Pubkey] that was requested to be removed is not present, an [Error]
+    /// If a [Key] that was requested to be removed is not present, an [Error]
     /// will be returned. This function allows one ot specify the error code to be returned.
     fn missing_key_error() -> Error;
 
-    /// Before a [Pubkey] is removed, the opportunity to perform validations is given.
+    /// Before a [Key] is removed, the opportunity to perform validations is given.
     /// Returning an [Ok] result indicates all validations passed.
-    fn pre_remove_validation(key: &Pubkey, authority: &Signer) -> Result<()>;
+    fn pre_remove_validation(key: &Key, authority: &Signer) -> Result<()>;
 
     /// Before a [Container] is added, the opportunity to perform validations is given.
     /// Returning an [Ok] result indicates all validations passed.
@@ -1,11 +1,12 @@
 use crate::errors::PassErrors;
+use crate::util::*;
 use anchor_lang::prelude::*;
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, InitSpace)]
+#[derive(Debug)]
 #[account]
 pub struct Pass {
-    /// The version of this struct, should be 0 until a new version is released
+    /// The version of this struct. Older accounts must be migrated to [`Self::VERSION`].
     pub version: u8,
     /// The initial authority
     pub subject: Pubkey,
@@ -25,9 +26,65 @@ pub struct Pass {
     pub network_data: [u8; 32],
     /// Additional data from the gatekeeper
     pub gatekeeper_data: [u8; 32],
+    /// Variable-length data from the network, limited by
+    /// [`crate::state::GatekeeperNetwork::max_pass_data_size`]
+    pub extended_network_data: Vec<u8>,
+    /// Variable-length data from the gatekeeper, limited by
+    /// [`crate::state::GatekeeperNetwork::max_pass_data_size`]
+    pub extended_gatekeeper_data: Vec<u8>,
+    /// The issue fee that can be refunded by the issuing gatekeeper within the network's
+    /// [`crate::state::GatekeeperNetwork::refund_grace_period`]
+    pub issue_fee: Option<PassIssueFee>,
 }
 
 impl Pass {
+    /// The current version of the account layout
+    pub const VERSION: u8 = 1;
+
+    pub fn size(extended_network_data_len: usize, extended_gatekeeper_data_len: usize) -> usize {
+        OC_SIZE_DISCRIMINATOR
+            + OC_SIZE_U8 // version
+            + OC_SIZE_PUBKEY // subject
+            + OC_SIZE_PUBKEY // network
+            + OC_SIZE_U16 // pass_number
+            + OC_SIZE_U8 // signer_bump
+            + OC_SIZE_PUBKEY // gatekeeper
+            + OC_SIZE_U64 // issue_time
+            + PassState::INIT_SPACE // state
+            + 32 // network_data
+            + 32 // gatekeeper_data
+            + OC_SIZE_VEC_PREFIX + extended_network_data_len // extended_network_data
+            + OC_SIZE_VEC_PREFIX + extended_gatekeeper_data_len // extended_gatekeeper_data
+            + OC_SIZE_U8 + PassIssueFee::INIT_SPACE // issue_fee
+    }
+
+    /// The size of the pass once `data` has replaced the extended data in `section`
+    pub fn size_after_update(&self, section: PassDataSection, data: &[u8]) -> usize {
+        match section {
+            PassDataSection::Network => Self::size(data.len(), self.extended_gatekeeper_data.len()),
+            PassDataSection::Gatekeeper => Self::size(self.extended_network_data.len(), data.len()),
+        }
+    }
+
+    pub fn set_extended_data(
+        &mut self,
+        section: PassDataSection,
+        data: Vec<u8>,
+        max_size: u16,
+    ) -> Result<()> {
+        require!(
+            data.len() <= max_size as usize,
+            PassErrors::PassDataTooLarge
+        );
+
+        match section {
+            PassDataSection::Network => self.extended_network_data = data,
+            PassDataSection::Gatekeeper => self.extended_gatekeeper_data = data,
+        }
+
+        Ok(())
+    }
+
     pub fn is_valid_state_change(&self, new_state: &PassState) -> bool {
         match new_state {
             PassState::Active => match self.state {
@@ -76,6 +133,42 @@ impl Pass {
         Ok(())
     }
 
+    /// Moves the pass to another gatekeeper. The issue fee was paid to the previous gatekeeper, so it
+    /// is no longer refundable.
+    pub fn change_gatekeeper(&mut self, gatekeeper: Pubkey) {
+        self.gatekeeper = gatekeeper;
+        self.issue_fee = None;
+    }
+
+    /// Whether the issue fee can still be refunded, as it was paid within `refund_grace_period`
+    pub fn is_issue_fee_refundable(&self, refund_grace_period: i64, now: i64) -> bool {
+        self.issue_fee.map_or(false, |issue_fee| {
+            refund_grace_period > 0 && now <= issue_fee.paid_at.saturating_add(refund_grace_period)
+        })
+    }
+
+    /// Takes the issue fee out of the pass, if it was revoked within `refund_grace_period` of paying
+    pub fn take_issue_fee_refund(
+        &mut self,
+        refund_grace_period: i64,
+        now: i64,
+    ) -> Result<PassIssueFee> {
+        require!(
+            self.state == PassState::Revoked,
+            PassErrors::InvalidStateChange
+        );
+
+        let issue_fee = self.issue_fee.ok_or(PassErrors::NoRefundableFee)?;
+        require!(
+            self.is_issue_fee_refundable(refund_grace_period, now),
+            PassErrors::RefundPeriodEnded
+        );
+
+        self.issue_fee = None;
+
+        Ok(issue_fee)
+    }
+
     pub fn is_valid_state(&mut self) -> bool {
         self.state == PassState::Active
     }
@@ -96,6 +189,30 @@ pub enum PassState {
     Revoked,
 }
 
+/// The shares of an issue fee paid for a [`Pass`], kept so they can be refunded
+#[derive(Debug, Copy, Clone, Eq, PartialEq, AnchorDeserialize, AnchorSerialize, InitSpace)]
+pub struct PassIssueFee {
+    /// The token account the fee was paid from, and the one it is refunded to
+    pub funder_token_account: Pubkey,
+    /// The mint the fee was paid in
+    pub mint: Pubkey,
+    /// The network's share of the fee
+    pub network_fee: u64,
+    /// The gatekeeper's share of the fee
+    pub gatekeeper_fee: u64,
+    /// The time the fee was paid
+    pub paid_at: i64,
+}
+
+/// The owner of an extended data section on a [`Pass`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum PassDataSection {
+    /// Set by network keys with [`crate::state::NetworkKeyFlags::SET_PASS_DATA`]
+    Network,
+    /// Set by gatekeeper keys with [`crate::state::GatekeeperKeyFlags::SET_PASS_DATA`]
+    Gatekeeper,
+}
+
 impl Display for PassState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -105,3 +222,108 @@ impl Display for PassState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_matches_serialized_length() {
+        let mut pass = make_pass();
+        pass.set_extended_data(PassDataSection::Network, vec![1; 10], 64)
+            .unwrap();
+        let expected_size = pass.size_after_update(PassDataSection::Gatekeeper, &[2; 20]);
+        pass.set_extended_data(PassDataSection::Gatekeeper, vec![2; 20], 64)
+            .unwrap();
+
+        assert_eq!(
+            expected_size,
+            OC_SIZE_DISCRIMINATOR + pass.try_to_vec().unwrap().len()
+        );
+        assert_eq!(pass.extended_network_data, vec![1; 10]);
+    }
+
+    #[test]
+    fn test_set_extended_data_above_max_size() {
+        let mut pass = make_pass();
+
+        let result = pass.set_extended_data(PassDataSection::Gatekeeper, vec![0; 65], 64);
+
+        assert_eq!(result, Err(error!(PassErrors::PassDataTooLarge)));
+        assert!(pass.extended_gatekeeper_data.is_empty());
+    }
+
+    #[test]
+    fn test_change_gatekeeper_drops_issue_fee() {
+        let mut pass = make_pass();
+        let new_gatekeeper = Pubkey::new_unique();
+
+        pass.change_gatekeeper(new_gatekeeper);
+
+        assert_eq!(pass.gatekeeper, new_gatekeeper);
+        assert_eq!(pass.issue_fee, None);
+        assert!(!pass.is_issue_fee_refundable(100, 1050));
+    }
+
+    #[test]
+    fn test_take_issue_fee_refund() {
+        let mut pass = make_pass();
+        let issue_fee = pass.issue_fee;
+
+        // Only revoked passes are refunded
+        assert!(pass.take_issue_fee_refund(100, 1050).is_err());
+
+        pass.state = PassState::Revoked;
+        assert_eq!(
+            pass.take_issue_fee_refund(0, 1050),
+            Err(error!(PassErrors::RefundPeriodEnded))
+        );
+        assert_eq!(
+            pass.take_issue_fee_refund(100, 1101),
+            Err(error!(PassErrors::RefundPeriodEnded))
+        );
+        assert_eq!(pass.take_issue_fee_refund(100, 1100).ok(), issue_fee);
+
+        // The fee can only be refunded once
+        assert_eq!(
+            pass.take_issue_fee_refund(100, 1100),
+            Err(error!(PassErrors::NoRefundableFee))
+        );
+    }
+
+    #[test]
+    fn test_is_issue_fee_refundable() {
+        let mut pass = make_pass();
+
+        assert!(pass.is_issue_fee_refundable(100, 1100));
+        assert!(!pass.is_issue_fee_refundable(100, 1101));
+        assert!(!pass.is_issue_fee_refundable(0, 1000));
+
+        pass.issue_fee = None;
+        assert!(!pass.is_issue_fee_refundable(100, 1000));
+    }
+
+    fn make_pass() -> Pass {
+        Pass {
+            version: 0,
+            subject: Pubkey::new_unique(),
+            network: Pubkey::new_unique(),
+            pass_number: 0,
+            signer_bump: 0,
+            gatekeeper: Pubkey::new_unique(),
+            issue_time: 0,
+            state: PassState::Active,
+            network_data: [0; 32],
+            gatekeeper_data: [0; 32],
+            extended_network_data: vec![],
+            extended_gatekeeper_data: vec![],
+            issue_fee: Some(PassIssueFee {
+                funder_token_account: Pubkey::new_unique(),
+                mint: Pubkey::new_unique(),
+                network_fee: 10,
+                gatekeeper_fee: 90,
+                paid_at: 1000,
+            }),
+        }
+    }
+}
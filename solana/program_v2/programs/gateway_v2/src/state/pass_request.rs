@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+/// A subject's request for a [`crate::state::Pass`] from a gatekeeper, with the issue fee held
+/// in escrow until the gatekeeper issues the pass or rejects the request
+#[derive(Debug, InitSpace)]
+#[account]
+pub struct PassRequest {
+    /// The version of this struct, should be 0 until a new version is released
+    pub version: u8,
+    /// The subject asking for a pass, who paid the escrow
+    pub subject: Pubkey,
+    /// The network the pass is requested on
+    pub network: Pubkey,
+    /// The gatekeeper asked to issue the pass
+    pub gatekeeper: Pubkey,
+    /// The mint of the escrowed fee
+    pub mint: Pubkey,
+    /// The token account holding the escrowed fee, owned by this account
+    pub escrow: Pubkey,
+    /// The token account the escrow came from, and the one refunds are sent to
+    pub subject_token_account: Pubkey,
+    /// The issue fee quoted when the request was made, which is what the subject pays
+    pub amount: u64,
+    /// The time after which the subject can reclaim a pending request's escrow
+    pub deadline: i64,
+    /// The state of this request
+    pub state: PassRequestState,
+    /// The bump for the signer of this account
+    pub bump: u8,
+}
+
+impl PassRequest {
+    pub fn is_for(
+        &self,
+        subject: &Pubkey,
+        network: &Pubkey,
+        gatekeeper: &Pubkey,
+        mint: &Pubkey,
+    ) -> bool {
+        self.subject == *subject
+            && self.network == *network
+            && self.gatekeeper == *gatekeeper
+            && self.mint == *mint
+    }
+
+    /// A request can be closed by the subject once it is fulfilled or its deadline has passed
+    pub fn can_reclaim(&self, now: i64) -> bool {
+        match self.state {
+            PassRequestState::Pending => now > self.deadline,
+            PassRequestState::Fulfilled => true,
+        }
+    }
+}
+
+/// The state of a [`PassRequest`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, AnchorDeserialize, AnchorSerialize, InitSpace)]
+pub enum PassRequestState {
+    /// Waiting for the gatekeeper, the fee is in escrow
+    Pending,
+    /// The pass was issued and the fee paid out of escrow
+    Fulfilled,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_reclaim() {
+        let mut pass_request = PassRequest {
+            version: 0,
+            subject: Pubkey::new_unique(),
+            network: Pubkey::new_unique(),
+            gatekeeper: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            escrow: Pubkey::new_unique(),
+            subject_token_account: Pubkey::new_unique(),
+            amount: 100,
+            deadline: 1000,
+            state: PassRequestState::Pending,
+            bump: 0,
+        };
+
+        assert!(!pass_request.can_reclaim(1000));
+        assert!(pass_request.can_reclaim(1001));
+
+        pass_request.state = PassRequestState::Fulfilled;
+        assert!(pass_request.can_reclaim(0));
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Marks a [`crate::voucher::PassVoucher`] nonce as used, so each voucher is only redeemed once.
+/// It can be closed once the voucher has expired, as it can no longer be redeemed.
+#[derive(Debug, InitSpace)]
+#[account]
+pub struct RedeemedVoucher {
+    /// The version of this struct, should be 0 until a new version is released
+    pub version: u8,
+    /// The pass the voucher was redeemed for
+    pub pass: Pubkey,
+    /// The account that paid the rent, and receives it when this account is closed
+    pub payer: Pubkey,
+    /// The [`crate::voucher::PassVoucher::expiry`]
+    pub expiry: i64,
+    /// The bump for the signer of this account
+    pub bump: u8,
+}
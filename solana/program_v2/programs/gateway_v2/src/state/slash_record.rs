@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// Evidence recorded when a [`crate::state::GatekeeperNetwork`] slashes a
+/// [`crate::state::Gatekeeper`]'s stake
+#[derive(Debug, InitSpace)]
+#[account]
+pub struct SlashRecord {
+    /// The version of this struct, should be 0 until a new version is released
+    pub version: u8,
+    /// The gatekeeper that was slashed
+    pub gatekeeper: Pubkey,
+    /// The network that slashed the gatekeeper
+    pub network: Pubkey,
+    /// The network key that authorised the slash
+    pub authority: Pubkey,
+    /// The amount of stake moved to the network
+    pub amount: u64,
+    /// A network-defined code for the reason of the slash
+    pub reason: u16,
+    /// A hash of the off-chain evidence for the slash
+    pub evidence_hash: [u8; 32],
+    /// A hash of the list of passes affected by the gatekeeper's misbehaviour
+    pub affected_passes_hash: [u8; 32],
+    /// The time of the slash
+    pub timestamp: i64,
+    /// The bump for the signer of this account
+    pub bump: u8,
+}
@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SponsorshipErrors;
+use crate::util::{
+    OC_SIZE_DISCRIMINATOR, OC_SIZE_PUBKEY, OC_SIZE_U16, OC_SIZE_U64, OC_SIZE_U8, OC_SIZE_VEC_PREFIX,
+};
+
+/// A fee budget deposited by a dApp, from which pass fees are paid without the dApp signing
+#[derive(Debug)]
+#[account]
+pub struct Sponsorship {
+    /// The version of this struct, should be 0 until a new version is released
+    pub version: u8,
+    /// The dApp that funds the sponsorship, and the only key that can withdraw from it
+    pub sponsor: Pubkey,
+    /// Distinguishes sponsorships created by the same sponsor
+    pub index: u16,
+    /// The mint of the tokens held for fees
+    pub mint: Pubkey,
+    /// The token account holding the budget, owned by this account
+    pub vault: Pubkey,
+    /// The networks whose passes may be sponsored, empty for any network
+    pub networks: Vec<Pubkey>,
+    /// The gatekeepers whose fees may be sponsored, empty for none
+    pub gatekeepers: Vec<Pubkey>,
+    /// The most that may be spent on a single subject, `0` for no limit
+    pub per_subject_cap: u64,
+    /// The time after which no more fees are paid, `0` if the sponsorship never expires
+    pub expires_at: i64,
+    /// The bump for the signer of this account
+    pub bump: u8,
+}
+
+impl Sponsorship {
+    pub fn size(networks: usize, gatekeepers: usize) -> usize {
+        OC_SIZE_DISCRIMINATOR
+            + OC_SIZE_U8 // version
+            + OC_SIZE_PUBKEY // sponsor
+            + OC_SIZE_U16 // index
+            + OC_SIZE_PUBKEY // mint
+            + OC_SIZE_PUBKEY // vault
+            + OC_SIZE_VEC_PREFIX + OC_SIZE_PUBKEY * networks // networks
+            + OC_SIZE_VEC_PREFIX + OC_SIZE_PUBKEY * gatekeepers // gatekeepers
+            + OC_SIZE_U64 // per_subject_cap
+            + OC_SIZE_U64 // expires_at
+            + OC_SIZE_U8 // bump
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now > self.expires_at
+    }
+
+    pub fn is_network_allowed(&self, network: &Pubkey) -> bool {
+        self.networks.is_empty() || self.networks.contains(network)
+    }
+
+    pub fn is_gatekeeper_allowed(&self, gatekeeper: &Pubkey) -> bool {
+        self.gatekeepers.contains(gatekeeper)
+    }
+
+    /// Records `amount` as spent on the subject, if the sponsorship's rules allow it
+    pub fn draw(
+        &self,
+        sponsored_subject: &mut SponsoredSubject,
+        network: &Pubkey,
+        gatekeeper: &Pubkey,
+        amount: u64,
+        now: i64,
+    ) -> Result<()> {
+        require!(!self.is_expired(now), SponsorshipErrors::SponsorshipExpired);
+        require!(
+            self.is_network_allowed(network),
+            SponsorshipErrors::NetworkNotSponsored
+        );
+        require!(
+            self.is_gatekeeper_allowed(gatekeeper),
+            SponsorshipErrors::GatekeeperNotSponsored
+        );
+
+        let spent = sponsored_subject
+            .spent
+            .checked_add(amount)
+            .ok_or(SponsorshipErrors::SubjectCapExceeded)?;
+        require!(
+            self.per_subject_cap == 0 || spent <= self.per_subject_cap,
+            SponsorshipErrors::SubjectCapExceeded
+        );
+
+        sponsored_subject.spent = spent;
+
+        Ok(())
+    }
+}
+
+/// Tracks how much a [`Sponsorship`] has spent on a single subject
+#[derive(Debug, InitSpace)]
+#[account]
+pub struct SponsoredSubject {
+    /// The version of this struct, should be 0 until a new version is released
+    pub version: u8,
+    /// The sponsorship paying the subject's fees
+    pub sponsorship: Pubkey,
+    /// The subject whose fees are paid
+    pub subject: Pubkey,
+    /// The total fees paid for the subject so far
+    pub spent: u64,
+    /// The bump for the signer of this account
+    pub bump: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_sponsorship(networks: Vec<Pubkey>, gatekeepers: Vec<Pubkey>) -> Sponsorship {
+        Sponsorship {
+            version: 0,
+            sponsor: Pubkey::new_unique(),
+            index: 0,
+            mint: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            networks,
+            gatekeepers,
+            per_subject_cap: 100,
+            expires_at: 1000,
+            bump: 0,
+        }
+    }
+
+    fn make_sponsored_subject() -> SponsoredSubject {
+        SponsoredSubject {
+            version: 0,
+            sponsorship: Pubkey::new_unique(),
+            subject: Pubkey::new_unique(),
+            spent: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_draw_within_cap() {
+        let network = Pubkey::new_unique();
+        let gatekeeper = Pubkey::new_unique();
+        let sponsorship = make_sponsorship(vec![network], vec![gatekeeper]);
+        let mut sponsored_subject = make_sponsored_subject();
+
+        sponsorship
+            .draw(&mut sponsored_subject, &network, &gatekeeper, 60, 500)
+            .unwrap();
+        sponsorship
+            .draw(&mut sponsored_subject, &network, &gatekeeper, 40, 500)
+            .unwrap();
+        assert_eq!(sponsored_subject.spent, 100);
+
+        assert!(sponsorship
+            .draw(&mut sponsored_subject, &network, &gatekeeper, 1, 500)
+            .is_err());
+        assert_eq!(sponsored_subject.spent, 100);
+    }
+
+    #[test]
+    fn test_draw_checks_rules() {
+        let network = Pubkey::new_unique();
+        let gatekeeper = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let sponsorship = make_sponsorship(vec![network], vec![gatekeeper]);
+        let mut sponsored_subject = make_sponsored_subject();
+
+        assert_eq!(
+            sponsorship.draw(&mut sponsored_subject, &other, &gatekeeper, 1, 500),
+            Err(error!(SponsorshipErrors::NetworkNotSponsored))
+        );
+        assert_eq!(
+            sponsorship.draw(&mut sponsored_subject, &network, &other, 1, 500),
+            Err(error!(SponsorshipErrors::GatekeeperNotSponsored))
+        );
+        assert_eq!(
+            sponsorship.draw(&mut sponsored_subject, &network, &gatekeeper, 1, 1001),
+            Err(error!(SponsorshipErrors::SponsorshipExpired))
+        );
+        assert_eq!(sponsored_subject.spent, 0);
+    }
+
+    #[test]
+    fn test_draw_without_gatekeepers() {
+        let network = Pubkey::new_unique();
+        let sponsorship = make_sponsorship(vec![network], vec![]);
+        let mut sponsored_subject = make_sponsored_subject();
+
+        assert_eq!(
+            sponsorship.draw(
+                &mut sponsored_subject,
+                &network,
+                &Pubkey::new_unique(),
+                1,
+                500
+            ),
+            Err(error!(SponsorshipErrors::GatekeeperNotSponsored))
+        );
+    }
+
+    #[test]
+    fn test_unlimited_sponsorship() {
+        let gatekeeper = Pubkey::new_unique();
+        let mut sponsorship = make_sponsorship(vec![], vec![gatekeeper]);
+        sponsorship.per_subject_cap = 0;
+        sponsorship.expires_at = 0;
+        let mut sponsored_subject = make_sponsored_subject();
+
+        sponsorship
+            .draw(
+                &mut sponsored_subject,
+                &Pubkey::new_unique(),
+                &gatekeeper,
+                u64::MAX,
+                i64::MAX,
+            )
+            .unwrap();
+        assert_eq!(sponsored_subject.spent, u64::MAX);
+    }
+}
@@ -3,15 +3,27 @@ use std::ops::{Div, Mul};
 
 use crate::constants::MAX_NETWORK_FEE;
 use anchor_lang::context::CpiContext;
-use anchor_lang::prelude::{Interface, InterfaceAccount, Pubkey, Signer};
-use anchor_lang::ToAccountInfo;
+use anchor_lang::prelude::{
+    error, Account, AccountInfo, Clock, Interface, InterfaceAccount, Pubkey, Rent, Signer,
+    SolanaSysvar,
+};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::{Key, ToAccountInfo};
 use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
 };
 use solana_program::entrypoint::ProgramResult;
 
-use crate::errors::{GatekeeperErrors, NetworkErrors};
-use crate::state::{GatekeeperAuthKey, GatekeeperFees, GatekeeperKeyFlags, NetworkFeesPercentage};
+use crate::constants::{
+    FEE_CREDIT_SEED, GATEKEEPER_SEED, NETWORK_SEED, PASS_REQUEST_SEED, SPONSORSHIP_SEED,
+};
+use crate::errors::{GatekeeperErrors, NetworkErrors, SponsorshipErrors};
+use crate::oracle::{convert_reference_amount, load_price};
+use crate::state::{
+    FeeCredit, Gatekeeper, GatekeeperAuthKey, GatekeeperFees, GatekeeperKeyFlags,
+    GatekeeperNetwork, NetworkFeesPercentage, PassRequest, SponsoredSubject, Sponsorship,
+};
 
 // pub const OC_SIZE_BOOL: usize = 1;
 pub const OC_SIZE_U8: usize = 1;
@@ -21,7 +33,7 @@ pub const OC_SIZE_U64: usize = 8;
 // pub const OC_SIZE_U128: usize = 16;
 pub const OC_SIZE_PUBKEY: usize = 32;
 pub const OC_SIZE_VEC_PREFIX: usize = 4;
-// pub const OC_SIZE_STRING_PREFIX: usize = 4;
+pub const OC_SIZE_STRING_PREFIX: usize = 4;
 pub const OC_SIZE_DISCRIMINATOR: usize = 8;
 // pub const OC_SIZE_TIMESTAMP: usize = 8;
 
@@ -33,27 +45,76 @@ pub fn validate_fees_within_bounds(fees: &[NetworkFeesPercentage]) -> bool {
             && fee.refresh <= MAX_NETWORK_FEE
             && fee.expire <= MAX_NETWORK_FEE
             && fee.verify <= MAX_NETWORK_FEE
+            && fee.issue as u32 + fee.issue_referrer as u32 <= MAX_NETWORK_FEE as u32
+            && fee.verify as u32 + fee.verify_referrer as u32 <= MAX_NETWORK_FEE as u32
     })
 }
 
+/// Returns the gatekeeper fees for the mint in force at `now`
 pub fn get_gatekeeper_fees(
     fees: &[GatekeeperFees],
     mint: Pubkey,
+    now: i64,
 ) -> Result<&GatekeeperFees, GatekeeperErrors> {
     fees.iter()
-        .find(|&&x| x.token == mint)
+        .filter(|&&x| x.token == mint && x.effective_from <= now)
+        .max_by_key(|x| x.effective_from)
         .ok_or(GatekeeperErrors::FeesNotProvided)
 }
 
+/// Returns the network fees for the mint in force at `now`
 pub fn get_network_fees(
     fees: &[NetworkFeesPercentage],
     mint: Pubkey,
+    now: i64,
 ) -> Result<&NetworkFeesPercentage, NetworkErrors> {
     fees.iter()
-        .find(|&&x| x.token == mint)
+        .filter(|&&x| x.token == mint && x.effective_from <= now)
+        .max_by_key(|x| x.effective_from)
         .ok_or(NetworkErrors::FeesNotProvided)
 }
 
+/// The pass operations a fee can be charged for
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FeeOperation {
+    Issue,
+    Refresh,
+    Expire,
+    Verify,
+}
+
+/// Returns the gatekeeper fee for an operation in base units of `mint`. Fixed fees for the mint
+/// take precedence, otherwise the gatekeeper's reference fees are converted using the price feed
+/// the network approved for the mint. Either fee has to be within the network's fee bounds.
+pub fn get_gatekeeper_fee(
+    gatekeeper: &Gatekeeper,
+    network: &GatekeeperNetwork,
+    mint: &InterfaceAccount<Mint>,
+    price_account: Option<&AccountInfo>,
+    operation: FeeOperation,
+) -> anchor_lang::Result<u64> {
+    let clock = Clock::get()?;
+
+    if let Ok(fees) = get_gatekeeper_fees(&gatekeeper.token_fees, mint.key(), clock.unix_timestamp)
+    {
+        return network.check_gatekeeper_fee(&mint.key(), operation, fees.fee(operation));
+    }
+
+    let reference_fees = gatekeeper
+        .reference_fees
+        .ok_or(GatekeeperErrors::FeesNotProvided)?;
+    let feed = network.get_price_feed(&mint.key())?;
+    let price_account = price_account
+        .filter(|account| account.key() == feed.price_account)
+        .ok_or_else(|| error!(NetworkErrors::InvalidPriceFeed))?;
+
+    let price = load_price(&price_account.try_borrow_data()?, feed, clock.slot)?;
+
+    let fee = convert_reference_amount(&price, reference_fees.fee(operation), mint.decimals)?;
+
+    network.check_gatekeeper_fee(&mint.key(), operation, fee)
+}
+
 /// calculate_network_and_gatekeeper_fee
 /// Returns two fees in the correct unit
 /// First result returns the fee for the network_fee
@@ -67,6 +128,21 @@ pub fn calculate_network_and_gatekeeper_fee(fee: u64, percent: u16) -> (u64, u64
     (network_fee as u64, gatekeeper_fee)
 }
 
+/// Splits a fee between the network, the gatekeeper and a referrer. The referrer's share is
+/// taken out of the gatekeeper's share.
+/// Returns `(network_fee, gatekeeper_fee, referrer_fee)`
+pub fn calculate_network_gatekeeper_and_referrer_fee(
+    fee: u64,
+    network_percent: u16,
+    referrer_percent: u16,
+) -> (u64, u64, u64) {
+    let (network_fee, gatekeeper_fee) = calculate_network_and_gatekeeper_fee(fee, network_percent);
+    let (referrer_fee, _) = calculate_network_and_gatekeeper_fee(fee, referrer_percent);
+    let referrer_fee = referrer_fee.min(gatekeeper_fee);
+
+    (network_fee, gatekeeper_fee - referrer_fee, referrer_fee)
+}
+
 pub fn create_and_invoke_transfer<'a>(
     spl_token_address: Interface<'a, TokenInterface>,
     source_account: InterfaceAccount<'a, TokenAccount>,
@@ -91,6 +167,414 @@ pub fn create_and_invoke_transfer<'a>(
     Ok(())
 }
 
+/// Transfers tokens out of an account owned by the gatekeeper, signing with the gatekeeper's seeds
+pub fn invoke_gatekeeper_transfer<'a>(
+    gatekeeper: &Account<'a, Gatekeeper>,
+    spl_token_program: &Interface<'a, TokenInterface>,
+    source_account: &InterfaceAccount<'a, TokenAccount>,
+    destination_account: &InterfaceAccount<'a, TokenAccount>,
+    mint: &InterfaceAccount<'a, Mint>,
+    amount: u64,
+) -> ProgramResult {
+    let gatekeeper_authority_key = gatekeeper.subject;
+    let gatekeeper_bump = gatekeeper.gatekeeper_bump.to_le_bytes();
+
+    let authority_seed = &[
+        GATEKEEPER_SEED.as_ref(),
+        gatekeeper_authority_key.as_ref(),
+        gatekeeper.gatekeeper_network.as_ref(),
+        gatekeeper_bump.as_ref(),
+    ][..];
+
+    let accounts_checked = TransferChecked {
+        from: source_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: destination_account.to_account_info(),
+        authority: gatekeeper.to_account_info(),
+    };
+
+    let signer = &[authority_seed][..];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            spl_token_program.to_account_info(),
+            accounts_checked,
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    Ok(())
+}
+
+/// Transfers tokens out of an account owned by an indexed network, signing with the network's seeds
+pub fn invoke_network_transfer<'a>(
+    network: &Account<'a, GatekeeperNetwork>,
+    spl_token_program: &Interface<'a, TokenInterface>,
+    source_account: &InterfaceAccount<'a, TokenAccount>,
+    destination_account: &InterfaceAccount<'a, TokenAccount>,
+    mint: &InterfaceAccount<'a, Mint>,
+    amount: u64,
+) -> ProgramResult {
+    let network_index = network.network_index.to_le_bytes();
+    let signer_bump = network.signer_bump.to_le_bytes();
+
+    let authority_seed = &[
+        NETWORK_SEED.as_ref(),
+        network.authority.as_ref(),
+        network_index.as_ref(),
+        signer_bump.as_ref(),
+    ][..];
+
+    let accounts_checked = TransferChecked {
+        from: source_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: destination_account.to_account_info(),
+        authority: network.to_account_info(),
+    };
+
+    let signer = &[authority_seed][..];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            spl_token_program.to_account_info(),
+            accounts_checked,
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    Ok(())
+}
+
+/// The source of the tokens paying a pass fee
+pub enum FeePayer<'a> {
+    /// A signer paying from their own token account
+    Funder {
+        funder: Signer<'a>,
+        token_account: InterfaceAccount<'a, TokenAccount>,
+    },
+    /// A [`Sponsorship`] paying from its vault, signing with the sponsorship's seeds
+    Sponsorship {
+        sponsorship: Box<Account<'a, Sponsorship>>,
+        vault: InterfaceAccount<'a, TokenAccount>,
+    },
+    /// A [`PassRequest`] paying from its escrow, signing with the request's seeds
+    PassRequest {
+        pass_request: Box<Account<'a, PassRequest>>,
+        escrow: InterfaceAccount<'a, TokenAccount>,
+    },
+}
+
+impl<'a> FeePayer<'a> {
+    /// Picks the sponsorship when one is provided, falling back to the funder
+    pub fn new(
+        funder: &Option<Signer<'a>>,
+        funder_token_account: &Option<InterfaceAccount<'a, TokenAccount>>,
+        sponsorship: &Option<Box<Account<'a, Sponsorship>>>,
+        sponsorship_vault: &Option<InterfaceAccount<'a, TokenAccount>>,
+    ) -> anchor_lang::Result<Self> {
+        match (sponsorship, sponsorship_vault, funder, funder_token_account) {
+            (Some(sponsorship), Some(vault), _, _) => {
+                if vault.key() != sponsorship.vault {
+                    return Err(error!(SponsorshipErrors::InvalidSponsorship));
+                }
+
+                Ok(FeePayer::Sponsorship {
+                    sponsorship: sponsorship.to_owned(),
+                    vault: vault.to_owned(),
+                })
+            }
+            (None, None, Some(funder), Some(token_account)) => Ok(FeePayer::Funder {
+                funder: funder.to_owned(),
+                token_account: token_account.to_owned(),
+            }),
+            _ => Err(error!(SponsorshipErrors::NoFeePayer)),
+        }
+    }
+
+    /// The token account the fees are paid from
+    pub fn token_account(&self) -> Pubkey {
+        match self {
+            FeePayer::Funder { token_account, .. } => token_account.key(),
+            FeePayer::Sponsorship { vault, .. } => vault.key(),
+            // The escrow is closed once the request is settled, so refunds go back to the subject
+            FeePayer::PassRequest { pass_request, .. } => pass_request.subject_token_account,
+        }
+    }
+
+    pub fn transfer(
+        &self,
+        spl_token_program: &Interface<'a, TokenInterface>,
+        destination_account: &InterfaceAccount<'a, TokenAccount>,
+        mint: &InterfaceAccount<'a, Mint>,
+        amount: u64,
+    ) -> ProgramResult {
+        match self {
+            FeePayer::Funder {
+                funder,
+                token_account,
+            } => create_and_invoke_transfer(
+                spl_token_program.to_owned(),
+                token_account.to_owned(),
+                destination_account.to_owned(),
+                mint.to_owned(),
+                funder.to_owned(),
+                amount,
+            ),
+            FeePayer::Sponsorship { sponsorship, vault } => invoke_sponsorship_transfer(
+                sponsorship,
+                spl_token_program,
+                vault,
+                destination_account,
+                mint,
+                amount,
+            ),
+            FeePayer::PassRequest {
+                pass_request,
+                escrow,
+            } => invoke_pass_request_transfer(
+                pass_request,
+                spl_token_program,
+                escrow,
+                destination_account,
+                mint,
+                amount,
+            ),
+        }
+    }
+}
+
+/// Transfers tokens out of a sponsorship's vault, signing with the sponsorship's seeds
+pub fn invoke_sponsorship_transfer<'a>(
+    sponsorship: &Account<'a, Sponsorship>,
+    spl_token_program: &Interface<'a, TokenInterface>,
+    source_account: &InterfaceAccount<'a, TokenAccount>,
+    destination_account: &InterfaceAccount<'a, TokenAccount>,
+    mint: &InterfaceAccount<'a, Mint>,
+    amount: u64,
+) -> ProgramResult {
+    let index = sponsorship.index.to_le_bytes();
+    let bump = sponsorship.bump.to_le_bytes();
+
+    let authority_seed = &[
+        SPONSORSHIP_SEED.as_ref(),
+        sponsorship.sponsor.as_ref(),
+        index.as_ref(),
+        bump.as_ref(),
+    ][..];
+
+    let accounts_checked = TransferChecked {
+        from: source_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: destination_account.to_account_info(),
+        authority: sponsorship.to_account_info(),
+    };
+
+    let signer = &[authority_seed][..];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            spl_token_program.to_account_info(),
+            accounts_checked,
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    Ok(())
+}
+
+/// Transfers tokens out of a fee credit's vault, signing with the fee credit's seeds
+pub fn invoke_fee_credit_transfer<'a>(
+    fee_credit: &Account<'a, FeeCredit>,
+    spl_token_program: &Interface<'a, TokenInterface>,
+    source_account: &InterfaceAccount<'a, TokenAccount>,
+    destination_account: &InterfaceAccount<'a, TokenAccount>,
+    mint: &InterfaceAccount<'a, Mint>,
+    amount: u64,
+) -> ProgramResult {
+    let bump = fee_credit.bump.to_le_bytes();
+
+    let authority_seed = &[
+        FEE_CREDIT_SEED.as_ref(),
+        fee_credit.funder.as_ref(),
+        fee_credit.gatekeeper.as_ref(),
+        fee_credit.mint.as_ref(),
+        bump.as_ref(),
+    ][..];
+
+    let accounts_checked = TransferChecked {
+        from: source_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: destination_account.to_account_info(),
+        authority: fee_credit.to_account_info(),
+    };
+
+    let signer = &[authority_seed][..];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            spl_token_program.to_account_info(),
+            accounts_checked,
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    Ok(())
+}
+
+/// Transfers tokens out of a pass request's escrow, signing with the request's seeds
+pub fn invoke_pass_request_transfer<'a>(
+    pass_request: &Account<'a, PassRequest>,
+    spl_token_program: &Interface<'a, TokenInterface>,
+    source_account: &InterfaceAccount<'a, TokenAccount>,
+    destination_account: &InterfaceAccount<'a, TokenAccount>,
+    mint: &InterfaceAccount<'a, Mint>,
+    amount: u64,
+) -> ProgramResult {
+    let bump = pass_request.bump.to_le_bytes();
+
+    let authority_seed = &[
+        PASS_REQUEST_SEED.as_ref(),
+        pass_request.subject.as_ref(),
+        pass_request.gatekeeper.as_ref(),
+        bump.as_ref(),
+    ][..];
+
+    let accounts_checked = TransferChecked {
+        from: source_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: destination_account.to_account_info(),
+        authority: pass_request.to_account_info(),
+    };
+
+    let signer = &[authority_seed][..];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            spl_token_program.to_account_info(),
+            accounts_checked,
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    Ok(())
+}
+
+/// Refunds whatever is left in a pass request's escrow to the subject, then closes the escrow
+pub fn close_pass_request_escrow<'a>(
+    pass_request: &Account<'a, PassRequest>,
+    spl_token_program: &Interface<'a, TokenInterface>,
+    escrow: &InterfaceAccount<'a, TokenAccount>,
+    subject_token_account: &InterfaceAccount<'a, TokenAccount>,
+    subject: &AccountInfo<'a>,
+    mint: &InterfaceAccount<'a, Mint>,
+) -> ProgramResult {
+    if escrow.amount > 0 {
+        invoke_pass_request_transfer(
+            pass_request,
+            spl_token_program,
+            escrow,
+            subject_token_account,
+            mint,
+            escrow.amount,
+        )?;
+    }
+
+    let bump = pass_request.bump.to_le_bytes();
+
+    let authority_seed = &[
+        PASS_REQUEST_SEED.as_ref(),
+        pass_request.subject.as_ref(),
+        pass_request.gatekeeper.as_ref(),
+        bump.as_ref(),
+    ][..];
+
+    let accounts = CloseAccount {
+        account: escrow.to_account_info(),
+        destination: subject.to_owned(),
+        authority: pass_request.to_account_info(),
+    };
+
+    let signer = &[authority_seed][..];
+    close_account(CpiContext::new_with_signer(
+        spl_token_program.to_account_info(),
+        accounts,
+        signer,
+    ))?;
+
+    Ok(())
+}
+
+/// Charges `amount` against the subject's share of the sponsorship, if a sponsorship is paying
+pub fn draw_sponsored_fee(
+    sponsorship: &Option<Box<Account<Sponsorship>>>,
+    sponsored_subject: &mut Option<Box<Account<SponsoredSubject>>>,
+    subject: &Pubkey,
+    network: &Pubkey,
+    gatekeeper: &Pubkey,
+    amount: u64,
+) -> anchor_lang::Result<()> {
+    let Some(sponsorship) = sponsorship else {
+        return Ok(());
+    };
+
+    let sponsored_subject = sponsored_subject
+        .as_mut()
+        .ok_or(SponsorshipErrors::InvalidSponsorship)?;
+    if sponsored_subject.sponsorship != sponsorship.key() || sponsored_subject.subject != *subject {
+        return Err(error!(SponsorshipErrors::InvalidSponsorship));
+    }
+
+    sponsorship.draw(
+        sponsored_subject,
+        network,
+        gatekeeper,
+        amount,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Resizes a program-owned account to `new_size`, keeping it rent exempt. The payer covers the
+/// rent for growth, and the rent freed by shrinking the account goes to `refund_receiver`.
+pub fn resize_account<'a>(
+    account: &AccountInfo<'a>,
+    new_size: usize,
+    payer: &AccountInfo<'a>,
+    refund_receiver: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> anchor_lang::Result<()> {
+    let rent = Rent::get()?;
+    let old_size = account.data_len();
+    let required = rent.minimum_balance(new_size);
+    let current = account.lamports();
+
+    if required > current {
+        transfer(
+            CpiContext::new(
+                system_program.to_owned(),
+                Transfer {
+                    from: payer.to_owned(),
+                    to: account.to_owned(),
+                },
+            ),
+            required - current,
+        )?;
+    } else if new_size < old_size {
+        let freed = rent.minimum_balance(old_size) - required;
+        let refund = freed.min(current - required);
+        **account.try_borrow_mut_lamports()? -= refund;
+        **refund_receiver.try_borrow_mut_lamports()? += refund;
+    }
+
+    account.realloc(new_size, false)?;
+
+    Ok(())
+}
+
 pub fn check_gatekeeper_auth_threshold(
     auth_keys: &[GatekeeperAuthKey],
     auth_threshold: u8,
@@ -107,6 +591,8 @@ pub fn check_gatekeeper_auth_threshold(
 
 #[cfg(test)]
 mod tests {
+    use anchor_lang::prelude::Pubkey;
+
     use crate::state::{GatekeeperAuthKey, GatekeeperFees, NetworkFeesPercentage};
     use crate::util::{check_gatekeeper_auth_threshold, validate_fees_within_bounds};
 
@@ -119,6 +605,9 @@ mod tests {
             refresh: 8,
             expire: 8,
             verify: 8,
+            issue_referrer: 0,
+            verify_referrer: 0,
+            effective_from: 0,
         };
 
         let fee2 = NetworkFeesPercentage {
@@ -127,6 +616,9 @@ mod tests {
             refresh: 5,
             expire: 3,
             verify: 10001,
+            issue_referrer: 0,
+            verify_referrer: 0,
+            effective_from: 0,
         };
 
         assert!(validate_fees_within_bounds(&[fee1]));
@@ -161,6 +653,18 @@ mod tests {
         assert_eq!(fees.1, 100);
     }
 
+    #[test]
+    fn get_fees_test_referrer_split() {
+        let fees = crate::util::calculate_network_gatekeeper_and_referrer_fee(1000, 500, 1000);
+        assert_eq!(fees, (50, 850, 100));
+    }
+
+    #[test]
+    fn get_fees_test_referrer_capped_by_gatekeeper_share() {
+        let fees = crate::util::calculate_network_gatekeeper_and_referrer_fee(1000, 9000, 5000);
+        assert_eq!(fees, (900, 0, 100));
+    }
+
     #[test]
     fn get_gatekeeper_fees_test() {
         let mint = "wLYV8imcPhPDZ3JJvUgSWv2p6PNz4RfFtveqn4esJGX"
@@ -171,6 +675,7 @@ mod tests {
             issue: 100,
             verify: 10,
             refresh: 10,
+            effective_from: 0,
             expire: 10,
         };
         let fee2 = GatekeeperFees {
@@ -180,13 +685,52 @@ mod tests {
             issue: 0,
             verify: 0,
             refresh: 0,
+            effective_from: 0,
             expire: 0,
         };
         let fees: Vec<GatekeeperFees> = vec![fee1, fee2];
-        let fee = crate::util::get_gatekeeper_fees(&fees, mint).unwrap();
+        let fee = crate::util::get_gatekeeper_fees(&fees, mint, 0).unwrap();
         assert_eq!(fee, &fee1);
     }
 
+    #[test]
+    fn get_gatekeeper_fees_in_force() {
+        let mint = Pubkey::new_unique();
+        let current = GatekeeperFees {
+            token: mint,
+            issue: 100,
+            refresh: 10,
+            expire: 10,
+            verify: 10,
+            effective_from: 1000,
+        };
+        let previous = GatekeeperFees {
+            issue: 50,
+            effective_from: 0,
+            ..current
+        };
+        let scheduled = GatekeeperFees {
+            issue: 200,
+            effective_from: 2000,
+            ..current
+        };
+        let fees = vec![scheduled, previous, current];
+
+        assert_eq!(
+            crate::util::get_gatekeeper_fees(&fees, mint, 999).unwrap(),
+            &previous
+        );
+        assert_eq!(
+            crate::util::get_gatekeeper_fees(&fees, mint, 1999).unwrap(),
+            &current
+        );
+        assert_eq!(
+            crate::util::get_gatekeeper_fees(&fees, mint, 2000).unwrap(),
+            &scheduled
+        );
+        assert!(crate::util::get_gatekeeper_fees(&fees[..1], mint, 1999).is_err());
+    }
+
     #[test]
     fn get_network_fees() {
         let mint = "wLYV8imcPhPDZ3JJvUgSWv2p6PNz4RfFtveqn4esJGX"
@@ -198,6 +742,9 @@ mod tests {
             verify: 10,
             refresh: 10,
             expire: 10,
+            issue_referrer: 0,
+            verify_referrer: 0,
+            effective_from: 0,
         };
         let fee2 = NetworkFeesPercentage {
             token: "wLYV8imcPhPDZ3JJvUgSWv2p6PNz4RfFtvdqn4esJGX"
@@ -207,9 +754,12 @@ mod tests {
             verify: 0,
             refresh: 0,
             expire: 0,
+            issue_referrer: 0,
+            verify_referrer: 0,
+            effective_from: 0,
         };
         let fees: Vec<NetworkFeesPercentage> = vec![fee1, fee2];
-        let fee = crate::util::get_network_fees(&fees, mint).unwrap();
+        let fee = crate::util::get_network_fees(&fees, mint, 0).unwrap();
         assert_eq!(fee, &fee1);
     }
 
@@ -0,0 +1,183 @@
+//! Off-chain pass vouchers, signed by a gatekeeper key with
+//! [`crate::state::GatekeeperKeyFlags::ISSUE`] and redeemed on-chain by checking an Ed25519
+//! program instruction in the same transaction.
+use anchor_lang::prelude::*;
+use solana_program::ed25519_program;
+use solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::errors::PassErrors;
+
+// Layout of the Ed25519 program's instruction data
+const ED25519_HEADER_SIZE: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+const ED25519_PUBKEY_SIZE: usize = 32;
+// An instruction index pointing at the Ed25519 instruction itself
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// The message a gatekeeper signs to let the subject redeem a pass without the gatekeeper online
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct PassVoucher {
+    /// The subject the pass is issued to
+    pub subject: Pubkey,
+    /// The network the pass is issued on
+    pub network: Pubkey,
+    /// The gatekeeper the pass is issued by, whose key signs the voucher
+    pub gatekeeper: Pubkey,
+    /// The number of the issued pass
+    pub pass_number: u16,
+    /// The time after which the voucher can no longer be redeemed
+    pub expiry: i64,
+    /// Stored in [`crate::state::Pass::gatekeeper_data`]
+    pub data: [u8; 32],
+    /// Unique per gatekeeper, so each voucher can only be redeemed once
+    pub nonce: u64,
+}
+
+impl PassVoucher {
+    /// The bytes signed by the gatekeeper, the voucher's borsh serialization
+    pub fn message(&self) -> Vec<u8> {
+        self.try_to_vec().unwrap()
+    }
+
+    /// Returns the key that signed this voucher in the Ed25519 instruction just before the
+    /// current one
+    pub fn load_signer(&self, instructions_sysvar: &AccountInfo) -> Result<Pubkey> {
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        let verify_index = current_index
+            .checked_sub(1)
+            .ok_or(PassErrors::InvalidVoucherSignature)?;
+        let instruction = load_instruction_at_checked(verify_index as usize, instructions_sysvar)?;
+
+        require_keys_eq!(
+            instruction.program_id,
+            ed25519_program::ID,
+            PassErrors::InvalidVoucherSignature
+        );
+
+        let (signer, message) = parse_ed25519_instruction(&instruction.data)?;
+        require!(
+            message == self.message().as_slice(),
+            PassErrors::InvalidVoucherSignature
+        );
+
+        Ok(signer)
+    }
+}
+
+/// Reads the key and message from Ed25519 program instruction data carrying a single signature
+/// whose key and message are stored in the same instruction. The Ed25519 program has already
+/// checked the signature by the time this instruction runs.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Result<(Pubkey, &[u8])> {
+    require!(
+        data.len() >= ED25519_HEADER_SIZE + ED25519_OFFSETS_SIZE && data[0] == 1,
+        PassErrors::InvalidVoucherSignature
+    );
+
+    let read_u16 = |index: usize| {
+        let offset = ED25519_HEADER_SIZE + index * 2;
+        u16::from_le_bytes([data[offset], data[offset + 1]])
+    };
+    let signature_instruction_index = read_u16(1);
+    let public_key_offset = read_u16(2) as usize;
+    let public_key_instruction_index = read_u16(3);
+    let message_data_offset = read_u16(4) as usize;
+    let message_data_size = read_u16(5) as usize;
+    let message_instruction_index = read_u16(6);
+
+    require!(
+        signature_instruction_index == ED25519_CURRENT_INSTRUCTION
+            && public_key_instruction_index == ED25519_CURRENT_INSTRUCTION
+            && message_instruction_index == ED25519_CURRENT_INSTRUCTION,
+        PassErrors::InvalidVoucherSignature
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + ED25519_PUBKEY_SIZE)
+        .ok_or(PassErrors::InvalidVoucherSignature)?;
+    let message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(PassErrors::InvalidVoucherSignature)?;
+
+    Ok((Pubkey::try_from(public_key).unwrap(), message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds the same layout as `solana_sdk::ed25519_instruction::new_ed25519_instruction`
+    fn make_instruction_data(
+        public_key: &Pubkey,
+        message: &[u8],
+        instruction_index: u16,
+    ) -> Vec<u8> {
+        let public_key_offset = ED25519_HEADER_SIZE + ED25519_OFFSETS_SIZE;
+        let signature_offset = public_key_offset + ED25519_PUBKEY_SIZE;
+        let message_data_offset = signature_offset + 64;
+
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset as u16,
+            instruction_index,
+            public_key_offset as u16,
+            instruction_index,
+            message_data_offset as u16,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(public_key.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+
+        data
+    }
+
+    fn make_voucher() -> PassVoucher {
+        PassVoucher {
+            subject: Pubkey::new_unique(),
+            network: Pubkey::new_unique(),
+            gatekeeper: Pubkey::new_unique(),
+            pass_number: 0,
+            expiry: 1000,
+            data: [7; 32],
+            nonce: 42,
+        }
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let signer = Pubkey::new_unique();
+        let message = make_voucher().message();
+        let data = make_instruction_data(&signer, &message, ED25519_CURRENT_INSTRUCTION);
+
+        let (parsed_signer, parsed_message) = parse_ed25519_instruction(&data).unwrap();
+
+        assert_eq!(parsed_signer, signer);
+        assert_eq!(parsed_message, message.as_slice());
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction_rejects_other_layouts() {
+        let signer = Pubkey::new_unique();
+        let message = make_voucher().message();
+
+        // Signatures over data in another instruction are not accepted
+        let data = make_instruction_data(&signer, &message, 0);
+        assert_eq!(
+            parse_ed25519_instruction(&data),
+            Err(error!(PassErrors::InvalidVoucherSignature))
+        );
+
+        let mut data = make_instruction_data(&signer, &message, ED25519_CURRENT_INSTRUCTION);
+        data[0] = 2;
+        assert!(parse_ed25519_instruction(&data).is_err());
+
+        let mut data = make_instruction_data(&signer, &message, ED25519_CURRENT_INSTRUCTION);
+        data.truncate(data.len() - 1);
+        assert!(parse_ed25519_instruction(&data).is_err());
+    }
+}